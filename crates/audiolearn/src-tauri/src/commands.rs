@@ -1,7 +1,8 @@
 //! Tauri commands for AudioLearn native functionality
 
 use serde::{Deserialize, Serialize};
-use tauri::command;
+use tauri::{command, AppHandle};
+use tauri_plugin_store::StoreExt;
 
 /// Voice information returned to frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -128,20 +129,58 @@ pub struct AppState {
     pub volume: f32,
 }
 
+/// Store file (in the app data directory) holding persisted state
+const STORE_PATH: &str = "audiolearn.json";
+
+/// Store key for [`AppState`]
+const APP_STATE_KEY: &str = "app_state";
+
 /// Save app state
 #[command]
-pub async fn save_app_state(state: AppState) -> Result<(), String> {
+pub async fn save_app_state(app: AppHandle, state: AppState) -> Result<(), String> {
     log::info!("Saving app state: {:?}", state);
-    // State will be saved using tauri-plugin-store
-    Ok(())
+    let value = serde_json::to_value(&state).map_err(|e| e.to_string())?;
+    store_set(app, APP_STATE_KEY.to_string(), value).await
 }
 
 /// Load app state
 #[command]
-pub async fn load_app_state() -> Result<AppState, String> {
+pub async fn load_app_state(app: AppHandle) -> Result<AppState, String> {
     log::info!("Loading app state");
-    // State will be loaded using tauri-plugin-store
-    Ok(AppState::default())
+    match store_get(app, APP_STATE_KEY.to_string()).await? {
+        Some(value) => serde_json::from_value(value).map_err(|e| e.to_string()),
+        None => Ok(AppState::default()),
+    }
+}
+
+/// Get a JSON value from the store
+#[command]
+pub async fn store_get(app: AppHandle, key: String) -> Result<Option<serde_json::Value>, String> {
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    Ok(store.get(key))
+}
+
+/// Set a JSON value in the store and write it to disk
+#[command]
+pub async fn store_set(app: AppHandle, key: String, value: serde_json::Value) -> Result<(), String> {
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    store.set(key, value);
+    store.save().map_err(|e| e.to_string())
+}
+
+/// Delete a value from the store
+#[command]
+pub async fn store_delete(app: AppHandle, key: String) -> Result<(), String> {
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    store.delete(key);
+    store.save().map_err(|e| e.to_string())
+}
+
+/// Get every entry in the store
+#[command]
+pub async fn store_entries(app: AppHandle) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    Ok(store.entries().into_iter().collect())
 }
 
 /// Get app version
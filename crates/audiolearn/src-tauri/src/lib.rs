@@ -65,6 +65,10 @@ pub fn run() {
             get_voices,
             save_app_state,
             load_app_state,
+            store_get,
+            store_set,
+            store_delete,
+            store_entries,
             get_app_version,
         ])
         .run(tauri::generate_context!())
@@ -55,6 +55,24 @@ impl Course {
         self.chapters.iter().map(|c| c.lessons.len() as u32).sum()
     }
     
+    /// Percentage (0-100) of this course's lessons that are in `completed`
+    ///
+    /// IDs of lessons from other courses are ignored; a course without
+    /// lessons is 0% complete.
+    pub fn progress(&self, completed: &[Id]) -> f32 {
+        let total = self.lesson_count();
+        if total == 0 {
+            return 0.0;
+        }
+        let done = self
+            .chapters
+            .iter()
+            .flat_map(|c| &c.lessons)
+            .filter(|l| completed.contains(&l.id))
+            .count();
+        done as f32 / total as f32 * 100.0
+    }
+
     pub fn get_lesson(&self, lesson_id: &Id) -> Option<&Lesson> {
         for chapter in &self.chapters {
             if let Some(lesson) = chapter.lessons.iter().find(|l| &l.id == lesson_id) {
@@ -63,6 +81,34 @@ impl Course {
         }
         None
     }
+
+    /// Every lesson, chapter by chapter, in listening order
+    pub fn lessons_in_order(&self) -> impl Iterator<Item = &Lesson> {
+        self.chapters.iter().flat_map(|c| &c.lessons)
+    }
+
+    /// Lesson after `lesson_id`, continuing into the next chapter
+    ///
+    /// `None` at the end of the course or if `lesson_id` is not in it.
+    pub fn next_lesson(&self, lesson_id: &Id) -> Option<&Lesson> {
+        let mut lessons = self.lessons_in_order();
+        lessons.find(|l| &l.id == lesson_id)?;
+        lessons.next()
+    }
+
+    /// Lesson before `lesson_id`, going back into the previous chapter
+    ///
+    /// `None` at the start of the course or if `lesson_id` is not in it.
+    pub fn prev_lesson(&self, lesson_id: &Id) -> Option<&Lesson> {
+        let mut previous = None;
+        for lesson in self.lessons_in_order() {
+            if &lesson.id == lesson_id {
+                return previous;
+            }
+            previous = Some(lesson);
+        }
+        None
+    }
 }
 
 /// Course author
@@ -169,3 +215,136 @@ impl Library {
         self.progress.iter().find(|p| &p.course_id == course_id)
     }
 }
+
+/// Learning material the user pasted or uploaded
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CustomMaterial {
+    pub id: Id,
+    pub title: String,
+    pub content: String,
+    pub created_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lesson(id: &str) -> Lesson {
+        Lesson {
+            id: id.to_string(),
+            chapter_id: "ch1".to_string(),
+            title: id.to_string(),
+            description: None,
+            audio_url: String::new(),
+            duration: 60,
+            transcript: None,
+            quiz: None,
+            order: 1,
+        }
+    }
+
+    fn course(lesson_ids: &[&str]) -> Course {
+        Course {
+            id: "c1".to_string(),
+            title: "Course".to_string(),
+            description: String::new(),
+            author: Author {
+                id: "a1".to_string(),
+                name: "Author".to_string(),
+                bio: None,
+                avatar_url: None,
+            },
+            cover_image: String::new(),
+            icon: "📘".to_string(),
+            difficulty: Difficulty::Beginner,
+            total_duration: 60 * lesson_ids.len() as Seconds,
+            chapters: vec![Chapter {
+                id: "ch1".to_string(),
+                title: "Chapter".to_string(),
+                description: None,
+                lessons: lesson_ids.iter().map(|id| lesson(id)).collect(),
+            }],
+            rating: 4.5,
+            review_count: 0,
+            tags: vec![],
+        }
+    }
+
+    /// Course with one chapter per entry of `chapters`
+    fn course_with_chapters(chapters: &[&[&str]]) -> Course {
+        let mut course = course(&[]);
+        course.chapters = chapters
+            .iter()
+            .enumerate()
+            .map(|(i, lesson_ids)| Chapter {
+                id: format!("ch{}", i + 1),
+                title: format!("Chapter {}", i + 1),
+                description: None,
+                lessons: lesson_ids.iter().map(|id| lesson(id)).collect(),
+            })
+            .collect();
+        course
+    }
+
+    fn ids(ids: &[&str]) -> Vec<Id> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_progress_partial() {
+        let course = course(&["l1", "l2", "l3", "l4"]);
+        assert_eq!(course.progress(&[]), 0.0);
+        assert_eq!(course.progress(&ids(&["l1"])), 25.0);
+        assert_eq!(course.progress(&ids(&["l2", "l4", "l3"])), 75.0);
+    }
+
+    #[test]
+    fn test_progress_full() {
+        let course = course(&["l1", "l2", "l3"]);
+        assert_eq!(course.progress(&ids(&["l3", "l1", "l2"])), 100.0);
+    }
+
+    #[test]
+    fn test_progress_ignores_other_courses() {
+        let course = course(&["l1", "l2"]);
+        assert_eq!(course.progress(&ids(&["l1", "other_l1", "other_l2"])), 50.0);
+    }
+
+    #[test]
+    fn test_progress_without_lessons_is_zero() {
+        let course = course(&[]);
+        assert_eq!(course.lesson_count(), 0);
+        assert_eq!(course.progress(&ids(&["l1"])), 0.0);
+    }
+
+    #[test]
+    fn test_lessons_in_order_spans_chapters() {
+        let course = course_with_chapters(&[&["l1", "l2"], &[], &["l3"], &["l4", "l5"]]);
+        let order: Vec<&str> = course.lessons_in_order().map(|l| l.id.as_str()).collect();
+        assert_eq!(order, vec!["l1", "l2", "l3", "l4", "l5"]);
+    }
+
+    #[test]
+    fn test_next_lesson_crosses_chapters() {
+        let course = course_with_chapters(&[&["l1", "l2"], &[], &["l3"], &["l4", "l5"]]);
+        let next = |id: &str| course.next_lesson(&id.to_string()).map(|l| l.id.as_str());
+
+        assert_eq!(next("l1"), Some("l2"));
+        assert_eq!(next("l2"), Some("l3"));
+        assert_eq!(next("l3"), Some("l4"));
+        assert_eq!(next("l5"), None);
+        assert_eq!(next("missing"), None);
+    }
+
+    #[test]
+    fn test_prev_lesson_crosses_chapters() {
+        let course = course_with_chapters(&[&["l1", "l2"], &[], &["l3"], &["l4", "l5"]]);
+        let prev = |id: &str| course.prev_lesson(&id.to_string()).map(|l| l.id.as_str());
+
+        assert_eq!(prev("l5"), Some("l4"));
+        assert_eq!(prev("l4"), Some("l3"));
+        assert_eq!(prev("l3"), Some("l2"));
+        assert_eq!(prev("l1"), None);
+        assert_eq!(prev("missing"), None);
+    }
+}
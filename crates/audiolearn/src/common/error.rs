@@ -11,6 +11,8 @@ pub enum AppError {
     Storage(String),
     Auth(String),
     Tts(String),
+    Io(String),
+    Model(String),
 }
 
 impl fmt::Display for AppError {
@@ -22,6 +24,8 @@ impl fmt::Display for AppError {
             Self::Storage(msg) => write!(f, "Storage error: {}", msg),
             Self::Auth(msg) => write!(f, "Auth error: {}", msg),
             Self::Tts(msg) => write!(f, "TTS error: {}", msg),
+            Self::Io(msg) => write!(f, "I/O error: {}", msg),
+            Self::Model(msg) => write!(f, "Model error: {}", msg),
         }
     }
 }
@@ -113,6 +113,10 @@ impl Difficulty {
             Self::Advanced => "📕",
         }
     }
+    
+    pub fn all() -> [Self; 3] {
+        [Self::Beginner, Self::Intermediate, Self::Advanced]
+    }
 }
 
 /// Timestamp for audio position
@@ -0,0 +1,338 @@
+//! Seekable audio playback
+//!
+//! `AudioController` tracks playback state and position on top of an
+//! [`AudioSink`] backend (rodio on desktop) and implements the
+//! [`AudioPlayer`] SPI so the UI can poll `position()` and seek.
+
+use std::time::Duration;
+use crate::common::{AppError, PlaybackSpeed, PlaybackState, Result, Seconds};
+use crate::spi::AudioPlayer;
+
+/// Low-level output the controller drives
+pub trait AudioSink {
+    /// Replace any queued audio with `bytes` (encoded WAV/MP3), starting
+    /// `start` into it, paused. Returns the total duration when known.
+    fn load(&mut self, bytes: &[u8], start: Duration) -> Result<Option<Duration>>;
+
+    fn play(&mut self);
+
+    fn pause(&mut self);
+
+    /// Drop the queued audio
+    fn stop(&mut self);
+
+    fn set_speed(&mut self, speed: f32);
+
+    /// Seek within the loaded audio; fails when the source can't seek
+    fn try_seek(&mut self, position: Duration) -> Result<()>;
+
+    /// Position within the currently loaded source
+    fn position(&self) -> Duration;
+
+    /// Whether the loaded audio has played to the end
+    fn is_finished(&self) -> bool;
+}
+
+/// Play/pause/seek state machine over an [`AudioSink`]
+pub struct AudioController<S: AudioSink> {
+    sink: S,
+    audio: Option<Vec<u8>>,
+    duration: Seconds,
+    /// Added to the sink position after a restart-and-skip seek
+    offset: Duration,
+    state: PlaybackState,
+    speed: PlaybackSpeed,
+}
+
+impl<S: AudioSink> AudioController<S> {
+    pub fn new(sink: S) -> Self {
+        Self {
+            sink,
+            audio: None,
+            duration: 0,
+            offset: Duration::ZERO,
+            state: PlaybackState::Stopped,
+            speed: PlaybackSpeed::default(),
+        }
+    }
+
+    /// Load encoded audio (e.g. synthesized speech), paused at the start
+    pub fn load_bytes(&mut self, bytes: Vec<u8>) -> Result<()> {
+        let total = self.sink.load(&bytes, Duration::ZERO)?;
+        self.sink.set_speed(self.speed.as_f32());
+        self.duration = total.map(|d| d.as_secs_f64().round() as Seconds).unwrap_or(0);
+        self.audio = Some(bytes);
+        self.offset = Duration::ZERO;
+        self.state = PlaybackState::Paused;
+        Ok(())
+    }
+
+    /// The backend being driven
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Reload the audio and skip ahead to `start`, for sources that can't seek
+    fn restart_at(&mut self, start: Duration) -> Result<()> {
+        let Some(audio) = &self.audio else {
+            return Err(AppError::Audio("No audio loaded".into()));
+        };
+        self.sink.load(audio, start)?;
+        self.sink.set_speed(self.speed.as_f32());
+        self.offset = start;
+        if self.state == PlaybackState::Playing {
+            self.sink.play();
+        }
+        Ok(())
+    }
+
+    fn clamp(&self, position: Seconds) -> Seconds {
+        if self.duration > 0 {
+            position.min(self.duration)
+        } else {
+            position
+        }
+    }
+}
+
+impl<S: AudioSink + Send + Sync> AudioPlayer for AudioController<S> {
+    fn load(&mut self, url: &str) -> Result<()> {
+        let bytes = std::fs::read(url)
+            .map_err(|e| AppError::Io(format!("Failed to read {}: {}", url, e)))?;
+        self.load_bytes(bytes)
+    }
+
+    fn play(&mut self) -> Result<()> {
+        if self.audio.is_none() {
+            return Err(AppError::Audio("No audio loaded".into()));
+        }
+        if self.sink.is_finished() {
+            self.restart_at(Duration::ZERO)?;
+        }
+        self.sink.play();
+        self.state = PlaybackState::Playing;
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        if self.state == PlaybackState::Playing {
+            self.sink.pause();
+            self.state = PlaybackState::Paused;
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        self.sink.stop();
+        self.audio = None;
+        self.duration = 0;
+        self.offset = Duration::ZERO;
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn seek(&mut self, position: Seconds) -> Result<()> {
+        if self.audio.is_none() {
+            return Err(AppError::Audio("No audio loaded".into()));
+        }
+        let target = Duration::from_secs(self.clamp(position) as u64);
+        if self.sink.try_seek(target).is_ok() {
+            self.offset = Duration::ZERO;
+            Ok(())
+        } else {
+            // Approximate seek: restart and skip
+            self.restart_at(target)
+        }
+    }
+
+    fn skip_forward(&mut self, seconds: Seconds) -> Result<()> {
+        self.seek(self.position().saturating_add(seconds))
+    }
+
+    fn skip_backward(&mut self, seconds: Seconds) -> Result<()> {
+        self.seek(self.position().saturating_sub(seconds))
+    }
+
+    fn set_speed(&mut self, speed: PlaybackSpeed) -> Result<()> {
+        self.sink.set_speed(speed.as_f32());
+        self.speed = speed;
+        Ok(())
+    }
+
+    fn position(&self) -> Seconds {
+        if self.audio.is_none() {
+            return 0;
+        }
+        let elapsed = self.offset + self.sink.position();
+        self.clamp(elapsed.as_secs() as Seconds)
+    }
+
+    fn duration(&self) -> Seconds {
+        self.duration
+    }
+
+    fn state(&self) -> PlaybackState {
+        if self.state == PlaybackState::Playing && self.sink.is_finished() {
+            PlaybackState::Stopped
+        } else {
+            self.state
+        }
+    }
+
+    fn speed(&self) -> PlaybackSpeed {
+        self.speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sink whose clock only moves when the test says so
+    #[derive(Default)]
+    struct MockSink {
+        seekable: bool,
+        total: Option<Duration>,
+        loads: Vec<Duration>,
+        playing: bool,
+        pos: Duration,
+        speed: f32,
+    }
+
+    impl MockSink {
+        fn advance(&mut self, secs: u64) {
+            if self.playing {
+                self.pos += Duration::from_secs(secs);
+            }
+        }
+    }
+
+    impl AudioSink for MockSink {
+        fn load(&mut self, _bytes: &[u8], start: Duration) -> Result<Option<Duration>> {
+            self.loads.push(start);
+            self.playing = false;
+            self.pos = Duration::ZERO;
+            Ok(self.total)
+        }
+
+        fn play(&mut self) {
+            self.playing = true;
+        }
+
+        fn pause(&mut self) {
+            self.playing = false;
+        }
+
+        fn stop(&mut self) {
+            self.playing = false;
+            self.pos = Duration::ZERO;
+        }
+
+        fn set_speed(&mut self, speed: f32) {
+            self.speed = speed;
+        }
+
+        fn try_seek(&mut self, position: Duration) -> Result<()> {
+            if !self.seekable {
+                return Err(AppError::Audio("not seekable".into()));
+            }
+            self.pos = position;
+            Ok(())
+        }
+
+        fn position(&self) -> Duration {
+            self.pos
+        }
+
+        fn is_finished(&self) -> bool {
+            self.total.is_some_and(|total| self.pos >= total)
+        }
+    }
+
+    fn controller(seekable: bool) -> AudioController<MockSink> {
+        let sink = MockSink { seekable, total: Some(Duration::from_secs(60)), ..Default::default() };
+        let mut controller = AudioController::new(sink);
+        controller.load_bytes(vec![0; 4]).unwrap();
+        controller
+    }
+
+    #[test]
+    fn test_requires_loaded_audio() {
+        let mut controller = AudioController::new(MockSink::default());
+        assert!(controller.play().is_err());
+        assert!(controller.seek(10).is_err());
+        assert_eq!(controller.position(), 0);
+    }
+
+    #[test]
+    fn test_position_follows_play_and_pause() {
+        let mut c = controller(true);
+        assert_eq!(c.state(), PlaybackState::Paused);
+        assert_eq!(c.duration(), 60);
+
+        c.play().unwrap();
+        c.sink.advance(5);
+        assert_eq!(c.position(), 5);
+
+        c.pause().unwrap();
+        c.sink.advance(5);
+        assert_eq!(c.position(), 5);
+        assert_eq!(c.state(), PlaybackState::Paused);
+    }
+
+    #[test]
+    fn test_seek_on_seekable_source() {
+        let mut c = controller(true);
+        c.play().unwrap();
+        c.seek(30).unwrap();
+        assert_eq!(c.position(), 30);
+        assert_eq!(c.sink().loads.len(), 1);
+
+        // Past the end clamps to the duration
+        c.seek(500).unwrap();
+        assert_eq!(c.position(), 60);
+    }
+
+    #[test]
+    fn test_seek_falls_back_to_restart_and_skip() {
+        let mut c = controller(false);
+        c.set_speed(PlaybackSpeed::OneHalf).unwrap();
+        c.play().unwrap();
+        c.seek(20).unwrap();
+
+        assert_eq!(c.sink().loads, vec![Duration::ZERO, Duration::from_secs(20)]);
+        assert!(c.sink().playing);
+        assert_eq!(c.sink().speed, 1.5);
+        assert_eq!(c.position(), 20);
+
+        c.sink.advance(3);
+        assert_eq!(c.position(), 23);
+
+        c.skip_backward(15).unwrap();
+        assert_eq!(c.position(), 8);
+    }
+
+    #[test]
+    fn test_finished_then_play_restarts() {
+        let mut c = controller(true);
+        c.play().unwrap();
+        c.sink.advance(60);
+        assert_eq!(c.state(), PlaybackState::Stopped);
+
+        c.play().unwrap();
+        assert_eq!(c.position(), 0);
+        assert_eq!(c.state(), PlaybackState::Playing);
+    }
+
+    #[test]
+    fn test_stop_unloads() {
+        let mut c = controller(true);
+        c.play().unwrap();
+        c.sink.advance(10);
+        c.stop().unwrap();
+        assert_eq!(c.state(), PlaybackState::Stopped);
+        assert_eq!(c.position(), 0);
+        assert_eq!(c.duration(), 0);
+        assert!(c.play().is_err());
+    }
+}
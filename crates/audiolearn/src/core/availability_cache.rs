@@ -0,0 +1,127 @@
+//! Short-lived memo of whether TTS engines are usable
+//!
+//! Probing Edge means a network round trip, so
+//! [`TtsManager`](crate::core::TtsManager) keeps the answer for a while
+//! instead of asking again on every UI call.
+
+use chrono::{DateTime, Duration, Utc};
+use std::cell::Cell;
+use crate::core::{Clock, SystemClock};
+
+/// How long an availability answer is trusted
+pub const AVAILABILITY_TTL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Availability answer that expires after a TTL
+#[derive(Debug)]
+pub struct AvailabilityCache<C: Clock = SystemClock> {
+    clock: C,
+    ttl: Duration,
+    checked: Cell<Option<(bool, DateTime<Utc>)>>,
+}
+
+impl AvailabilityCache {
+    pub fn new(ttl: std::time::Duration) -> Self {
+        Self::with_clock(ttl, SystemClock)
+    }
+}
+
+impl Default for AvailabilityCache {
+    fn default() -> Self {
+        Self::new(AVAILABILITY_TTL)
+    }
+}
+
+impl<C: Clock> AvailabilityCache<C> {
+    pub fn with_clock(ttl: std::time::Duration, clock: C) -> Self {
+        Self {
+            clock,
+            ttl: Duration::from_std(ttl).unwrap_or(Duration::MAX),
+            checked: Cell::new(None),
+        }
+    }
+
+    /// Cached answer if still fresh, otherwise the result of `probe`
+    pub fn get_or_probe(&self, probe: impl FnOnce() -> bool) -> bool {
+        let now = self.clock.now();
+        match self.checked.get() {
+            Some((available, at)) if now - at < self.ttl => available,
+            _ => {
+                let available = probe();
+                self.checked.set(Some((available, now)));
+                available
+            }
+        }
+    }
+
+    /// Forget the cached answer so the next call probes again
+    pub fn invalidate(&self) {
+        self.checked.set(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FakeClock;
+
+    /// Engine stand-in that counts how often it is probed
+    struct CountingEngine {
+        available: Cell<bool>,
+        probes: Cell<usize>,
+    }
+
+    impl CountingEngine {
+        fn new(available: bool) -> Self {
+            Self { available: Cell::new(available), probes: Cell::new(0) }
+        }
+
+        fn is_available(&self) -> bool {
+            self.probes.set(self.probes.get() + 1);
+            self.available.get()
+        }
+    }
+
+    fn cache() -> (AvailabilityCache<FakeClock>, FakeClock) {
+        let clock = FakeClock::new();
+        let ttl = std::time::Duration::from_secs(30);
+        (AvailabilityCache::with_clock(ttl, clock.clone()), clock)
+    }
+
+    #[test]
+    fn test_probes_once_within_ttl() {
+        let (cache, clock) = cache();
+        let engine = CountingEngine::new(true);
+
+        for _ in 0..5 {
+            assert!(cache.get_or_probe(|| engine.is_available()));
+            clock.advance(5);
+        }
+        assert_eq!(engine.probes.get(), 1);
+    }
+
+    #[test]
+    fn test_probes_again_after_ttl() {
+        let (cache, clock) = cache();
+        let engine = CountingEngine::new(false);
+
+        assert!(!cache.get_or_probe(|| engine.is_available()));
+        engine.available.set(true);
+        clock.advance(29);
+        assert!(!cache.get_or_probe(|| engine.is_available()));
+
+        clock.advance(1);
+        assert!(cache.get_or_probe(|| engine.is_available()));
+        assert_eq!(engine.probes.get(), 2);
+    }
+
+    #[test]
+    fn test_invalidate_forces_probe() {
+        let (cache, _clock) = cache();
+        let engine = CountingEngine::new(true);
+
+        cache.get_or_probe(|| engine.is_available());
+        cache.invalidate();
+        cache.get_or_probe(|| engine.is_available());
+        assert_eq!(engine.probes.get(), 2);
+    }
+}
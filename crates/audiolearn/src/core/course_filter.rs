@@ -0,0 +1,134 @@
+//! Browsing courses by difficulty, length and tags
+
+use crate::api::Course;
+use crate::common::{Difficulty, Seconds};
+
+/// Criteria a course must meet to be listed
+///
+/// Every set criterion must match; an empty filter matches every course.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CourseFilter {
+    /// Only courses at this level
+    pub difficulty: Option<Difficulty>,
+    /// Only courses no longer than this
+    pub max_duration: Option<Seconds>,
+    /// Only courses tagged with every one of these
+    pub tags: Vec<String>,
+}
+
+impl CourseFilter {
+    /// Check if no criterion is set
+    pub fn is_empty(&self) -> bool {
+        self.difficulty.is_none() && self.max_duration.is_none() && self.tags.is_empty()
+    }
+
+    /// Check if a course meets every criterion
+    pub fn matches(&self, course: &Course) -> bool {
+        self.difficulty.is_none_or(|d| course.difficulty == d)
+            && self.max_duration.is_none_or(|max| course.total_duration <= max)
+            && self.tags.iter().all(|tag| course.tags.contains(tag))
+    }
+}
+
+/// Courses that pass `filter`, in their original order
+pub fn filter_courses(courses: &[Course], filter: &CourseFilter) -> Vec<Course> {
+    courses.iter().filter(|c| filter.matches(c)).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::get_sample_courses;
+
+    fn ids(courses: &[Course]) -> Vec<&str> {
+        courses.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    fn tagged(id: &str, tags: &[&str]) -> Course {
+        let mut course = get_sample_courses().remove(0);
+        course.id = id.to_string();
+        course.tags = tags.iter().map(|t| t.to_string()).collect();
+        course
+    }
+
+    #[test]
+    fn test_empty_filter_returns_all() {
+        let courses = get_sample_courses();
+        let filter = CourseFilter::default();
+        assert!(filter.is_empty());
+        assert_eq!(filter_courses(&courses, &filter), courses);
+    }
+
+    #[test]
+    fn test_filter_by_difficulty() {
+        let courses = get_sample_courses();
+        for difficulty in Difficulty::all() {
+            let filter = CourseFilter { difficulty: Some(difficulty), ..Default::default() };
+            let found = filter_courses(&courses, &filter);
+            let expected: Vec<_> = courses.iter().filter(|c| c.difficulty == difficulty).collect();
+            assert_eq!(found.len(), expected.len());
+            assert!(found.iter().all(|c| c.difficulty == difficulty));
+        }
+    }
+
+    #[test]
+    fn test_filter_by_max_duration() {
+        let courses = get_sample_courses();
+        let shortest = courses.iter().map(|c| c.total_duration).min().unwrap();
+
+        // The limit is inclusive
+        let filter = CourseFilter { max_duration: Some(shortest), ..Default::default() };
+        let found = filter_courses(&courses, &filter);
+        assert!(!found.is_empty());
+        assert!(found.iter().all(|c| c.total_duration == shortest));
+
+        let filter = CourseFilter { max_duration: Some(shortest - 1), ..Default::default() };
+        assert!(filter_courses(&courses, &filter).is_empty());
+    }
+
+    #[test]
+    fn test_filter_requires_every_tag() {
+        let courses = vec![
+            tagged("a", &["rust", "programming"]),
+            tagged("b", &["rust"]),
+            tagged("c", &["python", "programming"]),
+        ];
+
+        let filter = CourseFilter { tags: vec!["programming".into()], ..Default::default() };
+        assert_eq!(ids(&filter_courses(&courses, &filter)), vec!["a", "c"]);
+
+        let filter = CourseFilter {
+            tags: vec!["rust".into(), "programming".into()],
+            ..Default::default()
+        };
+        assert_eq!(ids(&filter_courses(&courses, &filter)), vec!["a"]);
+
+        let filter = CourseFilter { tags: vec!["rust".into(), "python".into()], ..Default::default() };
+        assert!(filter_courses(&courses, &filter).is_empty());
+    }
+
+    #[test]
+    fn test_filters_combine() {
+        let mut courses = vec![
+            tagged("short", &["rust"]),
+            tagged("long", &["rust"]),
+            tagged("advanced", &["rust"]),
+        ];
+        courses[0].total_duration = 1800;
+        courses[1].total_duration = 7200;
+        courses[2].total_duration = 1800;
+        courses[2].difficulty = Difficulty::Advanced;
+        let level = courses[0].difficulty;
+
+        let filter = CourseFilter {
+            difficulty: Some(level),
+            max_duration: Some(3600),
+            tags: vec!["rust".into()],
+        };
+        assert_eq!(ids(&filter_courses(&courses, &filter)), vec!["short"]);
+
+        // A single failing criterion excludes the course
+        let filter = CourseFilter { tags: vec!["cloud".into()], ..filter };
+        assert!(filter_courses(&courses, &filter).is_empty());
+    }
+}
@@ -5,21 +5,199 @@
 //! without requiring an API key.
 
 use crate::common::{AudioLearnError, Result};
+use crate::core::{escape_ssml_text, validate_ssml};
 use crate::spi::tts::{SpeechOptions, Voice, VoiceGender};
 use msedge_tts::{
     tts::{client::connect, SpeechConfig},
     voice::get_voices_list,
 };
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Prosody adjustments for an Edge TTS request
+///
+/// `msedge-tts` renders these into the SSML request as
+/// `<prosody pitch="{pitch:+}Hz" rate="{rate:+}%" volume="{volume:+}%">`,
+/// so every value is relative to the voice's default (0 = unchanged).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeProsody {
+    /// Rate change in percent (-50 to +100, i.e. 0.5x to 2x speed)
+    pub rate: i32,
+    /// Pitch change in Hz (-50 to +50)
+    pub pitch: i32,
+    /// Volume change in percent (-100 to +0)
+    pub volume: i32,
+}
+
+impl EdgeProsody {
+    /// Slowest supported speech rate multiplier
+    pub const MIN_RATE: f32 = 0.5;
+    /// Fastest supported speech rate multiplier
+    pub const MAX_RATE: f32 = 2.0;
+    /// Largest pitch shift in Hz
+    pub const MAX_PITCH_HZ: f32 = 50.0;
+    
+    /// Map speech options onto Edge's prosody ranges
+    ///
+    /// Out-of-range values are clamped and non-finite values fall back to the defaults.
+    pub fn from_options(options: &SpeechOptions) -> Self {
+        let finite_or = |value: f32, default: f32| if value.is_finite() { value } else { default };
+        
+        let rate = finite_or(options.rate, 1.0).clamp(Self::MIN_RATE, Self::MAX_RATE);
+        let pitch = finite_or(options.pitch, 0.0).clamp(-1.0, 1.0);
+        let volume = finite_or(options.volume, 1.0).clamp(0.0, 1.0);
+        
+        Self {
+            rate: ((rate - 1.0) * 100.0).round() as i32,
+            pitch: (pitch * Self::MAX_PITCH_HZ).round() as i32,
+            volume: ((volume - 1.0) * 100.0).round() as i32,
+        }
+    }
+    
+    /// SSML `rate` attribute value (e.g. "+50%")
+    pub fn rate_attr(&self) -> String {
+        format!("{:+}%", self.rate)
+    }
+    
+    /// SSML `pitch` attribute value (e.g. "-10Hz")
+    pub fn pitch_attr(&self) -> String {
+        format!("{:+}Hz", self.pitch)
+    }
+    
+    /// SSML `volume` attribute value (e.g. "-25%")
+    pub fn volume_attr(&self) -> String {
+        format!("{:+}%", self.volume)
+    }
+}
+
+/// Network side of the Edge engine, replaceable in tests
+pub(crate) trait EdgeTransport: Send + Sync {
+    /// Fetch the voice list
+    fn voices(&self) -> Result<Vec<Voice>>;
+
+    /// Synthesize an SSML body with the voice whose short name is `voice`
+    fn synthesize(&self, ssml: &str, voice: &str, prosody: EdgeProsody) -> Result<Vec<u8>>;
+}
+
+/// Talks to Microsoft's service through `msedge-tts`
+struct MsEdgeTransport;
+
+impl EdgeTransport for MsEdgeTransport {
+    fn voices(&self) -> Result<Vec<Voice>> {
+        let voice_list = get_voices_list()
+            .map_err(|e| AudioLearnError::Tts(format!("Failed to fetch Edge voices: {}", e)))?;
+        
+        Ok(voice_list
+            .into_iter()
+            .filter_map(|v| {
+                // short_name is required for synthesizing, skip voices without it
+                let short_name = v.short_name.as_ref()?;
+                Some(Voice {
+                    id: short_name.clone(),
+                    name: v.name.clone(),
+                    language: v.locale.clone().unwrap_or_else(|| "unknown".to_string()),
+                    gender: v.gender.as_ref()
+                        .map(|g| match g.as_str() {
+                            "Male" => VoiceGender::Male,
+                            "Female" => VoiceGender::Female,
+                            _ => VoiceGender::Neutral,
+                        })
+                        .unwrap_or(VoiceGender::Neutral),
+                    is_neural: short_name.contains("Neural"),
+                })
+            })
+            .collect())
+    }
+    
+    fn synthesize(&self, ssml: &str, voice: &str, prosody: EdgeProsody) -> Result<Vec<u8>> {
+        let config = SpeechConfig {
+            voice_name: voice.to_string(),
+            // Use MP3 format for better compatibility
+            audio_format: "audio-24khz-48kbitrate-mono-mp3".to_string(),
+            rate: prosody.rate,
+            pitch: prosody.pitch,
+            volume: prosody.volume,
+        };
+        
+        let mut tts = connect()
+            .map_err(|e| AudioLearnError::Tts(format!("Failed to connect to Edge TTS: {}", e)))?;
+        
+        // msedge-tts puts the text into its SSML envelope verbatim
+        let audio_data = tts
+            .synthesize(ssml, &config)
+            .map_err(|e| AudioLearnError::Tts(format!("Failed to synthesize: {}", e)))?;
+        
+        // SynthesizedAudio has an audio_bytes field directly
+        Ok(audio_data.audio_bytes)
+    }
+}
+
+/// Run a blocking `request` on its own thread, giving up after `timeout`
+///
+/// `msedge-tts` has no timeout of its own and its calls can't be cancelled,
+/// so a request that times out keeps its thread until the socket gives up;
+/// its result is dropped.
+fn with_deadline<T: Send + 'static>(
+    timeout: Duration,
+    what: &str,
+    request: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(request());
+    });
+    
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(AudioLearnError::Tts(format!(
+            "{} timed out after {:.1}s",
+            what,
+            timeout.as_secs_f32()
+        ))),
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(AudioLearnError::Tts(format!("{} failed unexpectedly", what)))
+        }
+    }
+}
 
 /// Microsoft Edge TTS engine using neural voices
+///
+/// Every network call is bounded by a timeout, so when offline the engine
+/// fails fast and [`TtsManager`](crate::core::TtsManager) can fall back.
 pub struct EdgeTts {
     voices_cache: Option<Vec<Voice>>,
+    timeout: Duration,
+    transport: Arc<dyn EdgeTransport>,
 }
 
 impl EdgeTts {
+    /// How long a request may take before it fails
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+    
     /// Create a new Edge TTS engine
     pub fn new() -> Self {
-        Self { voices_cache: None }
+        Self::with_transport(MsEdgeTransport)
+    }
+    
+    pub(crate) fn with_transport(transport: impl EdgeTransport + 'static) -> Self {
+        Self {
+            voices_cache: None,
+            timeout: Self::DEFAULT_TIMEOUT,
+            transport: Arc::new(transport),
+        }
+    }
+    
+    /// Set how long each request may take before it fails
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+    
+    /// Get the request timeout
+    pub fn timeout(&self) -> Duration {
+        self.timeout
     }
     
     /// Get a recommended English voice
@@ -47,7 +225,7 @@ impl EdgeTts {
     /// Check if the engine is available
     pub fn is_available(&self) -> bool {
         // Try to fetch voices to check availability
-        get_voices_list().is_ok()
+        self.fetch_voices().is_ok()
     }
     
     /// Get available voices
@@ -57,66 +235,47 @@ impl EdgeTts {
             return Ok(cached.clone());
         }
         
-        let voice_list = get_voices_list()
-            .map_err(|e| AudioLearnError::Tts(format!("Failed to fetch Edge voices: {}", e)))?;
-        
-        let voices: Vec<Voice> = voice_list
-            .into_iter()
-            .filter_map(|v| {
-                // short_name is required for synthesizing, skip voices without it
-                let short_name = v.short_name.as_ref()?;
-                Some(Voice {
-                    id: short_name.clone(),
-                    name: v.name.clone(),
-                    language: v.locale.clone().unwrap_or_else(|| "unknown".to_string()),
-                    gender: v.gender.as_ref()
-                        .map(|g| match g.as_str() {
-                            "Male" => VoiceGender::Male,
-                            "Female" => VoiceGender::Female,
-                            _ => VoiceGender::Neutral,
-                        })
-                        .unwrap_or(VoiceGender::Neutral),
-                    is_neural: short_name.contains("Neural"),
-                })
-            })
-            .collect();
-        
+        let voices = self.fetch_voices()?;
         self.voices_cache = Some(voices.clone());
         Ok(voices)
     }
     
+    fn fetch_voices(&self) -> Result<Vec<Voice>> {
+        let transport = Arc::clone(&self.transport);
+        with_deadline(self.timeout, "Fetching Edge voices", move || transport.voices())
+    }
+    
     /// Synthesize text to audio bytes
+    ///
+    /// Markup characters are escaped, so `<` and `&` are read out.
     pub fn synthesize(&self, text: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+        self.synthesize_markup(&escape_ssml_text(text), options)
+    }
+    
+    /// Synthesize an SSML body (e.g. with `<break>` or `<emphasis>`) to
+    /// audio bytes
+    ///
+    /// The markup is sent as-is after checking it is well-formed; see
+    /// [`validate_ssml`] for what is accepted.
+    pub fn synthesize_ssml(&self, ssml: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+        validate_ssml(ssml)?;
+        self.synthesize_markup(ssml, options)
+    }
+    
+    fn synthesize_markup(&self, ssml: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
         // Get voice configuration
         let voice_name = options
             .voice
             .as_ref()
             .map(|v| v.id.clone())
             .unwrap_or_else(|| "en-US-AriaNeural".to_string());
+        let prosody = EdgeProsody::from_options(options);
         
-        // Create speech config manually
-        let config = SpeechConfig {
-            voice_name,
-            // Use MP3 format for better compatibility
-            audio_format: "audio-24khz-48kbitrate-mono-mp3".to_string(),
-            // Rate: percentage from -100 to 100 (0 = normal)
-            rate: ((options.rate - 1.0) * 100.0) as i32,
-            // Pitch: Hz adjustment from -50 to 50 (0 = normal)
-            pitch: (options.pitch * 50.0) as i32,
-            // Volume: percentage from 0 to 100
-            volume: (options.volume * 100.0) as i32,
-        };
-        
-        // Connect and synthesize
-        let mut tts = connect()
-            .map_err(|e| AudioLearnError::Tts(format!("Failed to connect to Edge TTS: {}", e)))?;
-        
-        let audio_data = tts
-            .synthesize(text, &config)
-            .map_err(|e| AudioLearnError::Tts(format!("Failed to synthesize: {}", e)))?;
-        
-        // SynthesizedAudio has an audio_bytes field directly
-        Ok(audio_data.audio_bytes)
+        let transport = Arc::clone(&self.transport);
+        let ssml = ssml.to_string();
+        with_deadline(self.timeout, "Edge TTS request", move || {
+            transport.synthesize(&ssml, &voice_name, prosody)
+        })
     }
     
     /// Get the engine name
@@ -149,6 +308,20 @@ impl EdgeTtsSync {
         Self::new().ok()
     }
     
+    /// Set how long each request may take before it fails
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self {
+            inner: self.inner.with_timeout(timeout),
+        }
+    }
+    
+    #[cfg(test)]
+    pub(crate) fn with_transport(transport: impl EdgeTransport + 'static) -> Self {
+        Self {
+            inner: EdgeTts::with_transport(transport),
+        }
+    }
+    
     /// Get the engine name
     pub fn name(&self) -> &str {
         self.inner.name()
@@ -169,6 +342,11 @@ impl EdgeTtsSync {
         self.inner.synthesize(text, options)
     }
     
+    /// Synthesize an SSML body to audio bytes
+    pub fn synthesize_ssml(&self, ssml: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+        self.inner.synthesize_ssml(ssml, options)
+    }
+    
     /// Get a recommended English voice
     pub fn recommended_english_voice() -> Voice {
         EdgeTts::recommended_english_voice()
@@ -185,6 +363,154 @@ impl Default for EdgeTtsSync {
 mod tests {
     use super::*;
     
+    fn rate_attr(rate: f32) -> String {
+        EdgeProsody::from_options(&SpeechOptions { rate, ..Default::default() }).rate_attr()
+    }
+    
+    #[test]
+    fn test_prosody_rate_mapping() {
+        assert_eq!(rate_attr(0.5), "-50%");
+        assert_eq!(rate_attr(1.0), "+0%");
+        assert_eq!(rate_attr(1.5), "+50%");
+        assert_eq!(rate_attr(2.0), "+100%");
+        
+        // Clamped to Edge's supported range
+        assert_eq!(rate_attr(0.1), "-50%");
+        assert_eq!(rate_attr(4.0), "+100%");
+        assert_eq!(rate_attr(f32::NAN), "+0%");
+    }
+    
+    #[test]
+    fn test_prosody_pitch_and_volume_mapping() {
+        let default = EdgeProsody::from_options(&SpeechOptions::default());
+        assert_eq!(default, EdgeProsody { rate: 0, pitch: 0, volume: 0 });
+        assert_eq!(default.pitch_attr(), "+0Hz");
+        assert_eq!(default.volume_attr(), "+0%");
+        
+        let adjusted = EdgeProsody::from_options(&SpeechOptions {
+            pitch: -0.2,
+            volume: 0.75,
+            ..Default::default()
+        });
+        assert_eq!(adjusted.pitch_attr(), "-10Hz");
+        assert_eq!(adjusted.volume_attr(), "-25%");
+        
+        let clamped = EdgeProsody::from_options(&SpeechOptions {
+            pitch: 3.0,
+            volume: -1.0,
+            ..Default::default()
+        });
+        assert_eq!(clamped.pitch_attr(), "+50Hz");
+        assert_eq!(clamped.volume_attr(), "-100%");
+    }
+    
+    /// Transport that answers only after `delay`, like a dead network
+    struct SlowTransport {
+        delay: Duration,
+    }
+    
+    impl EdgeTransport for SlowTransport {
+        fn voices(&self) -> Result<Vec<Voice>> {
+            thread::sleep(self.delay);
+            Ok(vec![EdgeTts::recommended_english_voice()])
+        }
+        
+        fn synthesize(&self, _text: &str, _voice: &str, _prosody: EdgeProsody) -> Result<Vec<u8>> {
+            thread::sleep(self.delay);
+            Ok(vec![1, 2, 3])
+        }
+    }
+    
+    /// Transport that answers at once, keeping the markup it was sent
+    #[derive(Default)]
+    struct RecordingTransport {
+        sent: std::sync::Mutex<Vec<String>>,
+    }
+    
+    impl EdgeTransport for Arc<RecordingTransport> {
+        fn voices(&self) -> Result<Vec<Voice>> {
+            Ok(Vec::new())
+        }
+        
+        fn synthesize(&self, ssml: &str, _voice: &str, _prosody: EdgeProsody) -> Result<Vec<u8>> {
+            self.sent.lock().unwrap().push(ssml.to_string());
+            Ok(vec![0])
+        }
+    }
+    
+    fn recording_edge() -> (EdgeTts, Arc<RecordingTransport>) {
+        let transport = Arc::new(RecordingTransport::default());
+        (EdgeTts::with_transport(Arc::clone(&transport)), transport)
+    }
+    
+    fn slow_edge(delay: Duration, timeout: Duration) -> EdgeTts {
+        EdgeTts::with_transport(SlowTransport { delay }).with_timeout(timeout)
+    }
+    
+    #[test]
+    fn test_default_timeout() {
+        assert_eq!(EdgeTts::new().timeout(), Duration::from_secs(5));
+    }
+    
+    #[test]
+    fn test_timeout_fails_fast() {
+        let mut edge = slow_edge(Duration::from_secs(3), Duration::from_millis(50));
+        let started = std::time::Instant::now();
+        
+        let result = edge.synthesize("Hello", &SpeechOptions::default());
+        assert!(matches!(result, Err(AudioLearnError::Tts(_))));
+        assert!(matches!(edge.voices(), Err(AudioLearnError::Tts(_))));
+        assert!(!edge.is_available());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+    
+    #[test]
+    fn test_response_within_timeout_succeeds() {
+        let mut edge = slow_edge(Duration::from_millis(10), Duration::from_secs(2));
+        
+        let audio = edge.synthesize("Hello", &SpeechOptions::default()).unwrap();
+        assert_eq!(audio, vec![1, 2, 3]);
+        assert_eq!(edge.voices().unwrap().len(), 1);
+        assert!(edge.is_available());
+    }
+    
+    #[test]
+    fn test_plain_text_is_escaped() {
+        let (edge, transport) = recording_edge();
+        let options = SpeechOptions::default();
+        
+        assert!(edge.synthesize("A & B < C", &options).is_ok());
+        edge.synthesize(r#"Say "hi" to Tom's <friend>"#, &options).unwrap();
+        edge.synthesize("C++ is fun?!", &options).unwrap();
+        assert_eq!(
+            *transport.sent.lock().unwrap(),
+            vec![
+                "A &amp; B &lt; C",
+                "Say &quot;hi&quot; to Tom&apos;s &lt;friend&gt;",
+                "C++ is fun?!",
+            ]
+        );
+        
+        // What Edge receives is always well-formed
+        for sent in transport.sent.lock().unwrap().iter() {
+            assert!(validate_ssml(sent).is_ok());
+        }
+    }
+    
+    #[test]
+    fn test_ssml_passes_through_after_validation() {
+        let (edge, transport) = recording_edge();
+        let options = SpeechOptions::default();
+        let ssml = r#"Wait <break time="500ms"/> for it &amp; <emphasis>listen</emphasis>"#;
+        
+        edge.synthesize_ssml(ssml, &options).unwrap();
+        let result = edge.synthesize_ssml("<emphasis>unclosed", &options);
+        assert!(matches!(result, Err(AudioLearnError::Tts(_))));
+        
+        // Invalid markup never reaches the network
+        assert_eq!(*transport.sent.lock().unwrap(), vec![ssml]);
+    }
+    
     #[test]
     fn test_edge_tts_voices() {
         let mut edge = EdgeTts::new();
@@ -0,0 +1,299 @@
+//! Analytics that records learning events as JSON lines
+//!
+//! Tracking only appends to an in-memory buffer; `flush` writes the buffered
+//! events to a JSONL file (desktop) or hands them back to the caller when
+//! there is no file (web).
+
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::{AppError, Id, Result, Seconds};
+use crate::spi::Analytics;
+
+/// Oldest events are dropped past this many unflushed events
+pub const MAX_BUFFERED_EVENTS: usize = 1000;
+
+/// A learning event
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum AnalyticsEvent {
+    LessonStarted { user_id: Id, lesson_id: Id },
+    LessonProgress { user_id: Id, lesson_id: Id, position: Seconds },
+    LessonCompleted { user_id: Id, lesson_id: Id },
+    CourseCompleted { user_id: Id, course_id: Id },
+    QuizAnswered { user_id: Id, quiz_id: Id, correct: bool },
+    BookmarkCreated { user_id: Id, lesson_id: Id, position: Seconds },
+    TimeSpent { user_id: Id, seconds: Seconds },
+    TtsPlayed { user_id: Id, characters: usize },
+    SearchPerformed { user_id: Id, query: String, results: usize },
+}
+
+/// An event with the time it was recorded; one JSON line in the log
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsRecord {
+    pub timestamp: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: AnalyticsEvent,
+}
+
+/// Buffered JSONL analytics
+pub struct FileAnalytics {
+    path: Option<PathBuf>,
+    buffer: Mutex<Vec<AnalyticsRecord>>,
+}
+
+impl FileAnalytics {
+    /// Analytics appended to the JSONL file at `path` on flush
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), buffer: Mutex::new(Vec::new()) }
+    }
+
+    /// Analytics kept in memory until flushed
+    pub fn in_memory() -> Self {
+        Self { path: None, buffer: Mutex::new(Vec::new()) }
+    }
+
+    /// Get default log path
+    #[cfg(feature = "desktop")]
+    pub fn default_path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("audiolearn")
+            .join("analytics.jsonl")
+    }
+
+    fn buffer(&self) -> MutexGuard<'_, Vec<AnalyticsRecord>> {
+        self.buffer.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Buffer `event`, timestamped now
+    pub fn record(&self, event: AnalyticsEvent) {
+        let mut buffer = self.buffer();
+        if buffer.len() >= MAX_BUFFERED_EVENTS {
+            buffer.remove(0);
+        }
+        buffer.push(AnalyticsRecord { timestamp: Utc::now(), event });
+    }
+
+    /// Number of events waiting to be flushed
+    pub fn pending(&self) -> usize {
+        self.buffer().len()
+    }
+
+    /// Write buffered events and clear the buffer
+    ///
+    /// Returns the flushed events as JSON lines. When writing to the file
+    /// fails the events stay buffered.
+    pub fn flush(&self) -> Result<String> {
+        // Take the events so recording isn't held up by the file write
+        let records = std::mem::take(&mut *self.buffer());
+
+        let written = to_json_lines(&records).and_then(|lines| {
+            if let (Some(path), false) = (&self.path, lines.is_empty()) {
+                append(path, &lines)?;
+            }
+            Ok(lines)
+        });
+        if written.is_err() {
+            // Put them back ahead of anything recorded meanwhile
+            let mut buffer = self.buffer();
+            let newer = std::mem::replace(&mut *buffer, records);
+            buffer.extend(newer);
+        }
+        written
+    }
+}
+
+/// One JSON object per line, each line newline-terminated
+pub fn to_json_lines(records: &[AnalyticsRecord]) -> Result<String> {
+    let mut out = String::new();
+    for record in records {
+        let line = serde_json::to_string(record)
+            .map_err(|e| AppError::Storage(format!("Failed to serialize event: {}", e)))?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn append(path: &std::path::Path, lines: &str) -> Result<()> {
+    use std::io::Write;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| AppError::Io(format!("Failed to create {}: {}", dir.display(), e)))?;
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| AppError::Io(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+impl Analytics for FileAnalytics {
+    fn track_lesson_start(&self, user_id: &Id, lesson_id: &Id) -> Result<()> {
+        self.record(AnalyticsEvent::LessonStarted { user_id: user_id.clone(), lesson_id: lesson_id.clone() });
+        Ok(())
+    }
+
+    fn track_progress(&self, user_id: &Id, lesson_id: &Id, position: Seconds) -> Result<()> {
+        self.record(AnalyticsEvent::LessonProgress {
+            user_id: user_id.clone(),
+            lesson_id: lesson_id.clone(),
+            position,
+        });
+        Ok(())
+    }
+
+    fn track_lesson_complete(&self, user_id: &Id, lesson_id: &Id) -> Result<()> {
+        self.record(AnalyticsEvent::LessonCompleted { user_id: user_id.clone(), lesson_id: lesson_id.clone() });
+        Ok(())
+    }
+
+    fn track_course_complete(&self, user_id: &Id, course_id: &Id) -> Result<()> {
+        self.record(AnalyticsEvent::CourseCompleted { user_id: user_id.clone(), course_id: course_id.clone() });
+        Ok(())
+    }
+
+    fn track_quiz_answer(&self, user_id: &Id, quiz_id: &Id, correct: bool) -> Result<()> {
+        self.record(AnalyticsEvent::QuizAnswered { user_id: user_id.clone(), quiz_id: quiz_id.clone(), correct });
+        Ok(())
+    }
+
+    fn track_bookmark(&self, user_id: &Id, lesson_id: &Id, timestamp: Seconds) -> Result<()> {
+        self.record(AnalyticsEvent::BookmarkCreated {
+            user_id: user_id.clone(),
+            lesson_id: lesson_id.clone(),
+            position: timestamp,
+        });
+        Ok(())
+    }
+
+    fn track_time_spent(&self, user_id: &Id, seconds: Seconds) -> Result<()> {
+        self.record(AnalyticsEvent::TimeSpent { user_id: user_id.clone(), seconds });
+        Ok(())
+    }
+
+    fn track_tts_played(&self, user_id: &Id, characters: usize) -> Result<()> {
+        self.record(AnalyticsEvent::TtsPlayed { user_id: user_id.clone(), characters });
+        Ok(())
+    }
+
+    fn track_search(&self, user_id: &Id, query: &str, results: usize) -> Result<()> {
+        self.record(AnalyticsEvent::SearchPerformed {
+            user_id: user_id.clone(),
+            query: query.to_string(),
+            results,
+        });
+        Ok(())
+    }
+}
+
+static ANALYTICS: OnceLock<FileAnalytics> = OnceLock::new();
+
+/// Process-global analytics: a JSONL file in the data directory on desktop,
+/// in memory elsewhere
+pub fn analytics() -> &'static FileAnalytics {
+    ANALYTICS.get_or_init(|| {
+        #[cfg(feature = "desktop")]
+        {
+            FileAnalytics::new(FileAnalytics::default_path())
+        }
+        #[cfg(not(feature = "desktop"))]
+        {
+            FileAnalytics::in_memory()
+        }
+    })
+}
+
+/// Flush the global analytics without blocking the caller
+pub fn flush_analytics() {
+    #[cfg(feature = "desktop")]
+    std::thread::spawn(|| {
+        if let Err(e) = analytics().flush() {
+            eprintln!("Failed to flush analytics: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_log(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("audiolearn-{}-{}.jsonl", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_records_are_json_lines() {
+        let analytics = FileAnalytics::in_memory();
+        analytics.track_lesson_start(&"u1".into(), &"l1".into()).unwrap();
+        analytics.track_tts_played(&"u1".into(), 120).unwrap();
+        analytics.track_search(&"u1".into(), "ownership", 3).unwrap();
+        assert_eq!(analytics.pending(), 3);
+
+        let lines = analytics.flush().unwrap();
+        let values: Vec<serde_json::Value> = lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0]["event"], "lesson_started");
+        assert_eq!(values[0]["lesson_id"], "l1");
+        assert_eq!(values[1]["event"], "tts_played");
+        assert_eq!(values[1]["characters"], 120);
+        assert_eq!(values[2]["query"], "ownership");
+        assert!(values.iter().all(|v| v["timestamp"].is_string()));
+
+        // Lines parse back into records
+        let record: AnalyticsRecord = serde_json::from_str(lines.lines().next().unwrap()).unwrap();
+        assert_eq!(record.event, AnalyticsEvent::LessonStarted { user_id: "u1".into(), lesson_id: "l1".into() });
+    }
+
+    #[test]
+    fn test_flush_clears_buffer() {
+        let analytics = FileAnalytics::in_memory();
+        analytics.track_lesson_complete(&"u1".into(), &"l1".into()).unwrap();
+
+        assert_eq!(analytics.flush().unwrap().lines().count(), 1);
+        assert_eq!(analytics.pending(), 0);
+        assert!(analytics.flush().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_flush_appends_to_file() {
+        let path = temp_log("append");
+        let _ = std::fs::remove_file(&path);
+        let analytics = FileAnalytics::new(&path);
+
+        analytics.track_lesson_start(&"u1".into(), &"l1".into()).unwrap();
+        analytics.flush().unwrap();
+        analytics.track_lesson_complete(&"u1".into(), &"l1".into()).unwrap();
+        analytics.flush().unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let events: Vec<String> = written
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["event"].to_string())
+            .collect();
+        assert_eq!(events, vec!["\"lesson_started\"", "\"lesson_completed\""]);
+        assert_eq!(analytics.pending(), 0);
+    }
+
+    #[test]
+    fn test_buffer_is_bounded() {
+        let analytics = FileAnalytics::in_memory();
+        for i in 0..MAX_BUFFERED_EVENTS + 5 {
+            analytics.track_time_spent(&"u1".into(), i as Seconds).unwrap();
+        }
+        assert_eq!(analytics.pending(), MAX_BUFFERED_EVENTS);
+
+        let lines = analytics.flush().unwrap();
+        let first: AnalyticsRecord = serde_json::from_str(lines.lines().next().unwrap()).unwrap();
+        assert_eq!(first.event, AnalyticsEvent::TimeSpent { user_id: "u1".into(), seconds: 5 });
+    }
+}
@@ -0,0 +1,82 @@
+//! Sentence embeddings from a GPT-2 model, for semantic search
+
+use rustml_core::Tensor;
+use rustml_nlp::{BpeTokenizer, GptModel};
+use crate::common::{AppError, Result};
+use crate::spi::TextEmbedder;
+
+/// Embeds text as the mean of a GPT-2 model's final hidden states
+pub struct GptEmbedder {
+    model: GptModel,
+    tokenizer: BpeTokenizer,
+}
+
+impl GptEmbedder {
+    pub fn new(model: GptModel, tokenizer: BpeTokenizer) -> Self {
+        Self { model, tokenizer }
+    }
+}
+
+impl TextEmbedder for GptEmbedder {
+    /// Text longer than the model's context window is truncated
+    fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let mut ids = self.tokenizer.encode(text);
+        if ids.is_empty() {
+            return Err(AppError::Model("Cannot embed empty text".into()));
+        }
+        ids.truncate(self.model.max_sequence_length());
+        
+        let len = ids.len();
+        let input = Tensor::from_vec(ids.into_iter().map(|id| id as f32).collect(), vec![1, len])
+            .map_err(|e| AppError::Model(e.to_string()))?;
+        let hidden = self.model
+            .forward_hidden(&input)
+            .map_err(|e| AppError::Model(e.to_string()))?;
+        
+        // Mean-pool [1, seq, n_embd] over the sequence
+        let pooled = hidden.mean(1).map_err(|e| AppError::Model(e.to_string()))?;
+        Ok(pooled.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustml_nlp::GptConfig;
+    
+    fn create_test_embedder() -> GptEmbedder {
+        let vocab = r#"{"a": 0, "b": 1, "c": 2, "d": 3, "e": 4, "f": 5, "g": 6, "h": 7}"#;
+        let tokenizer = BpeTokenizer::from_strings(vocab, "#version: 0.2").unwrap();
+        let model = GptModel::new(GptConfig {
+            vocab_size: 8,
+            n_positions: 4,
+            n_embd: 16,
+            n_layer: 1,
+            n_head: 2,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        })
+        .unwrap();
+        GptEmbedder::new(model, tokenizer)
+    }
+    
+    #[test]
+    fn test_embedding_has_model_width() {
+        let embedder = create_test_embedder();
+        let embedding = embedder.embed("abc").unwrap();
+        assert_eq!(embedding.len(), 16);
+        assert!(embedding.iter().all(|x| x.is_finite()));
+        assert_eq!(embedder.embed("abc").unwrap(), embedding);
+    }
+    
+    #[test]
+    fn test_long_text_is_truncated() {
+        let embedder = create_test_embedder();
+        assert_eq!(embedder.embed("abcdefgh").unwrap(), embedder.embed("abcd").unwrap());
+    }
+    
+    #[test]
+    fn test_empty_text_is_an_error() {
+        assert!(matches!(create_test_embedder().embed(""), Err(AppError::Model(_))));
+    }
+}
@@ -0,0 +1,143 @@
+//! Saved custom learning materials
+
+use crate::api::CustomMaterial;
+use crate::common::Result;
+use crate::core::{PlatformStorage, StorageService};
+use crate::spi::Storage;
+
+/// Persists the user's saved [`CustomMaterial`]s as one list
+pub struct MaterialRepository<S: Storage = PlatformStorage> {
+    storage: StorageService<S>,
+}
+
+impl<S: Storage> MaterialRepository<S> {
+    /// Storage key of the material list
+    pub const KEY: &'static str = "custom_materials";
+
+    pub fn new(storage: StorageService<S>) -> Self {
+        Self { storage }
+    }
+
+    /// All saved materials, oldest first
+    pub fn list(&self) -> Result<Vec<CustomMaterial>> {
+        Ok(self.storage.load(Self::KEY)?.unwrap_or_default())
+    }
+
+    /// Material with the given ID
+    pub fn get(&self, id: &str) -> Result<Option<CustomMaterial>> {
+        Ok(self.list()?.into_iter().find(|m| m.id == id))
+    }
+
+    /// Append `material`, renaming its ID if another material already uses
+    /// it. Returns the material as stored.
+    pub fn add(&self, mut material: CustomMaterial) -> Result<CustomMaterial> {
+        let mut materials = self.list()?;
+        material.id = unique_id(&material.id, &materials);
+        materials.push(material.clone());
+        self.storage.save(Self::KEY, &materials)?;
+        Ok(material)
+    }
+
+    /// Delete the material with the given ID; returns whether it existed
+    pub fn remove(&self, id: &str) -> Result<bool> {
+        let mut materials = self.list()?;
+        let before = materials.len();
+        materials.retain(|m| m.id != id);
+        if materials.len() == before {
+            return Ok(false);
+        }
+        self.storage.save(Self::KEY, &materials)?;
+        Ok(true)
+    }
+}
+
+impl<S: Storage + Default> Default for MaterialRepository<S> {
+    fn default() -> Self {
+        Self::new(StorageService::default())
+    }
+}
+
+/// `id`, or `id_2`, `id_3`, ... if it is already taken
+fn unique_id(id: &str, materials: &[CustomMaterial]) -> String {
+    let taken = |candidate: &str| materials.iter().any(|m| m.id == candidate);
+    if !taken(id) {
+        return id.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}_{}", id, n))
+        .find(|candidate| !taken(candidate))
+        .expect("unbounded suffixes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MemoryStorage;
+
+    fn repository() -> MaterialRepository<MemoryStorage> {
+        MaterialRepository::new(StorageService::new(MemoryStorage::new()))
+    }
+
+    fn material(id: &str, title: &str) -> CustomMaterial {
+        CustomMaterial {
+            id: id.to_string(),
+            title: title.to_string(),
+            content: format!("{} content", title),
+            created_at: "2024-12-27 12:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_list_get() {
+        let repo = repository();
+        assert!(repo.list().unwrap().is_empty());
+
+        repo.add(material("custom_1", "First")).unwrap();
+        repo.add(material("custom_2", "Second")).unwrap();
+
+        let titles: Vec<_> = repo.list().unwrap().into_iter().map(|m| m.title).collect();
+        assert_eq!(titles, vec!["First", "Second"]);
+        assert_eq!(repo.get("custom_2").unwrap().unwrap().title, "Second");
+        assert!(repo.get("custom_3").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_remove() {
+        let repo = repository();
+        repo.add(material("custom_1", "First")).unwrap();
+        repo.add(material("custom_2", "Second")).unwrap();
+
+        assert!(repo.remove("custom_1").unwrap());
+        assert!(!repo.remove("custom_1").unwrap());
+        let ids: Vec<_> = repo.list().unwrap().into_iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec!["custom_2"]);
+    }
+
+    #[test]
+    fn test_ids_stay_unique() {
+        let repo = repository();
+        // Saved within the same second, so the timestamp IDs collide
+        let first = repo.add(material("custom_1735300800", "A")).unwrap();
+        let second = repo.add(material("custom_1735300800", "B")).unwrap();
+        let third = repo.add(material("custom_1735300800", "C")).unwrap();
+
+        assert_eq!(first.id, "custom_1735300800");
+        assert_eq!(second.id, "custom_1735300800_2");
+        assert_eq!(third.id, "custom_1735300800_3");
+
+        // Removing one only removes that material
+        repo.remove(&second.id).unwrap();
+        let titles: Vec<_> = repo.list().unwrap().into_iter().map(|m| m.title).collect();
+        assert_eq!(titles, vec!["A", "C"]);
+    }
+
+    #[test]
+    fn test_persists_across_repository_instances() {
+        let storage = StorageService::new(MemoryStorage::new());
+        let repo = MaterialRepository::new(storage);
+        repo.add(material("custom_1", "Kept")).unwrap();
+
+        let reopened = MaterialRepository::new(repo.storage);
+        assert_eq!(reopened.list().unwrap().len(), 1);
+    }
+}
@@ -3,10 +3,20 @@
 //! Contains implementations of the API services.
 
 mod course_service;
+mod course_filter;
 mod sample_data;
 mod playback_state;
 mod search;
 mod settings;
+mod sleep_countdown;
+mod text_chunker;
+mod audio_controller;
+mod storage_service;
+mod material_repository;
+mod file_analytics;
+mod voices;
+mod availability_cache;
+mod ssml;
 
 #[cfg(feature = "desktop")]
 mod rodio_player;
@@ -14,6 +24,10 @@ mod rodio_player;
 mod audio_test;
 #[cfg(feature = "desktop")]
 mod audio_context;
+#[cfg(feature = "desktop")]
+mod pdf_text;
+#[cfg(feature = "semantic-search")]
+mod gpt_embedder;
 
 // Desktop TTS implementations
 #[cfg(feature = "desktop")]
@@ -22,6 +36,8 @@ mod native_tts;
 mod edge_tts;
 #[cfg(feature = "desktop")]
 mod tts_manager;
+#[cfg(feature = "desktop")]
+mod tts_cache;
 
 // Web TTS implementation
 #[cfg(feature = "web")]
@@ -31,10 +47,20 @@ mod web_tts;
 mod tts_tests;
 
 pub use course_service::*;
+pub use course_filter::*;
 pub use sample_data::*;
 pub use playback_state::*;
 pub use search::*;
 pub use settings::*;
+pub use sleep_countdown::*;
+pub use text_chunker::*;
+pub use audio_controller::*;
+pub use storage_service::*;
+pub use material_repository::*;
+pub use file_analytics::*;
+pub use voices::*;
+pub use availability_cache::*;
+pub use ssml::*;
 
 #[cfg(feature = "desktop")]
 pub use rodio_player::*;
@@ -42,6 +68,10 @@ pub use rodio_player::*;
 pub use audio_test::*;
 #[cfg(feature = "desktop")]
 pub use audio_context::*;
+#[cfg(feature = "desktop")]
+pub use pdf_text::*;
+#[cfg(feature = "semantic-search")]
+pub use gpt_embedder::*;
 
 // TTS exports - platform specific
 #[cfg(feature = "desktop")]
@@ -50,6 +80,8 @@ pub use native_tts::*;
 pub use edge_tts::*;
 #[cfg(feature = "desktop")]
 pub use tts_manager::*;
+#[cfg(feature = "desktop")]
+pub use tts_cache::*;
 
 #[cfg(feature = "web")]
 pub use web_tts::*;
@@ -75,10 +107,72 @@ pub fn speak_text(text: &str) -> crate::common::Result<()> {
     }
 }
 
+/// Speak text with custom options using the appropriate TTS for the platform
+pub fn speak_text_with_options(
+    text: &str,
+    options: &crate::spi::tts::SpeechOptions,
+) -> crate::common::Result<()> {
+    #[cfg(feature = "desktop")]
+    {
+        tts_manager::speak_text_with_options(text, options)
+    }
+    #[cfg(feature = "web")]
+    {
+        web_tts::web_speak_text_with_options(text, options)
+    }
+    #[cfg(not(any(feature = "desktop", feature = "web")))]
+    {
+        let _ = (text, options);
+        Err(crate::common::AudioLearnError::Tts("No TTS available".into()))
+    }
+}
+
+/// Speak text, reporting elapsed playback time while it plays
+///
+/// Progress is only reported where playback position is observable (Edge
+/// TTS on desktop); elsewhere this speaks without calling `on_progress`.
+pub fn speak_text_with_progress(
+    text: &str,
+    options: &crate::spi::tts::SpeechOptions,
+    on_progress: impl FnMut(std::time::Duration),
+) -> crate::common::Result<()> {
+    #[cfg(feature = "desktop")]
+    {
+        tts_manager::speak_text_with_progress(text, options, on_progress)
+    }
+    #[cfg(not(feature = "desktop"))]
+    {
+        let _ = on_progress;
+        speak_text_with_options(text, options)
+    }
+}
+
+/// Speak an SSML body (text with `<break>`, `<emphasis>` and the like)
+///
+/// SSML is only supported through Edge TTS on desktop; the markup is
+/// checked to be well-formed before it is sent.
+pub fn speak_ssml(
+    ssml: &str,
+    options: &crate::spi::tts::SpeechOptions,
+) -> crate::common::Result<()> {
+    #[cfg(feature = "desktop")]
+    {
+        tts_manager::speak_ssml(ssml, options)
+    }
+    #[cfg(not(feature = "desktop"))]
+    {
+        let _ = options;
+        validate_ssml(ssml)?;
+        Err(crate::common::AudioLearnError::Tts("SSML playback needs Edge TTS".into()))
+    }
+}
+
 /// Stop TTS playback
 pub fn stop_tts() -> crate::common::Result<()> {
     #[cfg(feature = "desktop")]
     {
+        // Signal any Edge playback driven by the TTS manager as well
+        let _ = tts_manager::stop_tts();
         let mut tts = native_tts::NativeTts::default();
         use crate::spi::tts::TtsEngine;
         tts.stop()
@@ -93,6 +187,54 @@ pub fn stop_tts() -> crate::common::Result<()> {
     }
 }
 
+/// Pause TTS playback, keeping the current position
+pub fn pause_tts() -> crate::common::Result<()> {
+    #[cfg(feature = "desktop")]
+    {
+        tts_manager::pause_tts()
+    }
+    #[cfg(feature = "web")]
+    {
+        web_tts::web_pause_tts()
+    }
+    #[cfg(not(any(feature = "desktop", feature = "web")))]
+    {
+        Ok(())
+    }
+}
+
+/// Resume paused TTS playback
+pub fn resume_tts() -> crate::common::Result<()> {
+    #[cfg(feature = "desktop")]
+    {
+        tts_manager::resume_tts()
+    }
+    #[cfg(feature = "web")]
+    {
+        web_tts::web_resume_tts()
+    }
+    #[cfg(not(any(feature = "desktop", feature = "web")))]
+    {
+        Ok(())
+    }
+}
+
+/// Check whether TTS playback is paused
+pub fn is_tts_paused() -> bool {
+    #[cfg(feature = "desktop")]
+    {
+        tts_manager::is_tts_paused()
+    }
+    #[cfg(feature = "web")]
+    {
+        web_tts::web_is_tts_paused()
+    }
+    #[cfg(not(any(feature = "desktop", feature = "web")))]
+    {
+        false
+    }
+}
+
 /// Get available TTS voices
 pub fn get_tts_voices() -> crate::common::Result<Vec<crate::spi::tts::Voice>> {
     #[cfg(feature = "desktop")]
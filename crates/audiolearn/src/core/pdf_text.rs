@@ -0,0 +1,41 @@
+//! Plain text extraction from PDF documents (desktop only)
+
+use lopdf::Document;
+use crate::common::{AudioLearnError, Result};
+
+/// Extract the text of every page of a PDF, in page order
+///
+/// Fails for documents that can't be parsed and for PDFs without a text
+/// layer (e.g. scanned pages).
+pub fn extract_text_from_pdf(bytes: &[u8]) -> Result<String> {
+    let document = Document::load_mem(bytes)
+        .map_err(|e| AudioLearnError::Io(format!("Failed to read PDF: {}", e)))?;
+
+    let pages: Vec<u32> = document.get_pages().keys().copied().collect();
+    let text = document
+        .extract_text(&pages)
+        .map_err(|e| AudioLearnError::Io(format!("Failed to extract PDF text: {}", e)))?;
+
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(AudioLearnError::Io("PDF contains no text (is it a scanned document?)".into()));
+    }
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_fixture_text() {
+        let text = extract_text_from_pdf(include_bytes!("fixtures/hello.pdf")).unwrap();
+        assert_eq!(text, "Ownership is one of the most unique features of Rust.");
+    }
+
+    #[test]
+    fn test_rejects_non_pdf() {
+        let result = extract_text_from_pdf(b"just some text");
+        assert!(matches!(result, Err(AudioLearnError::Io(_))));
+    }
+}
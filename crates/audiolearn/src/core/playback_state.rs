@@ -49,6 +49,14 @@ impl SleepTimer {
         }
     }
     
+    /// Seconds to count down, given how much of the lesson is left
+    pub fn countdown_seconds(&self, lesson_remaining: Seconds) -> Option<Seconds> {
+        match self {
+            Self::EndOfLesson => Some(lesson_remaining),
+            _ => self.as_seconds(),
+        }
+    }
+    
     pub fn all() -> Vec<Self> {
         vec![
             Self::Off,
@@ -111,6 +119,14 @@ impl LessonProgress {
     }
 }
 
+/// Where the user stopped in a lesson, for resuming it later
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlaybackPosition {
+    pub course_id: Id,
+    pub lesson_id: Id,
+    pub position: Seconds,
+}
+
 /// Current playback context
 #[derive(Clone, Debug, Default)]
 pub struct PlaybackContext {
@@ -177,6 +193,39 @@ impl PlaybackContext {
     }
 }
 
+/// Seconds moved by one arrow key press on a focused progress bar
+pub const SEEK_STEP: Seconds = 5;
+
+/// Position for a click `offset` pixels into a progress bar `width` pixels wide
+pub fn seek_position(offset: f64, width: f64, duration: Seconds) -> Seconds {
+    if width <= 0.0 || !offset.is_finite() {
+        return 0;
+    }
+    let fraction = (offset / width).clamp(0.0, 1.0);
+    (fraction * duration as f64).round() as Seconds
+}
+
+/// Move `position` by `delta` seconds, staying within `0..=duration`
+pub fn seek_by(position: Seconds, delta: i64, duration: Seconds) -> Seconds {
+    (position as i64 + delta).clamp(0, duration as i64) as Seconds
+}
+
+/// Lesson to start once `finished` has been heard to the end
+///
+/// Nothing follows when autoplay is off, when the sleep timer is set to
+/// stop at the end of this lesson, or after the last lesson of the course.
+pub fn autoplay_next<'a>(
+    course: &'a Course,
+    finished: &Id,
+    auto_play_next: bool,
+    sleep_timer: SleepTimer,
+) -> Option<&'a Lesson> {
+    if !auto_play_next || sleep_timer == SleepTimer::EndOfLesson {
+        return None;
+    }
+    course.next_lesson(finished)
+}
+
 /// Persistent playback data
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct PlaybackData {
@@ -207,6 +256,14 @@ impl PlaybackData {
         self.last_played_course = Some(course_id);
     }
     
+    /// Most recently played lesson and its saved position
+    pub fn last_played(&self) -> Option<PlaybackPosition> {
+        let lesson_id = self.last_played_lesson.clone()?;
+        let course_id = self.last_played_course.clone()?;
+        let position = self.lesson_progress.get(&lesson_id).map_or(0, |p| p.position);
+        Some(PlaybackPosition { course_id, lesson_id, position })
+    }
+    
     pub fn add_bookmark(&mut self, lesson_id: Id, timestamp: Timestamp, note: Option<String>) {
         let bookmark = Bookmark {
             id: format!("bm_{}", Utc::now().timestamp_millis()),
@@ -226,6 +283,32 @@ impl PlaybackData {
         self.bookmarks.iter().filter(|b| &b.lesson_id == lesson_id).collect()
     }
     
+    /// Mark a lesson as completed, keeping its saved position
+    ///
+    /// Returns `false` if it was already completed.
+    pub fn mark_complete(&mut self, lesson_id: Id, course_id: Id) -> bool {
+        let progress = self.lesson_progress
+            .entry(lesson_id.clone())
+            .or_insert_with(|| LessonProgress::new(lesson_id, course_id, 0));
+        !std::mem::replace(&mut progress.completed, true)
+    }
+    
+    /// Check whether a lesson has been completed
+    pub fn is_completed(&self, lesson_id: &Id) -> bool {
+        self.lesson_progress.get(lesson_id).is_some_and(|p| p.completed)
+    }
+    
+    /// IDs of every completed lesson, across all courses
+    pub fn completed_lessons(&self) -> Vec<Id> {
+        let mut ids: Vec<Id> = self.lesson_progress
+            .values()
+            .filter(|p| p.completed)
+            .map(|p| p.lesson_id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+    
     pub fn completed_lesson_count(&self) -> usize {
         self.lesson_progress.values().filter(|p| p.completed).count()
     }
@@ -271,9 +354,121 @@ mod tests {
         assert_eq!(bookmarks[0].note, Some("Important point".into()));
     }
     
+    #[test]
+    fn test_new_play_replaces_last_played() {
+        let mut data = PlaybackData::default();
+        assert_eq!(data.last_played(), None);
+        
+        data.update_progress("l1".into(), "c1".into(), 0, 300);
+        data.update_progress("l1".into(), "c1".into(), 120, 300);
+        data.update_progress("l7".into(), "c2".into(), 0, 300);
+        
+        assert_eq!(data.last_played(), Some(PlaybackPosition {
+            course_id: "c2".into(),
+            lesson_id: "l7".into(),
+            position: 0,
+        }));
+        // The earlier lesson keeps its own position
+        assert_eq!(data.get_progress(&"l1".into()).unwrap().position, 120);
+    }
+    
+    #[test]
+    fn test_completed_lessons() {
+        let mut data = PlaybackData::default();
+        data.update_progress("l2".into(), "c1".into(), 100, 100);
+        data.update_progress("l1".into(), "c1".into(), 30, 100);
+        data.update_progress("l3".into(), "c2".into(), 95, 100);
+        
+        assert_eq!(data.completed_lessons(), vec!["l2".to_string(), "l3".to_string()]);
+    }
+    
+    #[test]
+    fn test_mark_complete_is_idempotent() {
+        let mut data = PlaybackData::default();
+        data.update_progress("l1".into(), "c1".into(), 40, 100);
+        assert!(!data.is_completed(&"l1".into()));
+        
+        assert!(data.mark_complete("l1".into(), "c1".into()));
+        assert!(!data.mark_complete("l1".into(), "c1".into()));
+        assert!(data.mark_complete("l2".into(), "c1".into()));
+        
+        assert!(data.is_completed(&"l1".into()));
+        assert!(data.is_completed(&"l2".into()));
+        assert!(!data.is_completed(&"l3".into()));
+        assert_eq!(data.completed_lessons(), vec!["l1".to_string(), "l2".to_string()]);
+        // Completing doesn't lose the resume position
+        assert_eq!(data.get_progress(&"l1".into()).unwrap().position, 40);
+    }
+    
+    #[test]
+    fn test_completing_every_lesson_finishes_course() {
+        let course = crate::core::get_sample_courses().remove(0);
+        let mut data = PlaybackData::default();
+        let lessons: Vec<&Lesson> = course.chapters.iter().flat_map(|c| &c.lessons).collect();
+        
+        for lesson in &lessons[1..] {
+            data.mark_complete(lesson.id.clone(), course.id.clone());
+        }
+        assert!(course.progress(&data.completed_lessons()) < 100.0);
+        
+        data.mark_complete(lessons[0].id.clone(), course.id.clone());
+        assert_eq!(course.progress(&data.completed_lessons()), 100.0);
+    }
+    
     #[test]
     fn test_sleep_timer() {
         assert_eq!(SleepTimer::Minutes15.as_seconds(), Some(15 * 60));
         assert_eq!(SleepTimer::Off.as_seconds(), None);
+        assert_eq!(SleepTimer::Minutes5.countdown_seconds(42), Some(300));
+        assert_eq!(SleepTimer::EndOfLesson.countdown_seconds(42), Some(42));
+        assert_eq!(SleepTimer::Off.countdown_seconds(42), None);
+    }
+
+    #[test]
+    fn test_autoplay_advances_across_chapters() {
+        let course = crate::core::get_sample_courses().remove(0);
+        let lessons: Vec<&Lesson> = course.lessons_in_order().collect();
+        let boundary = course.chapters[0].lessons.last().unwrap();
+        assert!(course.chapters.len() > 1);
+
+        let next = autoplay_next(&course, &lessons[0].id, true, SleepTimer::Off);
+        assert_eq!(next, Some(lessons[1]));
+        let next = autoplay_next(&course, &boundary.id, true, SleepTimer::Minutes30);
+        assert_eq!(next, Some(&course.chapters[1].lessons[0]));
+    }
+
+    #[test]
+    fn test_autoplay_off_stops() {
+        let course = crate::core::get_sample_courses().remove(0);
+        let first = &course.chapters[0].lessons[0];
+        assert_eq!(autoplay_next(&course, &first.id, false, SleepTimer::Off), None);
+        // A timer set to stop after this lesson wins over autoplay
+        assert_eq!(autoplay_next(&course, &first.id, true, SleepTimer::EndOfLesson), None);
+    }
+
+    #[test]
+    fn test_autoplay_halts_after_last_lesson() {
+        let course = crate::core::get_sample_courses().remove(0);
+        let last = course.lessons_in_order().last().unwrap();
+        assert_eq!(autoplay_next(&course, &last.id, true, SleepTimer::Off), None);
+    }
+
+    #[test]
+    fn test_seek_position_from_click() {
+        assert_eq!(seek_position(100.0, 400.0, 600), 150);
+        assert_eq!(seek_position(0.0, 400.0, 600), 0);
+        assert_eq!(seek_position(400.0, 400.0, 600), 600);
+        // Clicks just outside the bar clamp to its ends
+        assert_eq!(seek_position(-3.0, 400.0, 600), 0);
+        assert_eq!(seek_position(410.0, 400.0, 600), 600);
+        // Bar not laid out yet
+        assert_eq!(seek_position(50.0, 0.0, 600), 0);
+    }
+
+    #[test]
+    fn test_seek_by_clamps() {
+        assert_eq!(seek_by(10, SEEK_STEP as i64, 100), 15);
+        assert_eq!(seek_by(3, -(SEEK_STEP as i64), 100), 0);
+        assert_eq!(seek_by(98, SEEK_STEP as i64, 100), 100);
     }
 }
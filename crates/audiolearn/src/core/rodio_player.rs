@@ -1,7 +1,103 @@
-//! Rodio-based audio player (placeholder for platform-specific implementation)
+//! Rodio-backed [`AudioSink`] for [`AudioController`]
 //!
-//! Note: rodio's OutputStream is not Send+Sync on Windows, so we use
-//! the audio_context module with global state instead.
+//! Note: rodio's OutputStream is not Send+Sync on Windows, so the stream is
+//! kept alive on a dedicated thread and only its (thread-safe) handle is
+//! shared with sinks.
 
-// This module is kept as documentation for the intended API design.
-// The actual implementation is in audio_context.rs
+use std::io::Cursor;
+use std::sync::{mpsc, OnceLock};
+use std::time::Duration;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use crate::common::{AppError, Result};
+use crate::core::{AudioController, AudioSink};
+
+/// Handle to the process-wide output stream
+fn output_handle() -> Result<&'static OutputStreamHandle> {
+    static HANDLE: OnceLock<std::result::Result<OutputStreamHandle, String>> = OnceLock::new();
+
+    HANDLE
+        .get_or_init(|| {
+            let (tx, rx) = mpsc::channel();
+            std::thread::spawn(move || match OutputStream::try_default() {
+                Ok((_stream, handle)) => {
+                    let _ = tx.send(Ok(handle));
+                    // Keep the stream alive for the rest of the process
+                    loop {
+                        std::thread::park();
+                    }
+                }
+                Err(e) => {
+                    let _ = tx.send(Err(format!("Failed to open audio output: {}", e)));
+                }
+            });
+            rx.recv().unwrap_or_else(|_| Err("Audio output thread exited".to_string()))
+        })
+        .as_ref()
+        .map_err(|e| AppError::Audio(e.clone()))
+}
+
+/// Plays audio through the default output device
+pub struct RodioSink {
+    sink: Sink,
+}
+
+impl RodioSink {
+    pub fn try_default() -> Result<Self> {
+        let sink = Sink::try_new(output_handle()?)
+            .map_err(|e| AppError::Audio(format!("Failed to create sink: {}", e)))?;
+        sink.pause();
+        Ok(Self { sink })
+    }
+}
+
+impl AudioSink for RodioSink {
+    fn load(&mut self, bytes: &[u8], start: Duration) -> Result<Option<Duration>> {
+        let source = Decoder::new(Cursor::new(bytes.to_vec()))
+            .map_err(|e| AppError::Audio(format!("Failed to decode audio: {}", e)))?;
+        let total = source.total_duration();
+
+        self.sink.clear();
+        self.sink.append(source.skip_duration(start));
+        self.sink.pause();
+        Ok(total)
+    }
+
+    fn play(&mut self) {
+        self.sink.play();
+    }
+
+    fn pause(&mut self) {
+        self.sink.pause();
+    }
+
+    fn stop(&mut self) {
+        self.sink.clear();
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.sink.set_speed(speed);
+    }
+
+    fn try_seek(&mut self, position: Duration) -> Result<()> {
+        self.sink
+            .try_seek(position)
+            .map_err(|e| AppError::Audio(format!("Seek failed: {}", e)))
+    }
+
+    fn position(&self) -> Duration {
+        self.sink.get_pos()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.sink.empty()
+    }
+}
+
+/// Controller playing through the default output device
+pub type RodioAudioController = AudioController<RodioSink>;
+
+impl RodioAudioController {
+    pub fn try_default() -> Result<Self> {
+        Ok(AudioController::new(RodioSink::try_default()?))
+    }
+}
@@ -1,7 +1,10 @@
 //! Search functionality for courses and lessons
 
+use std::collections::HashMap;
+use std::sync::Arc;
 use crate::api::{Course, Lesson};
 use crate::common::Id;
+use crate::spi::TextEmbedder;
 
 /// Search result item
 #[derive(Clone, Debug, PartialEq)]
@@ -10,11 +13,53 @@ pub enum SearchResult {
     Lesson(LessonSearchResult),
 }
 
+impl SearchResult {
+    /// Relevance score of this result
+    pub fn score(&self) -> f32 {
+        match self {
+            SearchResult::Course(c) => c.match_score,
+            SearchResult::Lesson(l) => l.match_score,
+        }
+    }
+}
+
+/// Restricts which kinds of results a search returns
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchFilter {
+    #[default]
+    All,
+    CoursesOnly,
+    LessonsOnly,
+}
+
+impl SearchFilter {
+    /// Check if a result passes the filter
+    pub fn accepts(&self, result: &SearchResult) -> bool {
+        matches!(
+            (self, result),
+            (SearchFilter::All, _)
+                | (SearchFilter::CoursesOnly, SearchResult::Course(_))
+                | (SearchFilter::LessonsOnly, SearchResult::Lesson(_))
+        )
+    }
+}
+
+/// Matched byte ranges per field name (only exact matches in single-string fields)
+pub type Highlights = Vec<(String, Vec<(usize, usize)>)>;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CourseSearchResult {
     pub course: Course,
     pub match_score: f32,
     pub matched_fields: Vec<String>,
+    pub highlights: Highlights,
+}
+
+impl CourseSearchResult {
+    /// Matched byte ranges within a field (empty if none)
+    pub fn highlights_for(&self, field: &str) -> &[(usize, usize)] {
+        highlights_for(&self.highlights, field)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -24,23 +69,151 @@ pub struct LessonSearchResult {
     pub course_title: String,
     pub match_score: f32,
     pub matched_fields: Vec<String>,
+    pub highlights: Highlights,
+}
+
+impl LessonSearchResult {
+    /// Matched byte ranges within a field (empty if none)
+    pub fn highlights_for(&self, field: &str) -> &[(usize, usize)] {
+        highlights_for(&self.highlights, field)
+    }
+}
+
+fn highlights_for<'a>(highlights: &'a Highlights, field: &str) -> &'a [(usize, usize)] {
+    highlights
+        .iter()
+        .find(|(name, _)| name == field)
+        .map(|(_, ranges)| ranges.as_slice())
+        .unwrap_or(&[])
+}
+
+/// Score multiplier for fuzzy (typo-tolerant) matches relative to exact ones
+const FUZZY_SCORE_FACTOR: f32 = 0.5;
+
+/// Score of a perfect semantic match (cosine similarity 1.0), on the same
+/// scale as keyword scores: worth as much as a course title hit
+const SEMANTIC_SCORE_WEIGHT: f32 = 10.0;
+
+/// Cosine similarity below which content isn't considered related
+const MIN_SEMANTIC_SIMILARITY: f32 = 0.5;
+
+/// Cosine similarity of two vectors (0.0 if either is zero or their lengths differ)
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Precomputed embeddings of every course and lesson
+#[derive(Clone)]
+struct SemanticIndex {
+    embedder: Arc<dyn TextEmbedder>,
+    courses: HashMap<Id, Vec<f32>>,
+    /// Keyed by (course id, lesson id)
+    lessons: HashMap<(Id, Id), Vec<f32>>,
+}
+
+impl SemanticIndex {
+    /// Embed the title and description of all content
+    ///
+    /// Items the embedder fails on are left out and only match by keyword.
+    fn build(embedder: Arc<dyn TextEmbedder>, courses: &[Course]) -> Self {
+        let mut index = Self { embedder, courses: HashMap::new(), lessons: HashMap::new() };
+        for course in courses {
+            if let Ok(embedding) = index.embedder.embed(&embedding_text(&course.title, Some(&course.description))) {
+                index.courses.insert(course.id.clone(), embedding);
+            }
+            for lesson in course.chapters.iter().flat_map(|chapter| &chapter.lessons) {
+                let text = embedding_text(&lesson.title, lesson.description.as_deref());
+                if let Ok(embedding) = index.embedder.embed(&text) {
+                    index.lessons.insert((course.id.clone(), lesson.id.clone()), embedding);
+                }
+            }
+        }
+        index
+    }
+}
+
+impl PartialEq for SemanticIndex {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.embedder, &other.embedder)
+            && self.courses == other.courses
+            && self.lessons == other.lessons
+    }
+}
+
+/// Text embedded for a course or lesson
+fn embedding_text(title: &str, description: Option<&str>) -> String {
+    match description {
+        Some(description) if !description.is_empty() => format!("{}. {}", title, description),
+        _ => title.to_string(),
+    }
+}
+
+/// Score contribution of a similarity, or 0.0 when the content isn't related
+fn semantic_score(embedding: Option<&Vec<f32>>, query: Option<&[f32]>) -> f32 {
+    let (Some(embedding), Some(query)) = (embedding, query) else {
+        return 0.0;
+    };
+    let similarity = cosine_similarity(embedding, query);
+    if similarity >= MIN_SEMANTIC_SIMILARITY {
+        SEMANTIC_SCORE_WEIGHT * similarity
+    } else {
+        0.0
+    }
 }
 
 /// Search engine for courses and lessons
 #[derive(Clone, PartialEq)]
 pub struct SearchEngine {
     courses: Vec<Course>,
+    fuzzy: bool,
+    min_score: f32,
+    semantic: Option<SemanticIndex>,
 }
 
 impl SearchEngine {
     pub fn new(courses: Vec<Course>) -> Self {
-        Self { courses }
+        Self { courses, fuzzy: false, min_score: 0.0, semantic: None }
+    }
+    
+    /// Also rank by meaning, using embeddings of course and lesson titles
+    /// and descriptions
+    ///
+    /// Results are scored by keyword matches plus their similarity to the
+    /// query, so related content is found even without shared words. All
+    /// content is embedded up front (and again on `update_courses`).
+    pub fn with_embeddings(mut self, embedder: impl TextEmbedder + 'static) -> Self {
+        self.semantic = Some(SemanticIndex::build(Arc::new(embedder), &self.courses));
+        self
+    }
+    
+    /// Enable or disable typo-tolerant matching
+    pub fn with_fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
     }
     
     pub fn update_courses(&mut self, courses: Vec<Course>) {
+        if let Some(semantic) = &mut self.semantic {
+            *semantic = SemanticIndex::build(semantic.embedder.clone(), &courses);
+        }
         self.courses = courses;
     }
     
+    /// Drop results scoring below `min_score` (default 0.0 keeps every match)
+    pub fn with_min_score(mut self, min_score: f32) -> Self {
+        self.min_score = min_score;
+        self
+    }
+    
     /// Search all content with a query string
     pub fn search(&self, query: &str) -> Vec<SearchResult> {
         if query.trim().is_empty() {
@@ -49,41 +222,89 @@ impl SearchEngine {
         
         let query_lower = query.to_lowercase();
         let terms: Vec<&str> = query_lower.split_whitespace().collect();
+        let query_embedding = self.semantic
+            .as_ref()
+            .and_then(|semantic| semantic.embedder.embed(query).ok());
+        let query_embedding = query_embedding.as_deref();
         
         let mut results = Vec::new();
         
         for course in &self.courses {
             // Search course
-            if let Some(result) = self.search_course(course, &terms) {
-                results.push(SearchResult::Course(result));
+            let similarity = semantic_score(
+                self.semantic.as_ref().and_then(|s| s.courses.get(&course.id)),
+                query_embedding,
+            );
+            match self.search_course(course, &terms) {
+                Some(mut result) => {
+                    result.match_score += similarity;
+                    results.push(SearchResult::Course(result));
+                }
+                None if similarity > 0.0 => results.push(SearchResult::Course(CourseSearchResult {
+                    course: course.clone(),
+                    match_score: similarity,
+                    matched_fields: Vec::new(),
+                    highlights: Vec::new(),
+                })),
+                None => {}
             }
             
             // Search lessons within course
             for chapter in &course.chapters {
                 for lesson in &chapter.lessons {
-                    if let Some(result) = self.search_lesson(lesson, course, &terms) {
-                        results.push(SearchResult::Lesson(result));
+                    let similarity = semantic_score(
+                        self.semantic
+                            .as_ref()
+                            .and_then(|s| s.lessons.get(&(course.id.clone(), lesson.id.clone()))),
+                        query_embedding,
+                    );
+                    match self.search_lesson(lesson, course, &terms) {
+                        Some(mut result) => {
+                            result.match_score += similarity;
+                            results.push(SearchResult::Lesson(result));
+                        }
+                        None if similarity > 0.0 => results.push(SearchResult::Lesson(LessonSearchResult {
+                            lesson: lesson.clone(),
+                            course_id: course.id.clone(),
+                            course_title: course.title.clone(),
+                            match_score: similarity,
+                            matched_fields: Vec::new(),
+                            highlights: Vec::new(),
+                        })),
+                        None => {}
                     }
                 }
             }
         }
         
-        // Sort by score (descending)
-        results.sort_by(|a, b| {
-            let score_a = match a {
-                SearchResult::Course(c) => c.match_score,
-                SearchResult::Lesson(l) => l.match_score,
-            };
-            let score_b = match b {
-                SearchResult::Course(c) => c.match_score,
-                SearchResult::Lesson(l) => l.match_score,
-            };
-            score_b.partial_cmp(&score_a).unwrap()
-        });
+        // Drop weak matches, then sort by score (descending)
+        results.retain(|result| result.score() >= self.min_score);
+        results.sort_by(|a, b| b.score().partial_cmp(&a.score()).unwrap());
         
         results
     }
     
+    /// Search and return one page of filtered results plus the total match count
+    ///
+    /// Results keep the score ordering of [`search`](Self::search); an offset past
+    /// the end yields an empty page.
+    pub fn search_paged(
+        &self,
+        query: &str,
+        filter: SearchFilter,
+        offset: usize,
+        limit: usize,
+    ) -> (Vec<SearchResult>, usize) {
+        let results: Vec<SearchResult> = self.search(query)
+            .into_iter()
+            .filter(|result| filter.accepts(result))
+            .collect();
+        let total = results.len();
+        
+        let page = results.into_iter().skip(offset).take(limit).collect();
+        (page, total)
+    }
+    
     fn search_course(&self, course: &Course, terms: &[&str]) -> Option<CourseSearchResult> {
         let mut score = 0.0;
         let mut matched_fields = Vec::new();
@@ -94,46 +315,53 @@ impl SearchEngine {
         
         for term in terms {
             // Title match (highest weight)
-            if title_lower.contains(term) {
-                score += 10.0;
+            if let Some(factor) = self.term_match(&title_lower, term) {
+                score += 10.0 * factor;
                 if !matched_fields.contains(&"title".to_string()) {
                     matched_fields.push("title".to_string());
                 }
             }
             
             // Author match
-            if author_lower.contains(term) {
-                score += 5.0;
+            if let Some(factor) = self.term_match(&author_lower, term) {
+                score += 5.0 * factor;
                 if !matched_fields.contains(&"author".to_string()) {
                     matched_fields.push("author".to_string());
                 }
             }
             
             // Description match
-            if desc_lower.contains(term) {
-                score += 3.0;
+            if let Some(factor) = self.term_match(&desc_lower, term) {
+                score += 3.0 * factor;
                 if !matched_fields.contains(&"description".to_string()) {
                     matched_fields.push("description".to_string());
                 }
             }
             
-            // Tag match
-            for tag in &course.tags {
-                if tag.to_lowercase().contains(term) {
-                    score += 7.0;
-                    if !matched_fields.contains(&"tags".to_string()) {
-                        matched_fields.push("tags".to_string());
-                    }
-                    break;
+            // Tag match (best matching tag only)
+            let tag_factor = course.tags
+                .iter()
+                .filter_map(|tag| self.term_match(&tag.to_lowercase(), term))
+                .reduce(f32::max);
+            if let Some(factor) = tag_factor {
+                score += 7.0 * factor;
+                if !matched_fields.contains(&"tags".to_string()) {
+                    matched_fields.push("tags".to_string());
                 }
             }
         }
         
         if score > 0.0 {
+            let mut highlights = Vec::new();
+            add_highlights(&mut highlights, "title", &course.title, terms);
+            add_highlights(&mut highlights, "author", &course.author.name, terms);
+            add_highlights(&mut highlights, "description", &course.description, terms);
+            
             Some(CourseSearchResult {
                 course: course.clone(),
                 match_score: score,
                 matched_fields,
+                highlights,
             })
         } else {
             None
@@ -149,48 +377,70 @@ impl SearchEngine {
         
         for term in terms {
             // Title match
-            if title_lower.contains(term) {
-                score += 8.0;
+            if let Some(factor) = self.term_match(&title_lower, term) {
+                score += 8.0 * factor;
                 if !matched_fields.contains(&"title".to_string()) {
                     matched_fields.push("title".to_string());
                 }
             }
             
             // Description match
-            if desc_lower.contains(term) {
-                score += 2.0;
+            if let Some(factor) = self.term_match(&desc_lower, term) {
+                score += 2.0 * factor;
                 if !matched_fields.contains(&"description".to_string()) {
                     matched_fields.push("description".to_string());
                 }
             }
             
-            // Transcript match
+            // Transcript match (best matching segment only)
             if let Some(transcript) = &lesson.transcript {
-                for segment in transcript {
-                    if segment.text.to_lowercase().contains(term) {
-                        score += 1.0;
-                        if !matched_fields.contains(&"transcript".to_string()) {
-                            matched_fields.push("transcript".to_string());
-                        }
-                        break;
+                let segment_factor = transcript
+                    .iter()
+                    .filter_map(|segment| self.term_match(&segment.text.to_lowercase(), term))
+                    .reduce(f32::max);
+                if let Some(factor) = segment_factor {
+                    score += factor;
+                    if !matched_fields.contains(&"transcript".to_string()) {
+                        matched_fields.push("transcript".to_string());
                     }
                 }
             }
         }
         
         if score > 0.0 {
+            let mut highlights = Vec::new();
+            add_highlights(&mut highlights, "title", &lesson.title, terms);
+            if let Some(description) = &lesson.description {
+                add_highlights(&mut highlights, "description", description, terms);
+            }
+            
             Some(LessonSearchResult {
                 lesson: lesson.clone(),
                 course_id: course.id.clone(),
                 course_title: course.title.clone(),
                 match_score: score,
                 matched_fields,
+                highlights,
             })
         } else {
             None
         }
     }
     
+    /// How strongly a term matches lowercased text
+    ///
+    /// Returns 1.0 for a substring match, a reduced factor for a fuzzy match
+    /// (when enabled), and None otherwise.
+    fn term_match(&self, text: &str, term: &str) -> Option<f32> {
+        if text.contains(term) {
+            Some(1.0)
+        } else if self.fuzzy && fuzzy_contains(text, term) {
+            Some(FUZZY_SCORE_FACTOR)
+        } else {
+            None
+        }
+    }
+    
     /// Get search suggestions based on partial query
     pub fn suggest(&self, query: &str, limit: usize) -> Vec<String> {
         if query.trim().is_empty() {
@@ -219,10 +469,128 @@ impl SearchEngine {
     }
 }
 
+/// Record the ranges of `text` matching any term under `field`, if there are any
+fn add_highlights(highlights: &mut Highlights, field: &str, text: &str, terms: &[&str]) {
+    let mut ranges: Vec<(usize, usize)> = terms
+        .iter()
+        .flat_map(|term| match_ranges(text, term))
+        .collect();
+    if ranges.is_empty() {
+        return;
+    }
+    
+    // Merge overlapping ranges from different terms
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    
+    highlights.push((field.to_string(), merged));
+}
+
+/// Byte ranges in `text` where the lowercase `term` occurs, ignoring case
+///
+/// Ranges are computed against the original text, so they always fall on
+/// char boundaries even when lowercasing changes byte lengths.
+pub fn match_ranges(text: &str, term: &str) -> Vec<(usize, usize)> {
+    if term.is_empty() {
+        return Vec::new();
+    }
+    
+    // Lowercased text plus, for each of its bytes, the original char's byte range
+    let mut lower = String::with_capacity(text.len());
+    let mut origin = Vec::with_capacity(text.len());
+    for (start, ch) in text.char_indices() {
+        let end = start + ch.len_utf8();
+        for lc in ch.to_lowercase() {
+            origin.extend(std::iter::repeat_n((start, end), lc.len_utf8()));
+            lower.push(lc);
+        }
+    }
+    
+    lower
+        .match_indices(term)
+        .map(|(i, m)| (origin[i].0, origin[i + m.len() - 1].1))
+        .collect()
+}
+
+/// Split text into (segment, is_match) pieces for rendering highlights
+pub fn highlight_segments<'a>(text: &'a str, ranges: &[(usize, usize)]) -> Vec<(&'a str, bool)> {
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    
+    for &(start, end) in ranges {
+        if start < pos || end > text.len() || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+            continue;
+        }
+        if start > pos {
+            segments.push((&text[pos..start], false));
+        }
+        if end > start {
+            segments.push((&text[start..end], true));
+        }
+        pos = end;
+    }
+    
+    if pos < text.len() {
+        segments.push((&text[pos..], false));
+    }
+    segments
+}
+
+/// Number of typos tolerated for a term of the given length
+fn max_typos(term_len: usize) -> usize {
+    match term_len {
+        0..=3 => 0,
+        4..=5 => 1,
+        _ => 2,
+    }
+}
+
+/// Check if any word in `text` starts with something within edit distance of `term`
+fn fuzzy_contains(text: &str, term: &str) -> bool {
+    let term: Vec<char> = term.chars().collect();
+    let max = max_typos(term.len());
+    if max == 0 {
+        return false;
+    }
+    
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .any(|word| {
+            let word: Vec<char> = word.chars().collect();
+            // Compare against word prefixes of similar length so "ownser" finds "ownership"
+            let shortest = term.len().saturating_sub(max).max(1);
+            let longest = (term.len() + max).min(word.len());
+            (shortest..=longest).any(|len| levenshtein(&term, &word[..len]) <= max)
+        })
+}
+
+/// Edit distance between two character sequences
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::Author;
+    use crate::api::{Author, Chapter};
     use crate::common::Difficulty;
     
     fn create_test_course() -> Course {
@@ -276,4 +644,280 @@ mod tests {
         
         assert!(!suggestions.is_empty());
     }
+    
+    fn course_with_title(id: &str, title: &str) -> Course {
+        Course {
+            id: id.into(),
+            title: title.into(),
+            ..create_test_course()
+        }
+    }
+    
+    #[test]
+    fn test_fuzzy_matches_typo() {
+        let courses = vec![course_with_title("c2", "Ownership and Borrowing")];
+        
+        // Exact-only search misses the typo
+        assert!(SearchEngine::new(courses.clone()).search("ownser").is_empty());
+        
+        let engine = SearchEngine::new(courses).with_fuzzy(true);
+        let results = engine.search("ownser");
+        assert_eq!(results.len(), 1);
+        if let SearchResult::Course(result) = &results[0] {
+            assert_eq!(result.course.id, "c2");
+            assert!(result.matched_fields.contains(&"title".to_string()));
+        }
+        
+        assert!(engine.search("xyz").is_empty());
+    }
+    
+    #[test]
+    fn test_exact_ranks_above_fuzzy() {
+        let engine = SearchEngine::new(vec![
+            course_with_title("typo", "Understanding Ownrship"),
+            course_with_title("exact", "Understanding Ownership"),
+        ]).with_fuzzy(true);
+        
+        let results = engine.search("ownership");
+        assert_eq!(results.len(), 2);
+        
+        let ids: Vec<&str> = results.iter().map(|r| match r {
+            SearchResult::Course(c) => c.course.id.as_str(),
+            SearchResult::Lesson(l) => l.lesson.id.as_str(),
+        }).collect();
+        assert_eq!(ids, vec!["exact", "typo"]);
+    }
+    
+    #[test]
+    fn test_highlight_title_substring() {
+        let engine = SearchEngine::new(vec![create_test_course()]);
+        let results = engine.search("program");
+        
+        let SearchResult::Course(result) = &results[0] else {
+            panic!("expected a course result");
+        };
+        // "Rust Programming Basics"
+        assert_eq!(result.highlights_for("title"), &[(5, 12)]);
+        assert_eq!(&result.course.title[5..12], "Program");
+        assert!(result.highlights_for("author").is_empty());
+    }
+    
+    #[test]
+    fn test_highlight_multiple_terms() {
+        let engine = SearchEngine::new(vec![create_test_course()]);
+        let results = engine.search("rust basics");
+        
+        let SearchResult::Course(result) = &results[0] else {
+            panic!("expected a course result");
+        };
+        assert_eq!(result.highlights_for("title"), &[(0, 4), (17, 23)]);
+        // "Learn Rust from scratch"
+        assert_eq!(result.highlights_for("description"), &[(6, 10)]);
+        
+        let segments = highlight_segments(&result.course.title, result.highlights_for("title"));
+        assert_eq!(segments, vec![("Rust", true), (" Programming ", false), ("Basics", true)]);
+    }
+    
+    #[test]
+    fn test_match_ranges_unicode_boundaries() {
+        // 'İ' lowercases to two chars with a different byte length
+        let text = "İstanbul Café CAFÉ";
+        let ranges = match_ranges(text, "café");
+        assert_eq!(ranges.len(), 2);
+        for (start, end) in ranges {
+            assert!(text.is_char_boundary(start) && text.is_char_boundary(end));
+            assert_eq!(text[start..end].to_lowercase(), "café");
+        }
+        
+        let ranges = match_ranges(text, "stan");
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(&text[ranges[0].0..ranges[0].1], "stan");
+    }
+    
+    #[test]
+    fn test_levenshtein() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars("ownser"), &chars("owner")), 1);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("same"), &chars("same")), 0);
+    }
+    
+    fn create_test_lesson(id: &str, title: &str) -> Lesson {
+        Lesson {
+            id: id.into(),
+            chapter_id: "ch1".into(),
+            title: title.into(),
+            description: None,
+            audio_url: "".into(),
+            duration: 300,
+            transcript: None,
+            quiz: None,
+            order: 1,
+        }
+    }
+    
+    fn create_course_with_lessons() -> Course {
+        Course {
+            chapters: vec![Chapter {
+                id: "ch1".into(),
+                title: "Getting Started".into(),
+                description: None,
+                lessons: vec![
+                    create_test_lesson("l1", "Installing Rust"),
+                    create_test_lesson("l2", "Hello Rust"),
+                    create_test_lesson("l3", "Rust Tooling"),
+                    create_test_lesson("l4", "Cargo Basics"),
+                ],
+            }],
+            ..create_test_course()
+        }
+    }
+    
+    fn result_id(result: &SearchResult) -> String {
+        match result {
+            SearchResult::Course(c) => format!("course:{}", c.course.id),
+            SearchResult::Lesson(l) => format!("lesson:{}", l.lesson.id),
+        }
+    }
+    
+    #[test]
+    fn test_search_filter_lessons_only() {
+        let engine = SearchEngine::new(vec![create_course_with_lessons()]);
+        
+        let (all, all_total) = engine.search_paged("rust", SearchFilter::All, 0, 100);
+        assert_eq!(all_total, 4); // course + 3 lessons
+        assert!(all.iter().any(|r| matches!(r, SearchResult::Course(_))));
+        
+        let (lessons, total) = engine.search_paged("rust", SearchFilter::LessonsOnly, 0, 100);
+        assert_eq!(total, 3);
+        assert!(lessons.iter().all(|r| matches!(r, SearchResult::Lesson(_))));
+        
+        let (courses, total) = engine.search_paged("rust", SearchFilter::CoursesOnly, 0, 100);
+        assert_eq!(total, 1);
+        assert!(matches!(courses[0], SearchResult::Course(_)));
+    }
+    
+    #[test]
+    fn test_search_pages_reassemble() {
+        let engine = SearchEngine::new(vec![create_course_with_lessons()]);
+        let full: Vec<String> = engine.search("rust").iter().map(result_id).collect();
+        
+        let mut reassembled = Vec::new();
+        for offset in (0..full.len()).step_by(3) {
+            let (page, total) = engine.search_paged("rust", SearchFilter::All, offset, 3);
+            assert_eq!(total, full.len());
+            assert!(page.len() <= 3);
+            reassembled.extend(page.iter().map(result_id));
+        }
+        assert_eq!(reassembled, full);
+        
+        // Out-of-range offsets give an empty page, not an error
+        let (page, total) = engine.search_paged("rust", SearchFilter::All, 50, 3);
+        assert!(page.is_empty());
+        assert_eq!(total, full.len());
+    }
+    
+    #[test]
+    fn test_min_score_prunes_weak_matches() {
+        let mut course = create_course_with_lessons();
+        course.chapters[0].lessons.push(Lesson {
+            description: Some("Why teams pick Rust".into()),
+            ..create_test_lesson("l5", "Choosing a Language")
+        });
+        
+        // Default threshold keeps the description-only lesson match
+        let engine = SearchEngine::new(vec![course.clone()]);
+        let ids: Vec<String> = engine.search("rust").iter().map(result_id).collect();
+        assert!(ids.contains(&"lesson:l5".to_string()));
+        
+        let engine = SearchEngine::new(vec![course]).with_min_score(5.0);
+        let results = engine.search("rust");
+        let ids: Vec<String> = results.iter().map(result_id).collect();
+        assert!(!ids.contains(&"lesson:l5".to_string()));
+        assert!(ids.contains(&"course:c1".to_string()));
+        assert!(ids.contains(&"lesson:l1".to_string()));
+        assert!(results.iter().all(|r| r.score() >= 5.0));
+    }
+    
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((cosine_similarity(&[1.0, 2.0], &[2.0, 4.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 3.0]).abs() < 1e-6);
+        assert!((cosine_similarity(&[1.0, 1.0], &[-1.0, -1.0]) + 1.0).abs() < 1e-6);
+        // Degenerate inputs have no similarity
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+        assert_eq!(cosine_similarity(&[1.0], &[1.0, 1.0]), 0.0);
+    }
+    
+    /// Embeds text as counts of words from a few hand-picked topics
+    struct TopicEmbedder;
+    
+    impl TextEmbedder for TopicEmbedder {
+        fn embed(&self, text: &str) -> crate::common::Result<Vec<f32>> {
+            const TOPICS: [&[&str]; 3] = [
+                &["memory", "ownership", "borrowing", "pointers", "lifetimes"],
+                &["cooking", "recipes", "pasta", "kitchen", "sauce"],
+                &["guitar", "chords", "music", "melody"],
+            ];
+            let text = text.to_lowercase();
+            Ok(TOPICS
+                .iter()
+                .map(|words| words.iter().filter(|w| text.contains(*w)).count() as f32)
+                .collect())
+        }
+    }
+    
+    #[test]
+    fn test_semantic_search_finds_related_course_without_keywords() {
+        let rust = Course {
+            description: "Ownership, borrowing and lifetimes explained".into(),
+            tags: vec![],
+            ..course_with_title("c1", "Rust in Depth")
+        };
+        let cooking = Course {
+            description: "Pasta and sauce recipes from an Italian kitchen".into(),
+            tags: vec![],
+            ..course_with_title("c2", "Cooking at Home")
+        };
+        let courses = vec![cooking, rust];
+        
+        // No course mentions "memory" or "pointers"
+        assert!(SearchEngine::new(courses.clone()).search("memory pointers").is_empty());
+        
+        let engine = SearchEngine::new(courses).with_embeddings(TopicEmbedder);
+        let results = engine.search("memory pointers");
+        let ids: Vec<String> = results.iter().map(result_id).collect();
+        assert_eq!(ids.first().map(String::as_str), Some("course:c1"));
+        let cooking_rank = ids.iter().position(|id| id == "course:c2");
+        assert!(cooking_rank.is_none_or(|rank| rank > 0));
+    }
+    
+    #[test]
+    fn test_semantic_score_adds_to_keyword_matches() {
+        let rust = Course {
+            description: "Ownership and borrowing".into(),
+            ..course_with_title("c1", "Rust Basics")
+        };
+        let keyword_only = SearchEngine::new(vec![rust.clone()]).search("rust ownership");
+        let blended = SearchEngine::new(vec![rust]).with_embeddings(TopicEmbedder).search("rust ownership");
+        
+        assert_eq!(keyword_only.len(), 1);
+        assert_eq!(blended.len(), 1);
+        assert!(blended[0].score() > keyword_only[0].score());
+    }
+    
+    #[test]
+    fn test_update_courses_reindexes_embeddings() {
+        let mut engine = SearchEngine::new(Vec::new()).with_embeddings(TopicEmbedder);
+        assert!(engine.search("guitar").is_empty());
+        
+        engine.update_courses(vec![Course {
+            description: "Chords and melody for beginners".into(),
+            tags: vec![],
+            ..course_with_title("c3", "First Steps")
+        }]);
+        let ids: Vec<String> = engine.search("music").iter().map(result_id).collect();
+        assert_eq!(ids, vec!["course:c3"]);
+    }
 }
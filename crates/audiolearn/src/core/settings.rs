@@ -1,8 +1,11 @@
 //! Settings and preferences persistence
 
 use serde::{Deserialize, Serialize};
-use crate::common::PlaybackSpeed;
-use crate::core::playback_state::{PlaybackData, SleepTimer};
+use crate::common::{Id, PlaybackSpeed, Result};
+use crate::core::playback_state::{PlaybackData, PlaybackPosition, SleepTimer};
+use crate::core::storage_service::{PlatformStorage, StorageService};
+use crate::spi::Storage;
+use crate::spi::tts::SpeechOptions;
 
 /// User preferences
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -82,6 +85,12 @@ impl Default for PlaybackSettings {
     }
 }
 
+/// Default character limit when speaking with Edge TTS
+pub const EDGE_TTS_MAX_CHARS: usize = 10_000;
+
+/// Default character limit when speaking with a native or browser engine
+pub const NATIVE_TTS_MAX_CHARS: usize = 5_000;
+
 /// TTS settings
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TtsSettings {
@@ -95,6 +104,37 @@ pub struct TtsSettings {
     pub volume: f32,
     /// Use Edge TTS (higher quality, requires internet)
     pub use_edge_tts: bool,
+    /// Character limit for text to speak (None = the engine's default)
+    #[serde(default)]
+    pub max_tts_chars: Option<usize>,
+}
+
+impl TtsSettings {
+    /// Options to speak with, using the saved rate and volume
+    pub fn speech_options(&self) -> SpeechOptions {
+        SpeechOptions {
+            rate: self.rate.clamp(0.5, 2.0),
+            volume: self.volume.clamp(0.0, 1.0),
+            ..SpeechOptions::default()
+        }
+    }
+    
+    /// Character limit in effect for the selected engine
+    pub fn effective_max_chars(&self) -> usize {
+        self.max_tts_chars.unwrap_or(if self.use_edge_tts {
+            EDGE_TTS_MAX_CHARS
+        } else {
+            NATIVE_TTS_MAX_CHARS
+        })
+    }
+    
+    /// Compare the length of `text` against the effective limit
+    pub fn check_length(&self, text: &str) -> TtsLength {
+        TtsLength {
+            chars: text.chars().count(),
+            limit: self.effective_max_chars(),
+        }
+    }
 }
 
 impl Default for TtsSettings {
@@ -105,10 +145,46 @@ impl Default for TtsSettings {
             pitch: 1.0,
             volume: 1.0,
             use_edge_tts: true,
+            max_tts_chars: None,
         }
     }
 }
 
+/// Length of a text to speak against the TTS character limit
+///
+/// Text over the limit is still spoken in full (it is chunked by the TTS
+/// layer), so this only drives what the UI shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TtsLength {
+    /// Characters in the text
+    pub chars: usize,
+    /// Effective character limit
+    pub limit: usize,
+}
+
+impl TtsLength {
+    /// Check if the text is longer than the limit
+    pub fn exceeds_limit(&self) -> bool {
+        self.chars > self.limit
+    }
+    
+    /// "X of Y characters"
+    pub fn label(&self) -> String {
+        format!("{} of {} characters", self.chars, self.limit)
+    }
+    
+    /// Warning to show when the text is over the limit
+    pub fn warning(&self) -> Option<String> {
+        self.exceeds_limit().then(|| {
+            format!(
+                "Content is {} characters over the {} character limit; playback may take a while to start.",
+                self.chars - self.limit,
+                self.limit
+            )
+        })
+    }
+}
+
 /// Notification settings
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct NotificationSettings {
@@ -141,13 +217,37 @@ pub struct AppState {
 }
 
 impl AppState {
+    /// Storage key the state is saved under
+    pub const KEY: &'static str = "app_state";
+    
+    /// Load the state saved in `storage`, or the default if there is none
+    pub fn load<S: Storage>(storage: &StorageService<S>) -> Result<Self> {
+        Ok(storage.load(Self::KEY)?.unwrap_or_default())
+    }
+    
+    /// Save the state to `storage`
+    pub fn save<S: Storage>(&self, storage: &StorageService<S>) -> Result<()> {
+        storage.save(Self::KEY, self)
+    }
+    
+    /// Apply `change` to the state saved in `storage` and save it back
+    ///
+    /// Only what `change` touches is modified, so parts of the app that
+    /// save different fields don't overwrite each other.
+    pub fn update<S: Storage>(storage: &StorageService<S>, change: impl FnOnce(&mut Self)) -> Result<Self> {
+        let mut state = Self::load(storage)?;
+        change(&mut state);
+        state.save(storage)?;
+        Ok(state)
+    }
+    
     /// Save state to JSON string
-    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+    pub fn to_json(&self) -> serde_json::Result<String> {
         serde_json::to_string_pretty(self)
     }
     
     /// Load state from JSON string
-    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
         serde_json::from_str(json)
     }
     
@@ -175,6 +275,54 @@ impl AppState {
     }
 }
 
+/// App state saved on this platform, or the default if it can't be read
+pub fn load_app_state() -> AppState {
+    AppState::load(&StorageService::<PlatformStorage>::default()).unwrap_or_else(|e| {
+        eprintln!("Failed to load app state: {}", e);
+        AppState::default()
+    })
+}
+
+/// Save app state to this platform's storage
+pub fn save_app_state(state: &AppState) -> Result<()> {
+    state.save(&StorageService::<PlatformStorage>::default())
+}
+
+/// Apply `change` to the app state saved on this platform
+pub fn update_app_state(change: impl FnOnce(&mut AppState)) -> Result<AppState> {
+    AppState::update(&StorageService::<PlatformStorage>::default(), change)
+}
+
+/// Mark a lesson as completed in the saved app state
+pub fn mark_lesson_complete(course_id: &Id, lesson_id: &Id) -> Result<()> {
+    update_app_state(|state| {
+        state.playback_data.mark_complete(lesson_id.clone(), course_id.clone());
+    })?;
+    Ok(())
+}
+
+/// Save the speech rate used for all playback
+pub fn save_speech_rate(rate: f32) -> Result<()> {
+    update_app_state(|state| state.settings.tts.rate = rate)?;
+    Ok(())
+}
+
+/// Save whether the next lesson starts when one finishes
+pub fn save_auto_play_next(enabled: bool) -> Result<()> {
+    update_app_state(|state| state.settings.playback.auto_play_next = enabled)?;
+    Ok(())
+}
+
+/// Speech options from the saved TTS settings
+pub fn saved_speech_options() -> SpeechOptions {
+    load_app_state().settings.tts.speech_options()
+}
+
+/// Lesson the user last played and where they stopped, for resuming
+pub fn last_played() -> Option<PlaybackPosition> {
+    load_app_state().playback_data.last_played()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,4 +344,85 @@ mod tests {
         
         assert_eq!(parsed.settings.display.dark_mode, true);
     }
+    
+    #[test]
+    fn test_max_tts_chars_defaults_per_engine() {
+        let mut tts = TtsSettings::default();
+        assert_eq!(tts.effective_max_chars(), EDGE_TTS_MAX_CHARS);
+        
+        tts.use_edge_tts = false;
+        assert_eq!(tts.effective_max_chars(), NATIVE_TTS_MAX_CHARS);
+        
+        tts.max_tts_chars = Some(2_000);
+        assert_eq!(tts.effective_max_chars(), 2_000);
+    }
+    
+    #[test]
+    fn test_length_warning_by_length() {
+        let tts = TtsSettings { max_tts_chars: Some(100), ..TtsSettings::default() };
+        
+        for (chars, over) in [(0, false), (50, false), (99, false), (100, false), (101, true), (5_000, true)] {
+            let length = tts.check_length(&"a".repeat(chars));
+            assert_eq!(length.chars, chars);
+            assert_eq!(length.exceeds_limit(), over, "{} chars", chars);
+            assert_eq!(length.warning().is_some(), over, "{} chars", chars);
+        }
+        
+        let length = tts.check_length(&"a".repeat(150));
+        assert_eq!(length.label(), "150 of 100 characters");
+        assert!(length.warning().unwrap().contains("50 characters over"));
+    }
+    
+    #[test]
+    fn test_length_counts_characters_not_bytes() {
+        let tts = TtsSettings { max_tts_chars: Some(5), ..TtsSettings::default() };
+        // 6 bytes, 5 characters
+        assert!(!tts.check_length("héllo").exceeds_limit());
+        assert_eq!(tts.check_length("日本語").chars, 3);
+    }
+    
+    #[test]
+    fn test_settings_without_max_tts_chars_deserialize() {
+        let json = r#"{"voice_id":null,"rate":1.0,"pitch":1.0,"volume":1.0,"use_edge_tts":false}"#;
+        let tts: TtsSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(tts.max_tts_chars, None);
+        assert_eq!(tts.effective_max_chars(), NATIVE_TTS_MAX_CHARS);
+    }
+    
+    #[test]
+    fn test_speech_rate_roundtrips_through_storage() {
+        let storage = StorageService::new(crate::core::MemoryStorage::new());
+        AppState::update(&storage, |state| state.settings.tts.rate = 1.5).unwrap();
+        
+        let loaded = AppState::load(&storage).unwrap();
+        assert_eq!(loaded.settings.tts.rate, 1.5);
+        assert_eq!(loaded.settings.tts.speech_options().rate, 1.5);
+    }
+    
+    #[test]
+    fn test_update_keeps_other_fields() {
+        let storage = StorageService::new(crate::core::MemoryStorage::new());
+        AppState::update(&storage, |state| {
+            state.playback_data.update_progress("l1".into(), "c1".into(), 42, 300);
+        }).unwrap();
+        AppState::update(&storage, |state| state.settings.tts.rate = 0.75).unwrap();
+        
+        let loaded = AppState::load(&storage).unwrap();
+        assert_eq!(loaded.settings.tts.rate, 0.75);
+        assert_eq!(loaded.playback_data.last_played().unwrap().position, 42);
+    }
+    
+    #[test]
+    fn test_speech_options_reflect_settings() {
+        assert_eq!(TtsSettings::default().speech_options(), SpeechOptions::default());
+        
+        let tts = TtsSettings { rate: 1.25, volume: 0.5, ..TtsSettings::default() };
+        let options = tts.speech_options();
+        assert_eq!(options.rate, 1.25);
+        assert_eq!(options.volume, 0.5);
+        
+        // Out-of-range values saved by hand are clamped
+        let tts = TtsSettings { rate: 9.0, ..TtsSettings::default() };
+        assert_eq!(tts.speech_options().rate, 2.0);
+    }
 }
@@ -0,0 +1,180 @@
+//! Running sleep timer that stops narration when it runs out
+//!
+//! [`SleepTimer`](crate::core::SleepTimer) is the option the user picks;
+//! [`SleepCountdown`] tracks the deadline once it is set. Nothing fires on
+//! its own: the app calls [`SleepCountdown::check_and_stop`] periodically.
+
+use chrono::{DateTime, Duration, Utc};
+use crate::common::Seconds;
+
+/// Source of the current time, replaceable in tests
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall-clock time
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that only moves when told to
+#[cfg(test)]
+#[derive(Clone)]
+pub(crate) struct FakeClock(std::rc::Rc<std::cell::Cell<DateTime<Utc>>>);
+
+#[cfg(test)]
+impl FakeClock {
+    pub(crate) fn new() -> Self {
+        Self(std::rc::Rc::new(std::cell::Cell::new(Utc::now())))
+    }
+
+    pub(crate) fn advance(&self, seconds: i64) {
+        self.0.set(self.0.get() + Duration::seconds(seconds));
+    }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0.get()
+    }
+}
+
+/// Countdown to stopping playback
+#[derive(Clone, Debug, Default)]
+pub struct SleepCountdown<C: Clock = SystemClock> {
+    clock: C,
+    deadline: Option<DateTime<Utc>>,
+}
+
+impl SleepCountdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Clock> SleepCountdown<C> {
+    pub fn with_clock(clock: C) -> Self {
+        Self { clock, deadline: None }
+    }
+
+    /// Start counting down from `seconds`, replacing any running countdown
+    pub fn start(&mut self, seconds: Seconds) {
+        self.deadline = Some(self.clock.now() + Duration::seconds(seconds as i64));
+    }
+
+    /// Stop the countdown without stopping playback
+    pub fn cancel(&mut self) {
+        self.deadline = None;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// Whole seconds left, rounded up, or `None` if not running
+    pub fn remaining(&self) -> Option<Seconds> {
+        let left = (self.deadline? - self.clock.now()).num_milliseconds().max(0);
+        Some(((left + 999) / 1000) as Seconds)
+    }
+
+    /// Call `on_expire` if the countdown has run out, and end it
+    ///
+    /// Returns whether it fired; a countdown fires at most once.
+    pub fn check(&mut self, on_expire: impl FnOnce()) -> bool {
+        match self.deadline {
+            Some(deadline) if self.clock.now() >= deadline => {
+                self.deadline = None;
+                on_expire();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Stop TTS playback if the countdown has run out
+    ///
+    /// Goes through [`stop_tts`](crate::core::stop_tts), which raises the
+    /// same stop flag as the stop button, so Edge playback ends at its next
+    /// chunk or tick.
+    pub fn check_and_stop(&mut self) -> bool {
+        self.check(|| {
+            let _ = crate::core::stop_tts();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn countdown() -> (SleepCountdown<FakeClock>, FakeClock) {
+        let clock = FakeClock::new();
+        (SleepCountdown::with_clock(clock.clone()), clock)
+    }
+
+    #[test]
+    fn test_remaining_decreases() {
+        let (mut timer, clock) = countdown();
+        assert_eq!(timer.remaining(), None);
+
+        timer.start(300);
+        assert!(timer.is_running());
+        assert_eq!(timer.remaining(), Some(300));
+
+        clock.advance(120);
+        assert_eq!(timer.remaining(), Some(180));
+
+        clock.advance(500);
+        assert_eq!(timer.remaining(), Some(0));
+    }
+
+    #[test]
+    fn test_cancel_prevents_stop() {
+        let (mut timer, clock) = countdown();
+        let mut stops = 0;
+
+        timer.start(60);
+        timer.cancel();
+        clock.advance(61);
+
+        assert!(!timer.check(|| stops += 1));
+        assert_eq!(stops, 0);
+        assert_eq!(timer.remaining(), None);
+    }
+
+    #[test]
+    fn test_expiry_triggers_stop_once() {
+        let (mut timer, clock) = countdown();
+        let mut stops = 0;
+
+        timer.start(60);
+        clock.advance(59);
+        assert!(!timer.check(|| stops += 1));
+
+        clock.advance(1);
+        assert!(timer.check(|| stops += 1));
+        assert!(!timer.check(|| stops += 1));
+        assert_eq!(stops, 1);
+        assert!(!timer.is_running());
+    }
+
+    #[test]
+    fn test_restart_replaces_deadline() {
+        let (mut timer, clock) = countdown();
+        let mut stops = 0;
+
+        timer.start(60);
+        clock.advance(50);
+        timer.start(60);
+        clock.advance(50);
+
+        assert!(!timer.check(|| stops += 1));
+        assert_eq!(timer.remaining(), Some(10));
+    }
+}
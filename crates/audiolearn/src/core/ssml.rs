@@ -0,0 +1,287 @@
+//! SSML escaping and validation for Edge TTS
+//!
+//! Edge wraps whatever it is sent in its own `<speak>`, `<voice>` and
+//! `<prosody>` elements, so the text is SSML markup either way. Plain text
+//! is escaped so `<` and `&` are spoken; SSML from the user is passed
+//! through as-is once it has been checked to be well-formed.
+
+use crate::common::{AudioLearnError, Result};
+use std::borrow::Cow;
+
+/// Escape text so it is read out literally inside SSML
+///
+/// All five XML special characters are replaced, so the result is also
+/// safe inside a quoted attribute.
+pub fn escape_ssml_text(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 16);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Check that `ssml` is a well-formed SSML body
+///
+/// The body is what goes inside `<speak>`: text mixed with elements such
+/// as `<break time="500ms"/>` or `<emphasis>`. The `<speak>` and `<voice>`
+/// wrappers are rejected because Edge adds its own from the speech options.
+pub fn validate_ssml(ssml: &str) -> Result<()> {
+    Validator { src: ssml, pos: 0, open: Vec::new() }.run()
+}
+
+struct Validator<'a> {
+    src: &'a str,
+    pos: usize,
+    /// Names of the elements currently open, innermost last
+    open: Vec<&'a str>,
+}
+
+impl<'a> Validator<'a> {
+    fn run(mut self) -> Result<()> {
+        if self.src.trim().is_empty() {
+            return Err(AudioLearnError::Tts("SSML is empty".into()));
+        }
+
+        while let Some(c) = self.peek() {
+            match c {
+                '<' => self.markup()?,
+                '&' => self.reference()?,
+                _ => self.pos += c.len_utf8(),
+            }
+        }
+
+        match self.open.last() {
+            Some(name) => Err(self.error(&format!("<{}> is never closed", name))),
+            None => Ok(()),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn error(&self, message: &str) -> AudioLearnError {
+        AudioLearnError::Tts(format!("Invalid SSML at byte {}: {}", self.pos, message))
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let trimmed = self.rest().trim_start();
+        let skipped = self.rest().len() - trimmed.len();
+        self.pos += skipped;
+        skipped > 0
+    }
+
+    /// Skip past `terminator`, failing with `what` if it never appears
+    fn skip_past(&mut self, terminator: &str, what: &str) -> Result<()> {
+        match self.rest().find(terminator) {
+            Some(i) => {
+                self.pos += i + terminator.len();
+                Ok(())
+            }
+            None => Err(self.error(&format!("unterminated {}", what))),
+        }
+    }
+
+    /// `<` starting a tag, comment or CDATA section
+    fn markup(&mut self) -> Result<()> {
+        let rest = self.rest();
+        if rest.starts_with("<!--") {
+            self.skip_past("-->", "comment")
+        } else if rest.starts_with("<![CDATA[") {
+            self.skip_past("]]>", "CDATA section")
+        } else if rest.starts_with("<?") {
+            self.skip_past("?>", "processing instruction")
+        } else if rest.starts_with("</") {
+            self.end_tag()
+        } else {
+            self.start_tag()
+        }
+    }
+
+    fn start_tag(&mut self) -> Result<()> {
+        self.pos += 1;
+        let name = self.name()?;
+        if matches!(name, "speak" | "voice") {
+            return Err(self.error(&format!(
+                "leave out <{}>; voice and prosody come from the speech options",
+                name
+            )));
+        }
+
+        loop {
+            let spaced = self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(());
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                self.open.push(name);
+                return Ok(());
+            }
+            if rest.is_empty() {
+                return Err(self.error(&format!("unterminated <{}> tag", name)));
+            }
+            if !spaced {
+                return Err(self.error("expected whitespace before attribute"));
+            }
+            self.attribute()?;
+        }
+    }
+
+    fn attribute(&mut self) -> Result<()> {
+        self.name()?;
+        self.skip_whitespace();
+        if self.peek() != Some('=') {
+            return Err(self.error("expected '=' after attribute name"));
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+
+        let quote = match self.peek() {
+            Some(q @ ('"' | '\'')) => q,
+            _ => return Err(self.error("attribute value must be quoted")),
+        };
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            match c {
+                _ if c == quote => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                '<' => return Err(self.error("'<' in attribute value")),
+                '&' => self.reference()?,
+                _ => self.pos += c.len_utf8(),
+            }
+        }
+        Err(self.error("unterminated attribute value"))
+    }
+
+    fn end_tag(&mut self) -> Result<()> {
+        self.pos += 2;
+        let name = self.name()?;
+        self.skip_whitespace();
+        if self.peek() != Some('>') {
+            return Err(self.error(&format!("expected '>' to close </{}>", name)));
+        }
+        match self.open.pop() {
+            Some(open) if open == name => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(open) => Err(self.error(&format!("</{}> closes <{}>", name, open))),
+            None => Err(self.error(&format!("</{}> has no opening tag", name))),
+        }
+    }
+
+    /// Element or attribute name
+    fn name(&mut self) -> Result<&'a str> {
+        let rest = self.rest();
+        let starts_ok = rest
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_' || c == ':');
+        if !starts_ok {
+            return Err(self.error("expected a name"));
+        }
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.')))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// `&` starting an entity or character reference
+    fn reference(&mut self) -> Result<()> {
+        let rest = &self.rest()[1..];
+        let Some(end) = rest.find(';') else {
+            return Err(self.error("'&' must start a reference like &amp;"));
+        };
+        let body = &rest[..end];
+        let valid = match body.strip_prefix('#') {
+            Some(hex) if hex.starts_with('x') => {
+                u32::from_str_radix(&hex[1..], 16).ok().and_then(char::from_u32).is_some()
+            }
+            Some(dec) => dec.parse::<u32>().ok().and_then(char::from_u32).is_some(),
+            None => matches!(body, "amp" | "lt" | "gt" | "quot" | "apos"),
+        };
+        if !valid {
+            return Err(self.error(&format!("unknown reference &{};", body)));
+        }
+        self.pos += end + 2;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_plain_text() {
+        assert_eq!(escape_ssml_text("Tom & Jerry"), "Tom &amp; Jerry");
+        assert_eq!(escape_ssml_text("if a < b > c"), "if a &lt; b &gt; c");
+        assert_eq!(escape_ssml_text("<break/>"), "&lt;break/&gt;");
+        assert_eq!(
+            escape_ssml_text(r#"A & B < C > "D" 'E'"#),
+            "A &amp; B &lt; C &gt; &quot;D&quot; &apos;E&apos;"
+        );
+        assert!(matches!(escape_ssml_text("C++ is fun?! Yes."), Cow::Borrowed(_)));
+
+        // Escaped text is always a valid body
+        for text in ["Tom & Jerry", "<speak>", "1 < 2 && 3 > 2", "&amp;", "it's \"C++\""] {
+            assert!(validate_ssml(&escape_ssml_text(text)).is_ok(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_ssml() {
+        for ssml in [
+            "Hello world",
+            "a > b",
+            r#"Take a breath. <break time="500ms"/> Now continue."#,
+            "<emphasis level='strong'>Rust</emphasis> is &quot;fast&quot; &amp; safe",
+            r#"<p><s>One.</s><s>Two &#8212; three &#x2014; four.</s></p>"#,
+            "<say-as interpret-as=\"characters\">SSML</say-as> <!-- note -->",
+        ] {
+            assert!(validate_ssml(ssml).is_ok(), "{}", ssml);
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_ssml() {
+        for ssml in [
+            "",
+            "<emphasis>never closed",
+            "<p><s>crossed</p></s>",
+            "stray </p>",
+            "Tom & Jerry",
+            "&nbsp;",
+            "<break time=500ms/>",
+            r#"<break time="500ms/>"#,
+            "<1st/>",
+            "<!-- open comment",
+            "<speak>Hello</speak>",
+            r#"<voice name="en-US-GuyNeural">Hi</voice>"#,
+        ] {
+            let result = validate_ssml(ssml);
+            assert!(matches!(result, Err(AudioLearnError::Tts(_))), "{}", ssml);
+        }
+    }
+}
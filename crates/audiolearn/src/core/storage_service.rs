@@ -0,0 +1,467 @@
+//! Persistent key-value storage
+//!
+//! Values are stored as JSON. `StorageService` picks the platform backend:
+//! a JSON store file on desktop (the same format the Tauri store plugin
+//! writes), the Tauri store under the `tauri` feature, and `localStorage`
+//! on web.
+
+use std::collections::HashMap;
+#[cfg(feature = "desktop")]
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use serde::{de::DeserializeOwned, Serialize};
+use crate::common::{AppError, Result};
+use crate::spi::Storage;
+
+fn encode<T: Serialize>(key: &str, value: &T) -> Result<String> {
+    serde_json::to_string(value)
+        .map_err(|e| AppError::Storage(format!("Failed to serialize {}: {}", key, e)))
+}
+
+fn decode<T: DeserializeOwned>(key: &str, json: &str) -> Result<T> {
+    serde_json::from_str(json)
+        .map_err(|e| AppError::Storage(format!("Failed to deserialize {}: {}", key, e)))
+}
+
+/// Non-persistent storage, for tests and platforms without a backend
+#[derive(Default)]
+pub struct MemoryStorage {
+    values: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn values(&self) -> std::sync::MutexGuard<'_, HashMap<String, String>> {
+        self.values.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        self.values().get(key).map(|json| decode(key, json)).transpose()
+    }
+
+    fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let json = encode(key, value)?;
+        self.values().insert(key.to_string(), json);
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.values().remove(key);
+        Ok(())
+    }
+
+    fn exists(&self, key: &str) -> bool {
+        self.values().contains_key(key)
+    }
+
+    fn clear(&self) -> Result<()> {
+        self.values().clear();
+        Ok(())
+    }
+}
+
+/// Storage in a single JSON object file
+#[cfg(feature = "desktop")]
+pub struct FileStorage {
+    path: std::path::PathBuf,
+    lock: Mutex<()>,
+}
+
+#[cfg(feature = "desktop")]
+impl FileStorage {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into(), lock: Mutex::new(()) }
+    }
+
+    /// Get default store path
+    pub fn default_path() -> std::path::PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| std::path::PathBuf::from("."))
+            .join("audiolearn")
+            .join("store.json")
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, serde_json::Value>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(json) => decode(&self.path.display().to_string(), &json),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(AppError::Storage(format!("Failed to read {}: {}", self.path.display(), e))),
+        }
+    }
+
+    fn write(&self, values: &BTreeMap<String, serde_json::Value>) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| AppError::Storage(format!("Failed to create {}: {}", dir.display(), e)))?;
+        }
+        let json = serde_json::to_string_pretty(values)
+            .map_err(|e| AppError::Storage(format!("Failed to serialize store: {}", e)))?;
+        std::fs::write(&self.path, json)
+            .map_err(|e| AppError::Storage(format!("Failed to write {}: {}", self.path.display(), e)))
+    }
+
+    fn update(&self, f: impl FnOnce(&mut BTreeMap<String, serde_json::Value>)) -> Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut values = self.read()?;
+        f(&mut values);
+        self.write(&values)
+    }
+}
+
+#[cfg(feature = "desktop")]
+impl Default for FileStorage {
+    fn default() -> Self {
+        Self::new(Self::default_path())
+    }
+}
+
+#[cfg(feature = "desktop")]
+impl Storage for FileStorage {
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.read()?
+            .remove(key)
+            .map(|value| {
+                serde_json::from_value(value)
+                    .map_err(|e| AppError::Storage(format!("Failed to deserialize {}: {}", key, e)))
+            })
+            .transpose()
+    }
+
+    fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| AppError::Storage(format!("Failed to serialize {}: {}", key, e)))?;
+        self.update(|values| {
+            values.insert(key.to_string(), value);
+        })
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.update(|values| {
+            values.remove(key);
+        })
+    }
+
+    fn exists(&self, key: &str) -> bool {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.read().map(|values| values.contains_key(key)).unwrap_or(false)
+    }
+
+    fn clear(&self) -> Result<()> {
+        self.update(BTreeMap::clear)
+    }
+}
+
+/// Browser `localStorage`
+#[cfg(feature = "web")]
+#[derive(Default)]
+pub struct LocalStorage;
+
+#[cfg(feature = "web")]
+impl LocalStorage {
+    fn storage() -> Result<web_sys::Storage> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| AppError::Storage("localStorage not available".into()))
+    }
+}
+
+#[cfg(feature = "web")]
+impl Storage for LocalStorage {
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let json = Self::storage()?
+            .get_item(key)
+            .map_err(|_| AppError::Storage(format!("Failed to read {}", key)))?;
+        json.map(|json| decode(key, &json)).transpose()
+    }
+
+    fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let json = encode(key, value)?;
+        Self::storage()?
+            .set_item(key, &json)
+            .map_err(|_| AppError::Storage(format!("Failed to write {} (quota exceeded?)", key)))
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        Self::storage()?
+            .remove_item(key)
+            .map_err(|_| AppError::Storage(format!("Failed to delete {}", key)))
+    }
+
+    fn exists(&self, key: &str) -> bool {
+        Self::storage()
+            .ok()
+            .and_then(|storage| storage.get_item(key).ok().flatten())
+            .is_some()
+    }
+
+    fn clear(&self) -> Result<()> {
+        Self::storage()?
+            .clear()
+            .map_err(|_| AppError::Storage("Failed to clear localStorage".into()))
+    }
+}
+
+#[cfg(feature = "tauri")]
+mod tauri_ipc {
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen_futures::{js_sys::Promise, JsFuture};
+    use crate::common::{AppError, Result};
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// `window.__TAURI__.core.invoke`, exposed by `withGlobalTauri`
+        #[wasm_bindgen(catch, js_namespace = ["__TAURI__", "core"], js_name = invoke)]
+        fn invoke_promise(cmd: &str, args: JsValue) -> std::result::Result<Promise, JsValue>;
+
+        #[wasm_bindgen(js_namespace = JSON)]
+        fn parse(json: &str) -> JsValue;
+
+        #[wasm_bindgen(catch, js_namespace = JSON)]
+        fn stringify(value: &JsValue) -> std::result::Result<JsValue, JsValue>;
+    }
+
+    /// Run a Tauri command and wait for its result
+    ///
+    /// A command that throws or rejects comes back as `AppError::Storage`.
+    pub async fn invoke(cmd: &str, args: serde_json::Value) -> Result<serde_json::Value> {
+        let failed = |e: JsValue| {
+            let reason = e.as_string().unwrap_or_else(|| format!("{:?}", e));
+            AppError::Storage(format!("{} failed: {}", cmd, reason))
+        };
+        let promise = invoke_promise(cmd, parse(&args.to_string())).map_err(failed)?;
+        let value = JsFuture::from(promise).await.map_err(failed)?;
+        if value.is_undefined() || value.is_null() {
+            return Ok(serde_json::Value::Null);
+        }
+        let json = stringify(&value).map_err(failed)?.as_string().unwrap_or_default();
+        serde_json::from_str(&json)
+            .map_err(|e| AppError::Storage(format!("{} returned invalid JSON: {}", cmd, e)))
+    }
+}
+
+/// The Tauri store (`audiolearn.json` in the app data dir), kept in step
+/// with the webview's `localStorage`
+///
+/// `Storage` is synchronous but Tauri commands are not, so reads are served
+/// from `localStorage` and every write lands there first. The matching
+/// `store_set`/`store_delete` call then runs in the background: `Ok` means
+/// the local write succeeded, and a failed store write is only logged.
+/// Call [`TauriStorage::restore`] at startup to bring back anything the
+/// webview has lost.
+#[cfg(feature = "tauri")]
+#[derive(Default)]
+pub struct TauriStorage {
+    local: LocalStorage,
+}
+
+#[cfg(feature = "tauri")]
+impl TauriStorage {
+    /// Copy entries from the Tauri store into `localStorage` where missing
+    ///
+    /// Returns how many entries were restored.
+    pub async fn restore(&self) -> Result<usize> {
+        let entries = tauri_ipc::invoke("store_entries", serde_json::json!({})).await?;
+        let serde_json::Value::Object(entries) = entries else {
+            return Err(AppError::Storage("store_entries did not return an object".into()));
+        };
+        let mut restored = 0;
+        for (key, value) in entries {
+            if !self.local.exists(&key) {
+                self.local.set(&key, &value)?;
+                restored += 1;
+            }
+        }
+        Ok(restored)
+    }
+
+    /// Send a write to the store without waiting for it
+    fn persist(cmd: &'static str, args: serde_json::Value) {
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = tauri_ipc::invoke(cmd, args).await {
+                eprintln!("Failed to update the Tauri store: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(feature = "tauri")]
+impl Storage for TauriStorage {
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        self.local.get(key)
+    }
+
+    fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| AppError::Storage(format!("Failed to serialize {}: {}", key, e)))?;
+        self.local.set(key, &value)?;
+        Self::persist("store_set", serde_json::json!({ "key": key, "value": value }));
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.local.delete(key)?;
+        Self::persist("store_delete", serde_json::json!({ "key": key }));
+        Ok(())
+    }
+
+    fn exists(&self, key: &str) -> bool {
+        self.local.exists(key)
+    }
+
+    fn clear(&self) -> Result<()> {
+        let storage = LocalStorage::storage()?;
+        let keys: Vec<String> = (0..storage.length().unwrap_or(0))
+            .filter_map(|i| storage.key(i).ok().flatten())
+            .collect();
+        for key in keys {
+            self.delete(&key)?;
+        }
+        Ok(())
+    }
+}
+
+/// Storage backend for the current platform
+#[cfg(feature = "desktop")]
+pub type PlatformStorage = FileStorage;
+
+/// Storage backend for the current platform
+#[cfg(all(feature = "tauri", not(feature = "desktop")))]
+pub type PlatformStorage = TauriStorage;
+
+/// Storage backend for the current platform
+#[cfg(all(feature = "web", not(any(feature = "desktop", feature = "tauri"))))]
+pub type PlatformStorage = LocalStorage;
+
+/// Storage backend for the current platform
+#[cfg(not(any(feature = "desktop", feature = "web")))]
+pub type PlatformStorage = MemoryStorage;
+
+/// Saves and loads app data as JSON under namespaced keys
+pub struct StorageService<S: Storage = PlatformStorage> {
+    backend: S,
+}
+
+impl<S: Storage> StorageService<S> {
+    /// Prefix applied to every key, so app data doesn't collide with other
+    /// `localStorage` users on the same origin
+    pub const KEY_PREFIX: &'static str = "audiolearn.";
+
+    pub fn new(backend: S) -> Self {
+        Self { backend }
+    }
+
+    fn key(key: &str) -> String {
+        format!("{}{}", Self::KEY_PREFIX, key)
+    }
+
+    /// Serialize `value` and store it under `key`
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        self.backend.set(&Self::key(key), value)
+    }
+
+    /// Load the value stored under `key`, if any
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        self.backend.get(&Self::key(key))
+    }
+
+    /// Remove the value stored under `key`
+    pub fn delete(&self, key: &str) -> Result<()> {
+        self.backend.delete(&Self::key(key))
+    }
+
+    /// Check whether anything is stored under `key`
+    pub fn contains(&self, key: &str) -> bool {
+        self.backend.exists(&Self::key(key))
+    }
+}
+
+impl<S: Storage + Default> Default for StorageService<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AppState;
+    use crate::common::PlaybackSpeed;
+
+    fn memory_service() -> StorageService<MemoryStorage> {
+        StorageService::new(MemoryStorage::new())
+    }
+
+    #[test]
+    fn test_app_state_roundtrip() {
+        let service = memory_service();
+        let mut state = AppState::default();
+        state.settings.playback.default_speed = PlaybackSpeed::OneHalf;
+        state.playback_data.update_progress("l1".into(), "c1".into(), 42, 300);
+
+        state.save(&service).unwrap();
+        let loaded = AppState::load(&service).unwrap();
+
+        assert_eq!(loaded.settings.playback.default_speed, PlaybackSpeed::OneHalf);
+        let progress = loaded.playback_data.get_progress(&"l1".into()).unwrap();
+        assert_eq!(progress.position, 42);
+        assert_eq!(loaded.playback_data.last_played_course, Some("c1".into()));
+    }
+
+    #[test]
+    fn test_stopping_persists_position() {
+        let service = memory_service();
+        assert_eq!(AppState::load(&service).unwrap().playback_data.last_played(), None);
+
+        // Play from the start, then stop two minutes in
+        let mut state = AppState::load(&service).unwrap();
+        state.playback_data.update_progress("l1".into(), "c1".into(), 0, 300);
+        state.save(&service).unwrap();
+        state.playback_data.update_progress("l1".into(), "c1".into(), 120, 300);
+        state.save(&service).unwrap();
+
+        let resume = AppState::load(&service).unwrap().playback_data.last_played().unwrap();
+        assert_eq!(resume.course_id, "c1");
+        assert_eq!(resume.lesson_id, "l1");
+        assert_eq!(resume.position, 120);
+    }
+
+    #[test]
+    fn test_missing_key_loads_none() {
+        let service = memory_service();
+        let loaded: Option<AppState> = service.load("missing").unwrap();
+        assert!(loaded.is_none());
+        assert!(!service.contains("missing"));
+    }
+
+    #[test]
+    fn test_delete() {
+        let service = memory_service();
+        service.save("enrolled", &vec!["c1", "c2"]).unwrap();
+        assert!(service.contains("enrolled"));
+
+        service.delete("enrolled").unwrap();
+        assert!(!service.contains("enrolled"));
+        assert_eq!(service.load::<Vec<String>>("enrolled").unwrap(), None);
+    }
+
+    #[test]
+    fn test_keys_are_namespaced() {
+        let service = memory_service();
+        service.save("enrolled", &vec!["c1"]).unwrap();
+        assert!(service.backend.exists("audiolearn.enrolled"));
+        assert!(!service.backend.exists("enrolled"));
+    }
+
+    #[test]
+    fn test_type_mismatch_is_a_storage_error() {
+        let service = memory_service();
+        service.save("count", &"not a number").unwrap();
+        assert!(matches!(service.load::<u32>("count"), Err(AppError::Storage(_))));
+    }
+}
@@ -0,0 +1,273 @@
+//! Sentence-aware text chunking for TTS
+//!
+//! Long text is split on sentence boundaries into chunks that a TTS
+//! engine can synthesize in one request.
+
+use crate::common::Seconds;
+
+/// Default maximum chunk length in bytes
+pub const DEFAULT_TTS_CHUNK_LEN: usize = 3000;
+
+/// Split text into chunks of at most `max_len` bytes
+///
+/// Chunks break on sentence boundaries where possible, falling back to word
+/// boundaries for sentences longer than the limit. Chunks are contiguous
+/// slices of `text`, so concatenating them gives back the original text.
+/// Only a single word longer than `max_len` is split mid-word.
+pub fn split_text_for_tts(text: &str, max_len: usize) -> Vec<&str> {
+    let max_len = max_len.max(1);
+    
+    // Break into pieces that each fit the limit
+    let mut pieces = Vec::new();
+    for sentence in sentences(text) {
+        if sentence.len() <= max_len {
+            pieces.push(sentence);
+            continue;
+        }
+        for word in words(sentence) {
+            if word.len() <= max_len {
+                pieces.push(word);
+            } else {
+                pieces.extend(hard_split(word, max_len));
+            }
+        }
+    }
+    
+    // Greedily pack consecutive pieces into chunks
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    for piece in pieces {
+        if end > start && end - start + piece.len() > max_len {
+            chunks.push(&text[start..end]);
+            start = end;
+        }
+        end += piece.len();
+    }
+    if end > start {
+        chunks.push(&text[start..end]);
+    }
+    
+    chunks
+}
+
+/// Where narration of a lesson picks up again
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NarrationStart<'a> {
+    /// Text left to read, starting at a sentence
+    pub text: &'a str,
+    /// Lesson position the start of `text` corresponds to
+    pub position: Seconds,
+}
+
+/// Resume narrating `text`, read over `duration` seconds, at `start`
+///
+/// Time is taken as spread evenly over the characters, and reading
+/// restarts at the beginning of the sentence `start` falls in, so the
+/// returned position is never later than `start`.
+pub fn resume_narration(text: &str, start: Seconds, duration: Seconds) -> NarrationStart<'_> {
+    let total = text.chars().count() as u64;
+    if start == 0 || duration == 0 || total == 0 {
+        return NarrationStart { text, position: 0 };
+    }
+    let target = start as u64 * total / duration as u64;
+    
+    let mut offset = 0;
+    let mut chars_before = 0;
+    for sentence in sentences(text) {
+        let len = sentence.chars().count() as u64;
+        if chars_before + len > target {
+            break;
+        }
+        offset += sentence.len();
+        chars_before += len;
+    }
+    // Past the end, replay the last sentence rather than nothing
+    if offset == text.len()
+        && let Some(last) = sentences(text).last()
+    {
+        offset -= last.len();
+        chars_before -= last.chars().count() as u64;
+    }
+    
+    NarrationStart {
+        text: &text[offset..],
+        position: (chars_before * duration as u64 / total) as Seconds,
+    }
+}
+
+/// Split into sentences, each keeping its trailing whitespace
+fn sentences(text: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    
+    while let Some((_, c)) = chars.next() {
+        if !matches!(c, '.' | '!' | '?' | '\n') {
+            continue;
+        }
+        
+        // Closing quotes and brackets belong to the sentence
+        while chars.next_if(|&(_, next)| matches!(next, '"' | '\'' | ')' | ']' | '”' | '’')).is_some() {}
+        
+        // Only a boundary when followed by whitespace (so "3.14" stays whole)
+        if chars.peek().is_some_and(|&(_, next)| !next.is_whitespace()) {
+            continue;
+        }
+        while chars.next_if(|&(_, next)| next.is_whitespace()).is_some() {}
+        
+        let end = chars.peek().map_or(text.len(), |&(i, _)| i);
+        out.push(&text[start..end]);
+        start = end;
+    }
+    
+    if start < text.len() {
+        out.push(&text[start..]);
+    }
+    out
+}
+
+/// Split into words, each keeping its trailing whitespace
+fn words(text: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut prev_whitespace = false;
+    
+    for (i, c) in text.char_indices() {
+        if prev_whitespace && !c.is_whitespace() && i > start {
+            out.push(&text[start..i]);
+            start = i;
+        }
+        prev_whitespace = c.is_whitespace();
+    }
+    
+    if start < text.len() {
+        out.push(&text[start..]);
+    }
+    out
+}
+
+/// Split an over-long word at char boundaries
+fn hard_split(word: &str, max_len: usize) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    
+    while start < word.len() {
+        let mut end = (start + max_len).min(word.len());
+        while !word.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == start {
+            // A single char wider than the limit - take it whole
+            end = start + word[start..].chars().next().map_or(1, char::len_utf8);
+        }
+        out.push(&word[start..end]);
+        start = end;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sample_text() -> String {
+        "Rust is a systems programming language. It is fast! Is it also safe? \
+         Yes: the borrow checker enforces \"aliasing XOR mutability\". Version 1.75 shipped in 2023.\n\
+         Ownership rules are simple, but they take practice to internalize fully. "
+            .repeat(20)
+    }
+    
+    #[test]
+    fn test_chunks_never_exceed_limit() {
+        let text = sample_text();
+        for max_len in [40, 100, 256, 1000] {
+            for chunk in split_text_for_tts(&text, max_len) {
+                assert!(chunk.len() <= max_len, "chunk of {} bytes > {}", chunk.len(), max_len);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_chunks_never_split_mid_word() {
+        let text = sample_text();
+        for max_len in [40, 100, 256] {
+            let chunks = split_text_for_tts(&text, max_len);
+            for pair in chunks.windows(2) {
+                let before = pair[0].chars().last().unwrap();
+                let after = pair[1].chars().next().unwrap();
+                assert!(
+                    before.is_whitespace() || after.is_whitespace(),
+                    "split inside a word: {:?} | {:?}", pair[0], pair[1]
+                );
+            }
+        }
+    }
+    
+    #[test]
+    fn test_chunks_preserve_full_text() {
+        let text = sample_text();
+        for max_len in [1, 7, 40, 100, 10_000] {
+            assert_eq!(split_text_for_tts(&text, max_len).concat(), text);
+        }
+        
+        let unicode = "Café au lait. Naïve façade! 日本語のテキスト。";
+        assert_eq!(split_text_for_tts(unicode, 5).concat(), unicode);
+    }
+    
+    #[test]
+    fn test_splits_on_sentence_boundaries() {
+        let text = "First sentence. Second one! Third?";
+        assert_eq!(split_text_for_tts(text, 20), vec!["First sentence. ", "Second one! Third?"]);
+        
+        // Short text is a single chunk
+        assert_eq!(split_text_for_tts(text, 100), vec![text]);
+        assert!(split_text_for_tts("", 100).is_empty());
+        
+        // Decimal points are not sentence ends
+        assert_eq!(sentences("Pi is 3.14 roughly. Done."), vec!["Pi is 3.14 roughly. ", "Done."]);
+    }
+    
+    #[test]
+    fn test_resume_narration_starts_at_sentence() {
+        // A second per char
+        let text = "One two three four five six seven. Eight nine ten eleven! ";
+        let resume = resume_narration(text, 40, text.len() as Seconds);
+        assert_eq!(resume.text, "Eight nine ten eleven! ");
+        assert_eq!(resume.position, 35);
+        
+        // The start of a sentence resumes exactly there
+        assert_eq!(resume_narration(text, 35, text.len() as Seconds).position, 35);
+        
+        // Inside the first sentence, and at the start, the whole text is read
+        assert_eq!(resume_narration(text, 10, 60).text, text);
+        assert_eq!(resume_narration(text, 0, 60), NarrationStart { text, position: 0 });
+        
+        // Past the end only the last sentence is replayed
+        assert_eq!(resume_narration(text, 500, 60).text, "Eight nine ten eleven! ");
+        assert_eq!(resume_narration("", 30, 60).position, 0);
+        assert_eq!(resume_narration(text, 30, 0).text, text);
+    }
+    
+    #[test]
+    fn test_resume_then_pause_does_not_grow_position() {
+        let text = sample_text();
+        let duration = 600;
+        
+        // Pausing right after resuming saves no more than the resume point
+        let mut saved = 317;
+        for _ in 0..10 {
+            let resume = resume_narration(&text, saved, duration);
+            assert!(resume.position <= saved);
+            assert!(text.ends_with(resume.text));
+            saved = resume.position;
+        }
+        
+        // Pausing later saves the resume point plus what was heard
+        let resume = resume_narration(&text, 317, duration);
+        assert!(317 - resume.position < 60, "resumed too far back");
+        let paused = resume.position + 12;
+        assert!(paused <= 317 + 12);
+        assert!(resume_narration(&text, paused, duration).position <= paused);
+    }
+}
@@ -0,0 +1,196 @@
+//! Synthesized Audio Cache
+//!
+//! LRU cache of synthesized TTS audio, bounded by total bytes, so replaying
+//! the same text doesn't go back to the network.
+
+use crate::common::Result;
+use crate::spi::tts::SpeechOptions;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+
+/// Default cache budget (32 MiB of audio)
+pub const DEFAULT_TTS_CACHE_BYTES: usize = 32 * 1024 * 1024;
+
+/// LRU cache of synthesized audio keyed by text and speech options
+pub struct TtsAudioCache {
+    entries: HashMap<u64, Vec<u8>>,
+    /// Keys from least to most recently used
+    order: VecDeque<u64>,
+    total_bytes: usize,
+    max_bytes: usize,
+}
+
+impl TtsAudioCache {
+    /// Create a cache holding at most `max_bytes` of audio
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            total_bytes: 0,
+            max_bytes,
+        }
+    }
+    
+    /// Cache key for a synthesis request
+    ///
+    /// Covers the text, voice, rate, pitch and volume - everything that changes the audio.
+    pub fn key(text: &str, options: &SpeechOptions) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        options.voice.as_ref().map(|v| v.id.as_str()).hash(&mut hasher);
+        options.rate.to_bits().hash(&mut hasher);
+        options.pitch.to_bits().hash(&mut hasher);
+        options.volume.to_bits().hash(&mut hasher);
+        hasher.finish()
+    }
+    
+    /// Get cached audio, marking it as most recently used
+    pub fn get(&mut self, key: u64) -> Option<Vec<u8>> {
+        let audio = self.entries.get(&key)?.clone();
+        self.touch(key);
+        Some(audio)
+    }
+    
+    /// Insert audio, evicting least recently used entries to stay within budget
+    ///
+    /// Audio larger than the whole budget is not cached.
+    pub fn insert(&mut self, key: u64, audio: Vec<u8>) {
+        if audio.len() > self.max_bytes {
+            return;
+        }
+        
+        if let Some(old) = self.entries.remove(&key) {
+            self.total_bytes -= old.len();
+            self.order.retain(|k| *k != key);
+        }
+        
+        while self.total_bytes + audio.len() > self.max_bytes {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(evicted) = self.entries.remove(&oldest) {
+                self.total_bytes -= evicted.len();
+            }
+        }
+        
+        self.total_bytes += audio.len();
+        self.entries.insert(key, audio);
+        self.order.push_back(key);
+    }
+    
+    /// Return cached audio for the request, or synthesize and cache it
+    pub fn get_or_synthesize<F>(&mut self, text: &str, options: &SpeechOptions, synthesize: F) -> Result<Vec<u8>>
+    where
+        F: FnOnce(&str, &SpeechOptions) -> Result<Vec<u8>>,
+    {
+        let key = Self::key(text, options);
+        if let Some(audio) = self.get(key) {
+            return Ok(audio);
+        }
+        
+        let audio = synthesize(text, options)?;
+        self.insert(key, audio.clone());
+        Ok(audio)
+    }
+    
+    /// Remove all cached audio
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.total_bytes = 0;
+    }
+    
+    /// Number of cached entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Total bytes of cached audio
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+    
+    fn touch(&mut self, key: u64) {
+        if let Some(pos) = self.order.iter().position(|k| *k == key) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(key);
+    }
+}
+
+impl Default for TtsAudioCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTS_CACHE_BYTES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::AudioLearnError;
+    use std::cell::Cell;
+    
+    #[test]
+    fn test_second_synthesize_uses_cache() {
+        let mut cache = TtsAudioCache::default();
+        let calls = Cell::new(0);
+        let mock_engine = |text: &str, _: &SpeechOptions| {
+            calls.set(calls.get() + 1);
+            Ok(format!("ID3 audio for {}", text).into_bytes())
+        };
+        let options = SpeechOptions::default();
+        
+        let first = cache.get_or_synthesize("Hello world", &options, mock_engine).unwrap();
+        let second = cache.get_or_synthesize("Hello world", &options, mock_engine).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(calls.get(), 1);
+        
+        // A different rate is a different request
+        let fast = SpeechOptions { rate: 1.5, ..Default::default() };
+        cache.get_or_synthesize("Hello world", &fast, mock_engine).unwrap();
+        assert_eq!(calls.get(), 2);
+    }
+    
+    #[test]
+    fn test_failed_synthesis_is_not_cached() {
+        let mut cache = TtsAudioCache::default();
+        let options = SpeechOptions::default();
+        
+        let result = cache.get_or_synthesize("Hello", &options, |_, _| {
+            Err(AudioLearnError::Tts("offline".into()))
+        });
+        assert!(result.is_err());
+        assert!(cache.is_empty());
+    }
+    
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = TtsAudioCache::new(10);
+        cache.insert(1, vec![0; 4]);
+        cache.insert(2, vec![0; 4]);
+        
+        // Touch 1 so 2 becomes the eviction candidate
+        assert!(cache.get(1).is_some());
+        cache.insert(3, vec![0; 4]);
+        
+        assert!(cache.get(2).is_none());
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(3).is_some());
+        assert_eq!(cache.total_bytes(), 8);
+        
+        // Larger than the whole budget: not cached
+        cache.insert(4, vec![0; 11]);
+        assert!(cache.get(4).is_none());
+        assert_eq!(cache.len(), 2);
+        
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.total_bytes(), 0);
+    }
+}
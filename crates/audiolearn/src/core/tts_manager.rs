@@ -4,11 +4,15 @@
 //! fallback between Edge TTS (neural) and Native TTS (system).
 
 use crate::common::{AudioLearnError, Result};
-use crate::core::{EdgeTtsSync, NativeTts};
+use crate::core::{
+    split_text_for_tts, AvailabilityCache, EdgeTtsSync, NativeTts, TtsAudioCache, DEFAULT_TTS_CHUNK_LEN,
+};
 use crate::spi::tts::{SpeechOptions, TtsEngine, Voice};
-use std::cell::RefCell;
 use std::io::Cursor;
-use rodio::{Decoder, OutputStream, Sink};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
+use rodio::{Decoder, OutputStream, Sink, Source};
 
 /// TTS engine preference
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,6 +39,12 @@ pub struct TtsManager {
     native: Option<NativeTts>,
     preference: TtsPreference,
     last_engine_used: Option<String>,
+    /// Synthesized Edge audio, so repeat playback skips the network
+    cache: TtsAudioCache,
+    /// Maximum length of each chunk sent to Edge TTS
+    max_chunk_len: usize,
+    /// Last engine probe, so repeated checks don't hit the network
+    availability: AvailabilityCache,
 }
 
 impl TtsManager {
@@ -56,18 +66,30 @@ impl TtsManager {
             _ => NativeTts::try_new(),
         };
         
+        Self::with_engines(edge, native, preference)
+    }
+    
+    fn with_engines(edge: Option<EdgeTtsSync>, native: Option<NativeTts>, preference: TtsPreference) -> Self {
         Self {
             edge,
             native,
             preference,
             last_engine_used: None,
+            cache: TtsAudioCache::default(),
+            max_chunk_len: DEFAULT_TTS_CHUNK_LEN,
+            availability: AvailabilityCache::default(),
         }
     }
     
     /// Check if any TTS engine is available
+    ///
+    /// The answer is reused for [`AVAILABILITY_TTL`](crate::core::AVAILABILITY_TTL);
+    /// re-initializing the manager probes again.
     pub fn is_available(&self) -> bool {
-        self.edge.as_ref().map(|e| e.is_available()).unwrap_or(false)
-            || self.native.as_ref().map(|n| n.is_available()).unwrap_or(false)
+        self.availability.get_or_probe(|| {
+            self.edge.as_ref().map(|e| e.is_available()).unwrap_or(false)
+                || self.native.as_ref().map(|n| n.is_available()).unwrap_or(false)
+        })
     }
     
     /// Get the name of the last engine used
@@ -80,6 +102,16 @@ impl TtsManager {
         self.preference = preference;
     }
     
+    /// Get the current TTS preference
+    pub fn preference(&self) -> TtsPreference {
+        self.preference
+    }
+    
+    /// Drop all cached synthesized audio
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+    
     /// Get available voices from all engines
     pub fn voices(&mut self) -> Result<Vec<Voice>> {
         let mut all_voices = Vec::new();
@@ -111,7 +143,7 @@ impl TtsManager {
             TtsPreference::EdgeFirst => {
                 // Try Edge first
                 if let Some(ref edge) = self.edge {
-                    match edge.synthesize(text, options) {
+                    match self.cache.get_or_synthesize(text, options, |t, o| edge.synthesize(t, o)) {
                         Ok(audio) => {
                             self.last_engine_used = Some(edge.name().to_string());
                             return Ok(audio);
@@ -130,7 +162,7 @@ impl TtsManager {
             TtsPreference::NativeFirst => {
                 // Native doesn't support synthesis to bytes, try Edge
                 if let Some(ref edge) = self.edge {
-                    match edge.synthesize(text, options) {
+                    match self.cache.get_or_synthesize(text, options, |t, o| edge.synthesize(t, o)) {
                         Ok(audio) => {
                             self.last_engine_used = Some(edge.name().to_string());
                             return Ok(audio);
@@ -144,10 +176,11 @@ impl TtsManager {
                 Err(AudioLearnError::Tts("No synthesis-capable TTS available".into()))
             }
             TtsPreference::EdgeOnly => {
-                self.edge
+                let edge = self.edge
                     .as_ref()
-                    .ok_or_else(|| AudioLearnError::Tts("Edge TTS not available".into()))?
-                    .synthesize(text, options)
+                    .ok_or_else(|| AudioLearnError::Tts("Edge TTS not available".into()))?;
+                self.cache
+                    .get_or_synthesize(text, options, |t, o| edge.synthesize(t, o))
                     .map(|audio| {
                         self.last_engine_used = Some("Microsoft Edge Neural TTS".to_string());
                         audio
@@ -163,72 +196,128 @@ impl TtsManager {
     
     /// Speak text using the preferred engine
     pub fn speak(&mut self, text: &str, options: &SpeechOptions) -> Result<()> {
+        self.speak_with_progress(text, options, |_| {})
+    }
+    
+    /// Speak text, calling `on_progress` with the elapsed playback time
+    /// about every [`PROGRESS_INTERVAL`]
+    ///
+    /// Native TTS playback is opaque, so progress is only reported for Edge.
+    pub fn speak_with_progress(
+        &mut self,
+        text: &str,
+        options: &SpeechOptions,
+        mut on_progress: impl FnMut(Duration),
+    ) -> Result<()> {
+        speak_through(self, text, options, &mut on_progress, play_audio_bytes)
+    }
+    
+    /// Engine that speaking should go through, from the preference and
+    /// which engines exist
+    fn speak_route(&self) -> SpeakRoute {
         match self.preference {
-            TtsPreference::EdgeFirst => {
-                // For Edge, synthesize then play
-                if let Some(ref edge) = self.edge {
-                    match edge.synthesize(text, options) {
-                        Ok(audio) => {
-                            self.last_engine_used = Some(edge.name().to_string());
-                            return play_audio_bytes(&audio);
-                        }
-                        Err(e) => {
-                            eprintln!("Edge TTS failed, falling back to native: {}", e);
-                        }
-                    }
-                }
-                
-                // Fallback to native
-                if let Some(ref mut native) = self.native {
-                    self.last_engine_used = Some(native.name().to_string());
-                    return native.speak(text, options);
-                }
-                
-                Err(AudioLearnError::Tts("No TTS engine available".into()))
-            }
+            TtsPreference::EdgeFirst if self.edge.is_some() => SpeakRoute::Edge { fallback_to_native: true },
+            TtsPreference::EdgeFirst => SpeakRoute::Native,
             TtsPreference::NativeFirst => {
-                // Try native first
-                if let Some(ref mut native) = self.native {
-                    if native.is_available() {
-                        self.last_engine_used = Some(native.name().to_string());
-                        return native.speak(text, options);
-                    }
+                let native_ready = self.native.as_ref().is_some_and(|n| n.is_available());
+                if native_ready || self.edge.is_none() {
+                    SpeakRoute::Native
+                } else {
+                    SpeakRoute::Edge { fallback_to_native: false }
                 }
-                
-                // Fallback to Edge
-                if let Some(ref edge) = self.edge {
-                    match edge.synthesize(text, options) {
-                        Ok(audio) => {
-                            self.last_engine_used = Some(edge.name().to_string());
-                            return play_audio_bytes(&audio);
-                        }
-                        Err(e) => {
-                            return Err(e);
-                        }
-                    }
-                }
-                
-                Err(AudioLearnError::Tts("No TTS engine available".into()))
             }
-            TtsPreference::EdgeOnly => {
-                let audio = self.edge
-                    .as_ref()
-                    .ok_or_else(|| AudioLearnError::Tts("Edge TTS not available".into()))?
-                    .synthesize(text, options)?;
-                
-                self.last_engine_used = Some("Microsoft Edge Neural TTS".to_string());
-                play_audio_bytes(&audio)
-            }
-            TtsPreference::NativeOnly => {
-                self.native
-                    .as_mut()
-                    .ok_or_else(|| AudioLearnError::Tts("Native TTS not available".into()))?
-                    .speak(text, options)
-                    .map(|_| {
-                        self.last_engine_used = Some("Native TTS".to_string());
-                    })
+            TtsPreference::EdgeOnly => SpeakRoute::Edge { fallback_to_native: false },
+            TtsPreference::NativeOnly => SpeakRoute::Native,
+        }
+    }
+    
+    /// Speak through native TTS
+    fn speak_native(&mut self, text: &str, options: &SpeechOptions) -> Result<()> {
+        let native = self.native
+            .as_mut()
+            .ok_or_else(|| AudioLearnError::Tts("No TTS engine available".into()))?;
+        self.last_engine_used = Some(native.name().to_string());
+        native.speak(text, options)
+    }
+    
+    /// Non-empty pieces of `text` to synthesize one at a time with Edge
+    fn edge_chunks(&self, text: &str) -> Result<Vec<String>> {
+        if self.edge.is_none() {
+            return Err(AudioLearnError::Tts("Edge TTS not available".into()));
+        }
+        Ok(split_text_for_tts(text, self.max_chunk_len)
+            .into_iter()
+            .filter(|chunk| !chunk.trim().is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+    
+    /// Synthesize one chunk with Edge, reusing cached audio
+    fn synthesize_edge_chunk(&mut self, chunk: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+        let edge = self.edge
+            .as_ref()
+            .ok_or_else(|| AudioLearnError::Tts("Edge TTS not available".into()))?;
+        let audio = self.cache.get_or_synthesize(chunk, options, |t, o| edge.synthesize(t, o))?;
+        self.last_engine_used = Some(edge.name().to_string());
+        Ok(audio)
+    }
+    
+    /// Synthesize an SSML body through Edge TTS
+    ///
+    /// Only Edge understands SSML, so this fails rather than falling back
+    /// to native TTS. SSML audio isn't cached: the cache is keyed by plain
+    /// text, which the same string would be read as.
+    pub fn synthesize_ssml(&mut self, ssml: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+        let edge = self.edge
+            .as_ref()
+            .ok_or_else(|| AudioLearnError::Tts("SSML needs Edge TTS, which is not available".into()))?;
+        let audio = edge.synthesize_ssml(ssml, options)?;
+        self.last_engine_used = Some(edge.name().to_string());
+        Ok(audio)
+    }
+    
+    /// Speak an SSML body through Edge TTS
+    ///
+    /// The markup is played in one piece, since splitting it could break
+    /// its elements apart.
+    pub fn speak_ssml(&mut self, ssml: &str, options: &SpeechOptions) -> Result<()> {
+        speak_ssml_through(self, ssml, options, play_audio_bytes)
+    }
+    
+    /// Synthesize text of any length, concatenating the per-chunk MP3 audio
+    pub fn synthesize_chunked(&mut self, text: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+        let mut audio = Vec::new();
+        for chunk in split_text_for_tts(text, self.max_chunk_len) {
+            if !chunk.trim().is_empty() {
+                audio.extend(self.synthesize(chunk, options)?);
             }
         }
+        
+        if audio.is_empty() {
+            return Err(AudioLearnError::Tts("No text to synthesize".into()));
+        }
+        Ok(audio)
+    }
+    
+    /// Synthesize text and write the MP3 audio to `path`
+    pub fn synthesize_to_file(&mut self, text: &str, path: &Path, options: &SpeechOptions) -> Result<()> {
+        let is_mp3 = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("mp3"));
+        if !is_mp3 {
+            return Err(AudioLearnError::Io(format!(
+                "Audio can only be saved as .mp3: {}", path.display()
+            )));
+        }
+        
+        let audio = self.synthesize_chunked(text, options)?;
+        std::fs::write(path, audio)
+            .map_err(|e| AudioLearnError::Io(format!("Failed to write {}: {}", path.display(), e)))
+    }
+    
+    /// Set the maximum length of each chunk sent to Edge TTS
+    pub fn set_max_chunk_len(&mut self, max_chunk_len: usize) {
+        self.max_chunk_len = max_chunk_len;
     }
     
     /// Stop any ongoing speech
@@ -249,6 +338,97 @@ impl Default for TtsManager {
     }
 }
 
+/// Engine chosen for speaking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpeakRoute {
+    Edge { fallback_to_native: bool },
+    Native,
+}
+
+/// Access to a manager for one call at a time
+///
+/// Playback runs between calls, so a manager behind a mutex is only locked
+/// while synthesizing, never while audio plays or sits paused.
+trait ManagerAccess {
+    fn with<R>(&mut self, f: impl FnOnce(&mut TtsManager) -> R) -> R;
+}
+
+impl ManagerAccess for &mut TtsManager {
+    fn with<R>(&mut self, f: impl FnOnce(&mut TtsManager) -> R) -> R {
+        f(self)
+    }
+}
+
+impl ManagerAccess for &Mutex<TtsManager> {
+    fn with<R>(&mut self, f: impl FnOnce(&mut TtsManager) -> R) -> R {
+        f(&mut lock(self))
+    }
+}
+
+/// Speak `text` with the engine the manager picks, handing Edge audio to
+/// `play` with the manager released
+fn speak_through(
+    mut manager: impl ManagerAccess,
+    text: &str,
+    options: &SpeechOptions,
+    on_progress: &mut dyn FnMut(Duration),
+    play: impl FnMut(&[u8], &mut ProgressTicker<'_>) -> Result<()>,
+) -> Result<()> {
+    match manager.with(|m| m.speak_route()) {
+        SpeakRoute::Edge { fallback_to_native } => {
+            match speak_edge_through(&mut manager, text, options, on_progress, play) {
+                Err(e) if fallback_to_native => {
+                    eprintln!("Edge TTS failed, falling back to native: {}", e);
+                    manager.with(|m| m.speak_native(text, options))
+                }
+                result => result,
+            }
+        }
+        SpeakRoute::Native => manager.with(|m| m.speak_native(text, options)),
+    }
+}
+
+/// Synthesize and play text through Edge TTS
+///
+/// Long text is split on sentence boundaries and played chunk by chunk,
+/// checking the stop flag between chunks. Elapsed time keeps counting
+/// across chunks.
+fn speak_edge_through(
+    manager: &mut impl ManagerAccess,
+    text: &str,
+    options: &SpeechOptions,
+    on_progress: &mut dyn FnMut(Duration),
+    mut play: impl FnMut(&[u8], &mut ProgressTicker<'_>) -> Result<()>,
+) -> Result<()> {
+    let chunks = manager.with(|m| m.edge_chunks(text))?;
+    
+    reset_playback_flags();
+    let mut progress = ProgressTicker::new(PROGRESS_INTERVAL, on_progress);
+    
+    for chunk in chunks {
+        if STOP_REQUESTED.load(Ordering::SeqCst) {
+            break;
+        }
+        let audio = manager.with(|m| m.synthesize_edge_chunk(&chunk, options))?;
+        play(&audio, &mut progress)?;
+    }
+    
+    Ok(())
+}
+
+/// Synthesize an SSML body under the manager, then play it released
+fn speak_ssml_through(
+    mut manager: impl ManagerAccess,
+    ssml: &str,
+    options: &SpeechOptions,
+    mut play: impl FnMut(&[u8], &mut ProgressTicker<'_>) -> Result<()>,
+) -> Result<()> {
+    let audio = manager.with(|m| m.synthesize_ssml(ssml, options))?;
+    let mut ignore_progress = |_: Duration| {};
+    reset_playback_flags();
+    play(&audio, &mut ProgressTicker::new(PROGRESS_INTERVAL, &mut ignore_progress))
+}
+
 // =============================================================================
 // Shared Audio Playback for Edge TTS (stoppable)
 // =============================================================================
@@ -258,16 +438,90 @@ use std::sync::atomic::{AtomicBool, Ordering};
 // Global flag to signal stop
 static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
 
+// Global flag to hold playback at its current position
+static PAUSE_REQUESTED: AtomicBool = AtomicBool::new(false);
+
 /// Stop any Edge TTS audio playback
 fn stop_edge_audio() {
     STOP_REQUESTED.store(true, Ordering::SeqCst);
 }
 
-/// Play audio bytes through rodio with stoppable playback
-fn play_audio_bytes(audio: &[u8]) -> Result<()> {
-    // Clear stop flag
-    STOP_REQUESTED.store(false, Ordering::SeqCst);
+/// What the playback loop should do with the sink on its next tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlaybackControl {
+    Continue,
+    Pause,
+    Resume,
+    Stop,
+}
+
+/// Decide the next playback action from the requested and current sink state
+fn playback_control(stop_requested: bool, pause_requested: bool, sink_paused: bool) -> PlaybackControl {
+    if stop_requested {
+        PlaybackControl::Stop
+    } else if pause_requested && !sink_paused {
+        PlaybackControl::Pause
+    } else if !pause_requested && sink_paused {
+        PlaybackControl::Resume
+    } else {
+        PlaybackControl::Continue
+    }
+}
+
+/// How often elapsed playback time is reported while speaking
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Turns positions sampled by the playback loop into evenly spaced
+/// elapsed-time reports, accumulating across chunks
+struct ProgressTicker<'a> {
+    interval: Duration,
+    /// Time played by earlier chunks
+    base: Duration,
+    last_emit: Option<Duration>,
+    callback: &'a mut dyn FnMut(Duration),
+}
+
+impl<'a> ProgressTicker<'a> {
+    fn new(interval: Duration, callback: &'a mut dyn FnMut(Duration)) -> Self {
+        Self { interval, base: Duration::ZERO, last_emit: None, callback }
+    }
+    
+    /// Report the current chunk's position if an interval has passed since
+    /// the last report
+    fn tick(&mut self, chunk_position: Duration) {
+        let elapsed = self.base + chunk_position;
+        let due = match self.last_emit {
+            Some(last) => elapsed >= last + self.interval,
+            None => true,
+        };
+        if due {
+            self.emit(elapsed);
+        }
+    }
     
+    /// The current chunk is done after playing for `played`
+    fn finish_chunk(&mut self, played: Duration) {
+        self.base += played;
+        if self.last_emit != Some(self.base) {
+            self.emit(self.base);
+        }
+    }
+    
+    fn emit(&mut self, elapsed: Duration) {
+        self.last_emit = Some(elapsed);
+        (self.callback)(elapsed);
+    }
+}
+
+/// Clear stop and pause flags before starting new playback
+fn reset_playback_flags() {
+    STOP_REQUESTED.store(false, Ordering::SeqCst);
+    PAUSE_REQUESTED.store(false, Ordering::SeqCst);
+}
+
+/// Play audio bytes through rodio with stoppable playback, reporting the
+/// position to `progress` from the wait loop
+fn play_audio_bytes(audio: &[u8], progress: &mut ProgressTicker<'_>) -> Result<()> {
     // Create output stream
     let (_stream, handle) = OutputStream::try_default()
         .map_err(|e| AudioLearnError::Audio(format!("Failed to get output stream: {}", e)))?;
@@ -280,128 +534,319 @@ fn play_audio_bytes(audio: &[u8]) -> Result<()> {
     let cursor = Cursor::new(audio.to_vec());
     let source = Decoder::new(cursor)
         .map_err(|e| AudioLearnError::Audio(format!("Failed to decode audio: {}", e)))?;
+    let total = source.total_duration();
     
     sink.append(source);
+    let mut position = Duration::ZERO;
     
-    // Wait for playback to complete or stop signal
+    // Wait for playback to complete or stop signal (a paused sink keeps its
+    // position, so resuming continues where it left off)
     while !sink.empty() {
-        // Check if stop requested
-        if STOP_REQUESTED.load(Ordering::SeqCst) {
-            sink.stop();
-            break;
+        position = sink.get_pos();
+        progress.tick(position);
+        
+        match playback_control(
+            STOP_REQUESTED.load(Ordering::SeqCst),
+            PAUSE_REQUESTED.load(Ordering::SeqCst),
+            sink.is_paused(),
+        ) {
+            PlaybackControl::Stop => {
+                sink.stop();
+                progress.finish_chunk(position);
+                return Ok(());
+            }
+            PlaybackControl::Pause => sink.pause(),
+            PlaybackControl::Resume => sink.play(),
+            PlaybackControl::Continue => {}
         }
         
         // Small sleep to avoid busy waiting
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::thread::sleep(Duration::from_millis(50));
     }
     
+    progress.finish_chunk(total.unwrap_or(position));
     Ok(())
 }
 
-// Thread-local TTS manager for global access
-thread_local! {
-    static TTS_MANAGER: RefCell<Option<TtsManager>> = const { RefCell::new(None) };
+// Process-global TTS manager, shared by every thread (the UI speaks from
+// `spawn_blocking` workers, so a thread-local would re-probe engines per worker)
+static TTS_MANAGER: OnceLock<Mutex<TtsManager>> = OnceLock::new();
+
+/// Lock a manager, recovering it if a previous holder panicked
+fn lock(manager: &Mutex<TtsManager>) -> MutexGuard<'_, TtsManager> {
+    manager.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The global TTS manager, created with the default preference if needed
+fn global() -> &'static Mutex<TtsManager> {
+    TTS_MANAGER.get_or_init(|| Mutex::new(TtsManager::new()))
+}
+
+/// Lock the global TTS manager
+fn global_manager() -> MutexGuard<'static, TtsManager> {
+    lock(global())
 }
 
 /// Initialize the global TTS manager
 pub fn init_tts() {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-    });
+    TTS_MANAGER.get_or_init(|| Mutex::new(TtsManager::new()));
 }
 
 /// Initialize TTS with specific preference
 pub fn init_tts_with_preference(preference: TtsPreference) {
-    TTS_MANAGER.with(|mgr| {
-        *mgr.borrow_mut() = Some(TtsManager::with_preference(preference));
+    let mut created = false;
+    let manager = TTS_MANAGER.get_or_init(|| {
+        created = true;
+        Mutex::new(TtsManager::with_preference(preference))
     });
+    if !created {
+        *lock(manager) = TtsManager::with_preference(preference);
+    }
+}
+
+/// Get the preference of the global TTS manager
+pub fn tts_preference() -> TtsPreference {
+    global_manager().preference()
 }
 
 /// Speak text using the global TTS manager
+///
+/// The manager is locked only while synthesizing, so other TTS calls go
+/// through while the audio plays.
 pub fn speak_text(text: &str) -> Result<()> {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-        mgr.as_mut().unwrap().speak(text, &SpeechOptions::default())
-    })
+    speak_text_with_options(text, &SpeechOptions::default())
 }
 
 /// Speak text with custom options
 pub fn speak_text_with_options(text: &str, options: &SpeechOptions) -> Result<()> {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-        mgr.as_mut().unwrap().speak(text, options)
-    })
+    speak_text_with_progress(text, options, |_| {})
+}
+
+/// Speak text, reporting elapsed playback time about every [`PROGRESS_INTERVAL`]
+pub fn speak_text_with_progress(
+    text: &str,
+    options: &SpeechOptions,
+    mut on_progress: impl FnMut(Duration),
+) -> Result<()> {
+    speak_through(global(), text, options, &mut on_progress, play_audio_bytes)
 }
 
 /// Synthesize text to audio bytes
 pub fn synthesize_text(text: &str) -> Result<Vec<u8>> {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-        mgr.as_mut().unwrap().synthesize(text, &SpeechOptions::default())
-    })
+    global_manager().synthesize(text, &SpeechOptions::default())
 }
 
 /// Synthesize text with custom options
 pub fn synthesize_text_with_options(text: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-        mgr.as_mut().unwrap().synthesize(text, options)
-    })
+    global_manager().synthesize(text, options)
+}
+
+/// Synthesize an SSML body through Edge TTS
+pub fn synthesize_ssml(ssml: &str, options: &SpeechOptions) -> Result<Vec<u8>> {
+    global_manager().synthesize_ssml(ssml, options)
+}
+
+/// Speak an SSML body through Edge TTS
+pub fn speak_ssml(ssml: &str, options: &SpeechOptions) -> Result<()> {
+    speak_ssml_through(global(), ssml, options, play_audio_bytes)
+}
+
+/// Synthesize text and save it as an MP3 file
+pub fn synthesize_to_file(text: &str, path: &Path, options: &SpeechOptions) -> Result<()> {
+    global_manager().synthesize_to_file(text, path, options)
 }
 
 /// Stop TTS playback
 pub fn stop_tts() -> Result<()> {
-    TTS_MANAGER.with(|mgr| {
-        if let Some(ref mut manager) = *mgr.borrow_mut() {
-            manager.stop()
-        } else {
-            Ok(())
-        }
-    })
+    // Signal Edge playback first, so it stops even if another call holds
+    // the manager lock while synthesizing
+    stop_edge_audio();
+    
+    match TTS_MANAGER.get() {
+        Some(manager) => lock(manager).stop(),
+        None => Ok(()),
+    }
+}
+
+/// Pause Edge TTS playback at the current position
+///
+/// Playback waits without holding the manager, so other TTS calls still
+/// go through while paused.
+pub fn pause_tts() -> Result<()> {
+    PAUSE_REQUESTED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Resume paused Edge TTS playback
+pub fn resume_tts() -> Result<()> {
+    PAUSE_REQUESTED.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Check if TTS playback is paused
+pub fn is_tts_paused() -> bool {
+    PAUSE_REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Clear the synthesized audio cache
+pub fn clear_tts_cache() {
+    if let Some(manager) = TTS_MANAGER.get() {
+        lock(manager).clear_cache();
+    }
 }
 
 /// Check if TTS is available
 pub fn is_tts_available() -> bool {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-        mgr.as_ref().map(|m| m.is_available()).unwrap_or(false)
-    })
+    global_manager().is_available()
 }
 
 /// Get available voices
 pub fn get_tts_voices() -> Result<Vec<Voice>> {
-    TTS_MANAGER.with(|mgr| {
-        let mut mgr = mgr.borrow_mut();
-        if mgr.is_none() {
-            *mgr = Some(TtsManager::new());
-        }
-        mgr.as_mut()
-            .ok_or_else(|| AudioLearnError::Tts("TTS not initialized".into()))?
-            .voices()
-    })
+    global_manager().voices()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{EdgeProsody, EdgeTransport};
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::thread;
+    
+    const FAKE_AUDIO: &[u8] = &[1, 2, 3];
+    
+    /// Edge transport that answers at once, counting voice list requests
+    #[derive(Default)]
+    struct FakeTransport {
+        voice_requests: AtomicUsize,
+    }
+    
+    impl EdgeTransport for Arc<FakeTransport> {
+        fn voices(&self) -> Result<Vec<Voice>> {
+            self.voice_requests.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![EdgeTtsSync::recommended_english_voice()])
+        }
+        
+        fn synthesize(&self, _ssml: &str, _voice: &str, _prosody: EdgeProsody) -> Result<Vec<u8>> {
+            Ok(FAKE_AUDIO.to_vec())
+        }
+    }
+    
+    /// Edge-only manager over a fake transport
+    fn fake_manager() -> (TtsManager, Arc<FakeTransport>) {
+        let transport = Arc::new(FakeTransport::default());
+        let edge = EdgeTtsSync::with_transport(Arc::clone(&transport));
+        (TtsManager::with_engines(Some(edge), None, TtsPreference::EdgeOnly), transport)
+    }
+    
+    /// Serializes tests that play audio: playback starts by clearing the
+    /// process-wide pause and stop flags, which would upset a test that
+    /// has set them
+    fn playback_flags() -> MutexGuard<'static, ()> {
+        static FLAGS: Mutex<()> = Mutex::new(());
+        FLAGS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    /// Whether another thread can use the manager right now
+    fn usable_from_other_thread(manager: &Mutex<TtsManager>) -> bool {
+        thread::scope(|s| {
+            s.spawn(|| manager.try_lock().ok().map(|mut m| m.voices().is_ok()))
+                .join()
+                .unwrap()
+        }) == Some(true)
+    }
+    
+    #[test]
+    fn test_manager_unlocked_during_playback() {
+        let _flags = playback_flags();
+        let (mut manager, _) = fake_manager();
+        manager.set_max_chunk_len(20);
+        let manager = Mutex::new(manager);
+        
+        let mut chunks_played = 0;
+        let play = |audio: &[u8], _: &mut ProgressTicker<'_>| {
+            assert_eq!(audio, FAKE_AUDIO);
+            assert!(usable_from_other_thread(&manager), "manager locked during playback");
+            chunks_played += 1;
+            Ok(())
+        };
+        let text = "The first sentence. The second sentence.";
+        speak_through(&manager, text, &SpeechOptions::default(), &mut |_| {}, play).unwrap();
+        
+        assert!(chunks_played >= 2);
+        assert_eq!(lock(&manager).last_engine(), Some("Microsoft Edge Neural TTS"));
+    }
+    
+    #[test]
+    fn test_manager_unlocked_during_ssml_playback() {
+        let _flags = playback_flags();
+        let manager = Mutex::new(fake_manager().0);
+        let play = |_: &[u8], _: &mut ProgressTicker<'_>| {
+            assert!(usable_from_other_thread(&manager), "manager locked during playback");
+            Ok(())
+        };
+        let ssml = r#"Wait <break time="200ms"/> then go."#;
+        speak_ssml_through(&manager, ssml, &SpeechOptions::default(), play).unwrap();
+    }
+    
+    #[test]
+    fn test_availability_probed_once_per_ttl() {
+        let (manager, transport) = fake_manager();
+        for _ in 0..5 {
+            assert!(manager.is_available());
+        }
+        assert_eq!(transport.voice_requests.load(Ordering::SeqCst), 1);
+        
+        // Once the answer expires every check probes again
+        let (mut manager, transport) = fake_manager();
+        manager.availability = AvailabilityCache::new(Duration::ZERO);
+        for _ in 0..3 {
+            assert!(manager.is_available());
+        }
+        assert_eq!(transport.voice_requests.load(Ordering::SeqCst), 3);
+    }
+    
+    #[test]
+    fn test_availability_probed_again_after_reinit() {
+        let transport = Arc::new(FakeTransport::default());
+        let init = || {
+            let edge = EdgeTtsSync::with_transport(Arc::clone(&transport));
+            TtsManager::with_engines(Some(edge), None, TtsPreference::EdgeOnly)
+        };
+        
+        let manager = init();
+        assert!(manager.is_available());
+        assert!(manager.is_available());
+        let manager = init();
+        assert!(manager.is_available());
+        assert_eq!(transport.voice_requests.load(Ordering::SeqCst), 2);
+    }
+    
+    #[test]
+    fn test_manager_usable_while_paused() {
+        let _flags = playback_flags();
+        let manager = Mutex::new(fake_manager().0);
+        let play = |_: &[u8], _: &mut ProgressTicker<'_>| {
+            pause_tts().unwrap();
+            thread::scope(|s| {
+                let caller = s.spawn(|| {
+                    let listed = lock(&manager).voices().is_ok();
+                    let still_paused = is_tts_paused();
+                    resume_tts().unwrap();
+                    (listed, still_paused)
+                });
+                // Hold like the playback loop does until resumed
+                let deadline = std::time::Instant::now() + Duration::from_secs(5);
+                while is_tts_paused() && std::time::Instant::now() < deadline {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                assert!(!is_tts_paused(), "manager call blocked while paused");
+                assert_eq!(caller.join().unwrap(), (true, true));
+            });
+            Ok(())
+        };
+        speak_through(&manager, "Pause here.", &SpeechOptions::default(), &mut |_| {}, play).unwrap();
+    }
     
     #[test]
     fn test_tts_manager_creation() {
@@ -409,6 +854,76 @@ mod tests {
         println!("TTS available: {}", manager.is_available());
     }
     
+    #[test]
+    fn test_playback_control_transitions() {
+        // Playing: nothing to do until a pause is requested
+        assert_eq!(playback_control(false, false, false), PlaybackControl::Continue);
+        assert_eq!(playback_control(false, true, false), PlaybackControl::Pause);
+        
+        // Paused: stays paused until resume is requested
+        assert_eq!(playback_control(false, true, true), PlaybackControl::Continue);
+        assert_eq!(playback_control(false, false, true), PlaybackControl::Resume);
+        
+        // Stop wins from either state
+        assert_eq!(playback_control(true, false, false), PlaybackControl::Stop);
+        assert_eq!(playback_control(true, true, true), PlaybackControl::Stop);
+    }
+    
+    #[test]
+    fn test_progress_ticker_emits_every_interval() {
+        // One second of synthetic audio, sampled the way the playback loop does
+        let tone = crate::core::generate_test_tone(440.0, 1.0, 8000);
+        let source = Decoder::new(Cursor::new(tone)).unwrap();
+        let total = source.total_duration().unwrap();
+        
+        let mut reports = Vec::new();
+        let mut record = |elapsed: Duration| reports.push(elapsed);
+        let mut progress = ProgressTicker::new(PROGRESS_INTERVAL, &mut record);
+        
+        let step = Duration::from_millis(50);
+        let mut position = Duration::ZERO;
+        while position < total {
+            progress.tick(position);
+            position += step;
+        }
+        progress.finish_chunk(total);
+        
+        let millis: Vec<u128> = reports.iter().map(|d| d.as_millis()).collect();
+        assert_eq!(millis, vec![0, 200, 400, 600, 800, 1000]);
+    }
+    
+    #[test]
+    fn test_progress_ticker_accumulates_chunks() {
+        let mut reports = Vec::new();
+        let mut record = |elapsed: Duration| reports.push(elapsed);
+        let mut progress = ProgressTicker::new(PROGRESS_INTERVAL, &mut record);
+        
+        progress.tick(Duration::ZERO);
+        progress.tick(Duration::from_millis(150));
+        progress.finish_chunk(Duration::from_millis(300));
+        
+        // Second chunk picks up where the first ended
+        progress.tick(Duration::ZERO);
+        progress.tick(Duration::from_millis(250));
+        
+        let millis: Vec<u128> = reports.iter().map(|d| d.as_millis()).collect();
+        assert_eq!(millis, vec![0, 300, 550]);
+    }
+    
+    #[test]
+    fn test_preference_shared_across_threads() {
+        init_tts_with_preference(TtsPreference::NativeOnly);
+        
+        let seen = std::thread::spawn(tts_preference).join().unwrap();
+        assert_eq!(seen, TtsPreference::NativeOnly);
+        
+        // Re-initializing from another thread is visible here too
+        std::thread::spawn(|| init_tts_with_preference(TtsPreference::EdgeFirst))
+            .join()
+            .unwrap();
+        assert_eq!(tts_preference(), TtsPreference::EdgeFirst);
+    }
+    
     #[test]
     fn test_tts_voices() {
         let mut manager = TtsManager::new();
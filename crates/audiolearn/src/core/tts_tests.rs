@@ -9,6 +9,7 @@ mod tts_tests {
         EdgeTts, NativeTts, TtsManager,
         synthesize_text, get_tts_voices, is_tts_available,
     };
+    use crate::common::AudioLearnError;
     use crate::spi::tts::{SpeechOptions, TtsEngine};
     use rodio::Source;
     
@@ -244,4 +245,49 @@ mod tts_tests {
             println!("⚠️ Edge TTS not available");
         }
     }
+    
+    /// Test exporting narration to an MP3 file (requires network)
+    #[test]
+    fn test_synthesize_to_file() {
+        let edge = EdgeTts::new();
+        
+        if edge.is_available() {
+            let path = std::env::temp_dir().join("audiolearn_export_test.mp3");
+            let mut manager = TtsManager::new();
+            // Small chunks so the export covers the multi-chunk path
+            manager.set_max_chunk_len(40);
+            
+            let text = "This is the first sentence of the export. And this is the second one.";
+            match manager.synthesize_to_file(text, &path, &SpeechOptions::default()) {
+                Ok(()) => {
+                    let bytes = std::fs::read(&path).expect("Exported file should be readable");
+                    let _ = std::fs::remove_file(&path);
+                    
+                    println!("✅ Exported {} bytes to {}", bytes.len(), path.display());
+                    assert!(!bytes.is_empty(), "Exported audio should not be empty");
+                    
+                    // Check for MP3 header (0xFF 0xFB or ID3 header)
+                    let is_mp3 = (bytes.len() >= 2 && bytes[0] == 0xFF && (bytes[1] & 0xE0) == 0xE0)
+                        || (bytes.len() >= 3 && &bytes[0..3] == b"ID3");
+                    assert!(is_mp3, "Exported file should look like MP3");
+                }
+                Err(e) => {
+                    println!("⚠️ Export failed: {}", e);
+                }
+            }
+        } else {
+            println!("⚠️ Edge TTS not available");
+        }
+    }
+    
+    /// Exporting to a non-MP3 path is rejected before synthesis
+    #[test]
+    fn test_synthesize_to_file_rejects_extension() {
+        let mut manager = TtsManager::new();
+        let path = std::env::temp_dir().join("audiolearn_export_test.wav");
+        
+        let result = manager.synthesize_to_file("Hello", &path, &SpeechOptions::default());
+        assert!(matches!(result, Err(AudioLearnError::Io(_))));
+        assert!(!path.exists());
+    }
 }
@@ -0,0 +1,117 @@
+//! Grouping and filtering of TTS voices for voice pickers
+
+use std::collections::BTreeMap;
+use crate::spi::tts::Voice;
+
+/// Primary language subtag of a voice, lowercased ("en" for "en-US")
+pub fn voice_language(voice: &Voice) -> String {
+    voice.language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Voices grouped by primary language, sorted by name within each language
+pub fn voices_by_language(voices: &[Voice]) -> BTreeMap<String, Vec<Voice>> {
+    let mut groups: BTreeMap<String, Vec<Voice>> = BTreeMap::new();
+    for voice in voices {
+        groups.entry(voice_language(voice)).or_default().push(voice.clone());
+    }
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    groups
+}
+
+/// Voices whose language starts with `lang_prefix` ("en" or "en-GB"),
+/// optionally only neural ones. An empty prefix matches every language.
+pub fn filter_voices(voices: &[Voice], lang_prefix: &str, neural_only: bool) -> Vec<Voice> {
+    let prefix = lang_prefix.to_lowercase();
+    voices
+        .iter()
+        .filter(|v| !neural_only || v.is_neural)
+        .filter(|v| {
+            let language = v.language.to_lowercase().replace('_', "-");
+            prefix.is_empty()
+                || language == prefix
+                || language.strip_prefix(&prefix).is_some_and(|rest| rest.starts_with('-'))
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spi::tts::VoiceGender;
+
+    fn voice(name: &str, language: &str, is_neural: bool) -> Voice {
+        Voice {
+            id: format!("{}-{}", language, name),
+            name: name.to_string(),
+            language: language.to_string(),
+            gender: VoiceGender::Female,
+            is_neural,
+        }
+    }
+
+    fn sample_voices() -> Vec<Voice> {
+        vec![
+            voice("Sonia", "en-GB", true),
+            voice("Aria", "en-US", true),
+            voice("Zira", "en-US", false),
+            voice("Elvira", "es-ES", true),
+            voice("Helena", "es_ES", false),
+            voice("Nanami", "ja-JP", true),
+            voice("Denise", "fr-FR", false),
+        ]
+    }
+
+    fn names(voices: &[Voice]) -> Vec<&str> {
+        voices.iter().map(|v| v.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_voices_by_language() {
+        let groups = voices_by_language(&sample_voices());
+
+        let languages: Vec<_> = groups.keys().map(String::as_str).collect();
+        assert_eq!(languages, vec!["en", "es", "fr", "ja"]);
+        assert_eq!(names(&groups["en"]), vec!["Aria", "Sonia", "Zira"]);
+        assert_eq!(names(&groups["es"]), vec!["Elvira", "Helena"]);
+        assert_eq!(names(&groups["ja"]), vec!["Nanami"]);
+    }
+
+    #[test]
+    fn test_filter_by_language() {
+        let voices = sample_voices();
+
+        assert_eq!(names(&filter_voices(&voices, "en", false)), vec!["Sonia", "Aria", "Zira"]);
+        assert_eq!(names(&filter_voices(&voices, "en-US", false)), vec!["Aria", "Zira"]);
+        assert_eq!(names(&filter_voices(&voices, "ES-es", false)), vec!["Elvira", "Helena"]);
+        assert!(filter_voices(&voices, "de", false).is_empty());
+        // A prefix only matches whole subtags
+        assert!(filter_voices(&voices, "e", false).is_empty());
+        assert_eq!(filter_voices(&voices, "", false).len(), voices.len());
+    }
+
+    #[test]
+    fn test_filter_neural_only() {
+        let voices = sample_voices();
+
+        assert_eq!(names(&filter_voices(&voices, "en", true)), vec!["Sonia", "Aria"]);
+        assert!(filter_voices(&voices, "fr", true).is_empty());
+        assert_eq!(names(&filter_voices(&voices, "", true)), vec!["Sonia", "Aria", "Elvira", "Nanami"]);
+    }
+
+    #[test]
+    fn test_display_label() {
+        let mut aria = voice("Aria", "en-US", true);
+        assert_eq!(aria.display_label(), "Aria (en-US, Female, Neural)");
+
+        aria.is_neural = false;
+        aria.gender = VoiceGender::Male;
+        assert_eq!(aria.display_label(), "Aria (en-US, Male)");
+    }
+}
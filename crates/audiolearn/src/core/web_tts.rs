@@ -148,9 +148,46 @@ pub fn web_speak_text(text: &str) -> Result<()> {
     tts.speak(text, &SpeechOptions::default())
 }
 
+/// Speak text with custom options using Web Speech API (convenience function)
+#[cfg(feature = "web")]
+pub fn web_speak_text_with_options(text: &str, options: &SpeechOptions) -> Result<()> {
+    let mut tts = WebTts::new()?;
+    tts.speak(text, options)
+}
+
 /// Stop Web Speech API (convenience function)
 #[cfg(feature = "web")]
 pub fn web_stop_tts() -> Result<()> {
     let mut tts = WebTts::default();
     tts.stop()
 }
+
+/// Pause Web Speech API (convenience function)
+#[cfg(feature = "web")]
+pub fn web_pause_tts() -> Result<()> {
+    let window = web_sys::window()
+        .ok_or_else(|| AudioLearnError::Tts("No window object".into()))?;
+    let synth = window.speech_synthesis()
+        .map_err(|_| AudioLearnError::Tts("SpeechSynthesis not available".into()))?;
+    synth.pause();
+    Ok(())
+}
+
+/// Resume Web Speech API (convenience function)
+#[cfg(feature = "web")]
+pub fn web_resume_tts() -> Result<()> {
+    let window = web_sys::window()
+        .ok_or_else(|| AudioLearnError::Tts("No window object".into()))?;
+    let synth = window.speech_synthesis()
+        .map_err(|_| AudioLearnError::Tts("SpeechSynthesis not available".into()))?;
+    synth.resume();
+    Ok(())
+}
+
+/// Whether Web Speech API output is paused
+#[cfg(feature = "web")]
+pub fn web_is_tts_paused() -> bool {
+    web_sys::window()
+        .and_then(|window| window.speech_synthesis().ok())
+        .is_some_and(|synth| synth.paused())
+}
@@ -3,7 +3,13 @@
 use dioxus::prelude::*;
 use ::components::prelude::*;
 use crate::api::*;
-use crate::core::{get_sample_courses, speak_text, stop_tts};
+use crate::common::Seconds;
+use crate::core::{
+    analytics, flush_analytics, get_sample_courses, load_app_state, mark_lesson_complete,
+    resume_narration, saved_speech_options, speak_text_with_progress, stop_tts,
+    update_app_state, PlaybackData, PlaybackPosition, SleepCountdown, SleepTimer,
+};
+use crate::spi::Analytics;
 use crate::facade::*;
 
 /// Navigation routes
@@ -31,9 +37,187 @@ pub fn AudioLearnApp() -> Element {
     let mut current_course = use_signal(|| Option::<Course>::None);
     let mut current_lesson = use_signal(|| Option::<Lesson>::None);
     let mut is_playing = use_signal(|| false);
-    let position = use_signal(|| 0u32);
+    let mut position = use_signal(|| 0u32);
     let mut show_player = use_signal(|| false);
+    let mut app_state = use_signal(load_app_state);
+    let completed_lessons = use_memo(move || app_state.read().playback_data.completed_lessons());
+    let last_played = use_memo(move || app_state.read().playback_data.last_played());
+    let auto_play_next = use_memo(move || app_state.read().settings.playback.auto_play_next);
+    // Lesson to start next, queued when the one playing finishes
+    let mut up_next = use_signal(|| Option::<(String, Lesson)>::None);
     
+    // The webview's storage may have been cleared; bring back what the
+    // Tauri store still has
+    #[cfg(feature = "tauri")]
+    use_future(move || async move {
+        match crate::core::TauriStorage::default().restore().await {
+            Ok(0) => {}
+            Ok(_) => app_state.set(load_app_state()),
+            Err(e) => eprintln!("Failed to restore saved state: {}", e),
+        }
+    });
+    
+    // Only changed by the desktop-only sleep timer handlers below
+    #[cfg_attr(not(feature = "desktop"), allow(unused_mut))]
+    let mut sleep_timer = use_signal(SleepTimer::default);
+    #[cfg_attr(not(feature = "desktop"), allow(unused_mut))]
+    let mut sleep_countdown = use_signal(SleepCountdown::new);
+    
+    // Stop playback once the sleep timer runs out
+    #[cfg(feature = "desktop")]
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            if !sleep_countdown.peek().is_running() {
+                continue;
+            }
+            if sleep_countdown.write().check_and_stop() {
+                is_playing.set(false);
+                sleep_timer.set(SleepTimer::Off);
+            }
+        }
+    });
+    
+    // The countdown is only checked on desktop, so only offer it there
+    #[cfg(feature = "desktop")]
+    let on_sleep_timer_change = Some(EventHandler::new(move |timer: SleepTimer| {
+        let lesson_remaining = current_lesson.peek().as_ref()
+            .map_or(0, |l| l.duration.saturating_sub(*position.peek()));
+        match timer.countdown_seconds(lesson_remaining) {
+            Some(seconds) => sleep_countdown.write().start(seconds),
+            None => sleep_countdown.write().cancel(),
+        }
+        sleep_timer.set(timer);
+    }));
+    #[cfg(not(feature = "desktop"))]
+    let on_sleep_timer_change: Option<EventHandler<SleepTimer>> = None;
+    
+    // Only desktop playback reports when a lesson ends, so autoplay is
+    // only offered there
+    #[cfg(feature = "desktop")]
+    let on_autoplay_change = Some(EventHandler::new(move |enabled: bool| {
+        app_state.write().settings.playback.auto_play_next = enabled;
+        if let Err(e) = crate::core::save_auto_play_next(enabled) {
+            eprintln!("Failed to save autoplay setting: {}", e);
+        }
+    }));
+    #[cfg(not(feature = "desktop"))]
+    let on_autoplay_change: Option<EventHandler<bool>> = None;
+    
+    // Remember where the user is in a lesson so Home can offer to resume it
+    let mut record_position = move |course_id: String, lesson: &Lesson, seconds: Seconds| {
+        let record = |data: &mut PlaybackData| {
+            data.update_progress(lesson.id.clone(), course_id.clone(), seconds, lesson.duration);
+        };
+        record(&mut app_state.write().playback_data);
+        if let Err(e) = update_app_state(|state| record(&mut state.playback_data)) {
+            eprintln!("Failed to save app state: {}", e);
+        }
+    };
+    
+    // Mark a lesson done, on screen and in saved state
+    let mut complete_lesson = move |course_id: String, lesson_id: String| {
+        app_state.write().playback_data.mark_complete(lesson_id.clone(), course_id.clone());
+        if let Err(e) = mark_lesson_complete(&course_id, &lesson_id) {
+            eprintln!("Failed to save lesson completion: {}", e);
+        }
+    };
+    
+    // Read `lesson` aloud with the player starting at `start` seconds
+    let mut play_lesson = move |course_id: String, lesson: Lesson, start: Seconds| {
+        // Use TTS to read the lesson content
+        // Extract transcript text or use description
+        let content = lesson.transcript
+            .as_ref()
+            .map(|segments| {
+                segments.iter()
+                    .map(|s| s.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .or_else(|| lesson.description.clone())
+            .unwrap_or_else(|| "This lesson content will be available soon.".to_string());
+
+        // Resuming picks up at the sentence `start` falls in, and the
+        // player follows what is actually being read from there
+        let resume = resume_narration(&content, start, lesson.duration);
+        let start = resume.position;
+        let lesson_text = if start == 0 {
+            format!("Now playing: {}. {}", lesson.title, content)
+        } else {
+            resume.text.to_string()
+        };
+
+        record_position(course_id.clone(), &lesson, start);
+        current_lesson.set(Some(lesson.clone()));
+        position.set(start);
+        show_player.set(true);
+        is_playing.set(true);
+
+        let user_id = user.read().id.clone();
+        let _ = analytics().track_lesson_start(&user_id, &lesson.id);
+        let _ = analytics().track_tts_played(&user_id, lesson_text.chars().count());
+        flush_analytics();
+        let options = saved_speech_options();
+        // Platform-specific TTS handling
+        #[cfg(feature = "desktop")]
+        spawn(async move {
+            // Elapsed time comes back from the blocking
+            // worker and drives the player's position
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let speaking = tokio::task::spawn_blocking(move || {
+                // Stop any existing speech first
+                let _ = stop_tts();
+                speak_text_with_progress(&lesson_text, &options, move |elapsed| {
+                    let _ = tx.send(elapsed.as_secs() as u32);
+                })
+            });
+            while let Some(seconds) = rx.recv().await {
+                if *position.peek() != start + seconds {
+                    position.set(start + seconds);
+                }
+            }
+            // Stopping or switching lessons also ends playback, so only a
+            // lesson that is still current and playing was heard to the end
+            let finished = matches!(speaking.await, Ok(Ok(())))
+                && *is_playing.peek()
+                && current_lesson.peek().as_ref().is_some_and(|l| l.id == lesson.id);
+            if !finished {
+                return;
+            }
+            complete_lesson(course_id.clone(), lesson.id.clone());
+            
+            // Carry on with the next lesson, unless autoplay is off, the
+            // sleep timer ends here or this was the last lesson
+            let next = courses.peek().iter()
+                .find(|c| c.id == course_id)
+                .and_then(|c| crate::core::autoplay_next(c, &lesson.id, *auto_play_next.peek(), *sleep_timer.peek()))
+                .cloned();
+            match next {
+                Some(next) => up_next.set(Some((course_id, next))),
+                None => {
+                    is_playing.set(false);
+                    if *sleep_timer.peek() == SleepTimer::EndOfLesson {
+                        sleep_countdown.write().cancel();
+                        sleep_timer.set(SleepTimer::Off);
+                    }
+                }
+            }
+        });
+        #[cfg(feature = "web")]
+        {
+            let _ = stop_tts();
+            let _ = speak_text_with_progress(&lesson_text, &options, |_| {});
+        }
+    };
+    
+    // Start the lesson queued by autoplay
+    use_effect(move || {
+        if let Some((course_id, lesson)) = up_next() {
+            up_next.set(None);
+            play_lesson(course_id, lesson, 0);
+        }
+    });
     
     rsx! {
         // Inject CSS
@@ -64,15 +248,39 @@ pub fn AudioLearnApp() -> Element {
             // Main content
             main { class: "app-main",
                 match route.read().clone() {
-                    Route::Home | Route::Library => rsx! {
+                    Route::Library => rsx! {
+                        LibraryPage {
+                            courses: courses.read().clone(),
+                            completed_lessons: completed_lessons(),
+                            on_course_click: move |id: String| {
+                                if let Some(c) = courses.read().iter().find(|c| c.id == id) {
+                                    current_course.set(Some(c.clone()));
+                                    route.set(Route::Course(id));
+                                }
+                            },
+                        }
+                    },
+                    Route::Home => rsx! {
                         HomePage {
                             courses: courses.read().clone(),
+                            completed_lessons: completed_lessons(),
+                            last_played: last_played(),
                             on_course_click: move |id: String| {
                                 if let Some(c) = courses.read().iter().find(|c| c.id == id) {
                                     current_course.set(Some(c.clone()));
                                     route.set(Route::Course(id));
                                 }
                             },
+                            on_resume: move |last: PlaybackPosition| {
+                                let found = courses.read().iter()
+                                    .find(|c| c.id == last.course_id)
+                                    .and_then(|c| Some((c.clone(), c.get_lesson(&last.lesson_id)?.clone())));
+                                if let Some((course, lesson)) = found {
+                                    current_course.set(Some(course));
+                                    route.set(Route::Course(last.course_id.clone()));
+                                    play_lesson(last.course_id, lesson, last.position);
+                                }
+                            },
                         }
                     },
                     Route::Course(_id) => {
@@ -80,51 +288,20 @@ pub fn AudioLearnApp() -> Element {
                             rsx! {
                                 CoursePage {
                                     course: course.clone(),
-                                    completed_lessons: vec![],
+                                    completed_lessons: completed_lessons(),
                                     current_lesson_id: current_lesson.read().as_ref().map(|l| l.id.clone()),
                                     on_lesson_click: {
                                         let course_clone = course.clone();
                                         move |lesson_id: String| {
                                             if let Some(lesson) = course_clone.get_lesson(&lesson_id) {
-                                                current_lesson.set(Some(lesson.clone()));
-                                                show_player.set(true);
-                                                is_playing.set(true);
-                                                
-                                                // Use TTS to read the lesson content
-                                                // Extract transcript text or use description
-                                                let content = lesson.transcript
-                                                    .as_ref()
-                                                    .map(|segments| {
-                                                        segments.iter()
-                                                            .map(|s| s.text.as_str())
-                                                            .collect::<Vec<_>>()
-                                                            .join(" ")
-                                                    })
-                                                    .or_else(|| lesson.description.clone())
-                                                    .unwrap_or_else(|| "This lesson content will be available soon.".to_string());
-                                                
-                                                let lesson_text = format!(
-                                                    "Now playing: {}. {}",
-                                                    lesson.title,
-                                                    content
-                                                );
-                                                // Platform-specific TTS handling
-                                                #[cfg(feature = "desktop")]
-                                                spawn(async move {
-                                                    let _ = tokio::task::spawn_blocking(move || {
-                                                        // Stop any existing speech first
-                                                        let _ = stop_tts();
-                                                        speak_text(&lesson_text)
-                                                    }).await;
-                                                });
-                                                #[cfg(feature = "web")]
-                                                {
-                                                    let _ = stop_tts();
-                                                    let _ = speak_text(&lesson_text);
-                                                }
+                                                play_lesson(course_clone.id.clone(), lesson.clone(), 0);
                                             }
                                         }
                                     },
+                                    on_lesson_complete: {
+                                        let course_id = course.id.clone();
+                                        move |lesson_id: String| complete_lesson(course_id.clone(), lesson_id)
+                                    },
                                     on_back: move |_| route.set(Route::Home),
                                 }
                             }
@@ -151,6 +328,13 @@ pub fn AudioLearnApp() -> Element {
                     },
                     Route::Player => rsx! {
                         div { "Full player view" }
+                        if let Some(lesson) = current_lesson.read().clone() {
+                            AudioProgress {
+                                position: *position.read(),
+                                duration: lesson.duration,
+                                on_seek: move |target| position.set(target),
+                            }
+                        }
                     },
                 }
             }
@@ -159,7 +343,6 @@ pub fn AudioLearnApp() -> Element {
             if *show_player.read() {
                 if let Some(lesson) = current_lesson.read().clone() {
                     {
-                        let lesson_title = lesson.title.clone();
                         rsx! {
                             MiniPlayer {
                                 title: lesson.title,
@@ -168,28 +351,34 @@ pub fn AudioLearnApp() -> Element {
                                 position: *position.read(),
                                 duration: lesson.duration,
                                 is_playing: *is_playing.read(),
-                                on_play: {
-                                    let title = lesson_title.clone();
-                                    move |_| {
+                                sleep_timer: *sleep_timer.read(),
+                                sleep_remaining: sleep_countdown.read().remaining(),
+                                on_sleep_timer_change,
+                                autoplay: auto_play_next(),
+                                on_autoplay_change,
+                                on_play: move |_| {
+                                    // Carry on where narration was paused; if it
+                                    // has ended, start again from the saved position
+                                    if crate::core::is_tts_paused() {
                                         is_playing.set(true);
-                                        let text = format!("Resuming: {}", title);
-                                        #[cfg(feature = "desktop")]
-                                        spawn(async move {
-                                            let _ = tokio::task::spawn_blocking(move || {
-                                                let _ = crate::core::stop_tts();
-                                                crate::core::speak_text(&text)
-                                            }).await;
-                                        });
-                                        #[cfg(feature = "web")]
-                                        {
-                                            let _ = crate::core::stop_tts();
-                                            let _ = crate::core::speak_text(&text);
-                                        }
+                                        let _ = crate::core::resume_tts();
+                                        return;
+                                    }
+                                    let course = current_course.peek().clone();
+                                    let lesson = current_lesson.peek().clone();
+                                    if let (Some(course), Some(lesson)) = (course, lesson) {
+                                        play_lesson(course.id, lesson, *position.peek());
                                     }
                                 },
                                 on_pause: move |_| {
                                     is_playing.set(false);
-                                    let _ = crate::core::stop_tts();
+                                    let _ = crate::core::pause_tts();
+                                    // Save where playback stopped so it can be resumed
+                                    let course = current_course.peek().clone();
+                                    let lesson = current_lesson.peek().clone();
+                                    if let (Some(course), Some(lesson)) = (course, lesson) {
+                                        record_position(course.id, &lesson, *position.peek());
+                                    }
                                 },
                                 on_expand: move |_| route.set(Route::Player),
                             }
@@ -4,6 +4,7 @@ use dioxus::prelude::*;
 use ::components::prelude::*;
 use crate::common::{Seconds, Timestamp};
 use crate::api::Course;
+use crate::core::{seek_by, seek_position, SEEK_STEP};
 
 /// Audio player controls
 #[derive(Props, Clone, PartialEq)]
@@ -59,21 +60,56 @@ pub struct AudioProgressProps {
     pub on_seek: EventHandler<Seconds>,
 }
 
+/// Click anywhere on the bar to seek there; when focused, the left and right
+/// arrows step by [`SEEK_STEP`] and Home/End jump to either end.
 #[component]
 pub fn AudioProgress(props: AudioProgressProps) -> Element {
+    let mut bar = use_signal(|| None::<std::rc::Rc<MountedData>>);
+
     let progress = if props.duration > 0 {
         (props.position as f32 / props.duration as f32) * 100.0
     } else {
         0.0
     };
-    
+
     let pos = Timestamp::new(props.position);
     let dur = Timestamp::new(props.duration);
-    
+    let position = props.position;
+    let duration = props.duration;
+
     rsx! {
         div { class: "audio-progress",
             span { class: "time current", "{pos.format()}" }
             div { class: "progress-bar",
+                role: "slider",
+                tabindex: 0,
+                aria_label: "Seek",
+                aria_valuemin: 0,
+                aria_valuemax: "{duration}",
+                aria_valuenow: "{position}",
+                aria_valuetext: "{pos.format()}",
+                onmounted: move |event| bar.set(Some(event.data())),
+                onclick: move |event| {
+                    let Some(element) = bar() else { return };
+                    let x = event.client_coordinates().x;
+                    spawn(async move {
+                        if let Ok(rect) = element.get_client_rect().await {
+                            let target = seek_position(x - rect.origin.x, rect.width(), duration);
+                            props.on_seek.call(target);
+                        }
+                    });
+                },
+                onkeydown: move |event| {
+                    let target = match event.key() {
+                        Key::ArrowLeft | Key::ArrowDown => seek_by(position, -(SEEK_STEP as i64), duration),
+                        Key::ArrowRight | Key::ArrowUp => seek_by(position, SEEK_STEP as i64, duration),
+                        Key::Home => 0,
+                        Key::End => duration,
+                        _ => return,
+                    };
+                    event.prevent_default();
+                    props.on_seek.call(target);
+                },
                 div { class: "progress-fill", style: "width: {progress}%" }
             }
             span { class: "time total", "{dur.format()}" }
@@ -92,14 +128,20 @@ pub struct CourseCardProps {
 #[component]
 pub fn CourseCard(props: CourseCardProps) -> Element {
     let course_id = props.course.id.clone();
+    let finished = props.progress >= 100.0;
     
     rsx! {
-        div { class: "course-card",
+        div { class: if finished { "course-card completed" } else { "course-card" },
             onclick: move |_| props.on_click.call(course_id.clone()),
             
             div { class: "course-icon", "{props.course.icon}" }
             div { class: "course-info",
-                h4 { "{props.course.title}" }
+                h4 {
+                    "{props.course.title}"
+                    if finished {
+                        Icon { name: IconName::Check, size: Size::Sm, color: "var(--success)" }
+                    }
+                }
                 Progress { 
                     value: props.progress,
                     max: 100.0,
@@ -122,10 +164,26 @@ pub struct MiniPlayerProps {
     pub on_play: EventHandler<()>,
     pub on_pause: EventHandler<()>,
     pub on_expand: EventHandler<()>,
+    /// Selected sleep timer
+    #[props(default)]
+    pub sleep_timer: SleepTimer,
+    /// Seconds until the sleep timer stops playback, while it runs
+    #[props(default)]
+    pub sleep_remaining: Option<Seconds>,
+    /// Optional handler for picking a sleep timer (hides the control if unset)
+    #[props(default)]
+    pub on_sleep_timer_change: Option<EventHandler<SleepTimer>>,
+    /// Whether the next lesson starts when this one finishes
+    #[props(default)]
+    pub autoplay: bool,
+    /// Optional handler for toggling autoplay (hides the control if unset)
+    #[props(default)]
+    pub on_autoplay_change: Option<EventHandler<bool>>,
 }
 
 #[component]
 pub fn MiniPlayer(props: MiniPlayerProps) -> Element {
+    let mut show_sleep_picker = use_signal(|| false);
     let progress = if props.duration > 0 {
         (props.position as f32 / props.duration as f32) * 100.0
     } else {
@@ -167,10 +225,48 @@ pub fn MiniPlayer(props: MiniPlayerProps) -> Element {
                     Icon { name: IconName::SkipForward }
                 }
                 
+                if props.on_sleep_timer_change.is_some() {
+                    button {
+                        class: if props.sleep_remaining.is_some() { "mini-sleep active" } else { "mini-sleep" },
+                        title: "Sleep timer",
+                        onclick: move |e| {
+                            e.stop_propagation();
+                            show_sleep_picker.set(true);
+                        },
+                        Icon { name: IconName::Moon }
+                        if let Some(left) = props.sleep_remaining {
+                            span { class: "mini-sleep-remaining", "{Timestamp::new(left).format()}" }
+                        }
+                    }
+                }
+                
+                if let Some(on_autoplay_change) = props.on_autoplay_change {
+                    button {
+                        class: if props.autoplay { "mini-autoplay active" } else { "mini-autoplay" },
+                        title: if props.autoplay { "Autoplay next lesson: on" } else { "Autoplay next lesson: off" },
+                        onclick: move |e| {
+                            e.stop_propagation();
+                            on_autoplay_change.call(!props.autoplay);
+                        },
+                        Icon { name: IconName::RotateCw }
+                    }
+                }
+                
                 button { class: "mini-expand",
                     Icon { name: IconName::ChevronUp }
                 }
             }
+            
+            if let Some(on_change) = props.on_sleep_timer_change.filter(|_| *show_sleep_picker.read()) {
+                SleepTimerPicker {
+                    current: props.sleep_timer,
+                    on_select: move |timer| {
+                        on_change.call(timer);
+                        show_sleep_picker.set(false);
+                    },
+                    on_close: move |_| show_sleep_picker.set(false),
+                }
+            }
         }
     }
 }
@@ -206,6 +302,9 @@ pub struct LessonRowProps {
     /// Optional handler for reading lesson title aloud
     #[props(default)]
     pub on_read_aloud: Option<EventHandler<String>>,
+    /// Optional handler for marking the lesson as done
+    #[props(default)]
+    pub on_mark_complete: Option<EventHandler<()>>,
 }
 
 #[component]
@@ -223,7 +322,7 @@ pub fn LessonRow(props: LessonRowProps) -> Element {
             onclick: move |_| props.on_click.call(()),
             
             span { class: "lesson-number", "{props.number}" }
-            span { class: "lesson-title", "{props.title}" }
+            span { class: if props.completed { "lesson-title completed" } else { "lesson-title" }, "{props.title}" }
             
             if props.completed {
                 Icon { name: IconName::Check, color: "var(--success)" }
@@ -246,6 +345,19 @@ pub fn LessonRow(props: LessonRowProps) -> Element {
                 }
             }
             
+            // Mark done button
+            if let Some(on_mark) = props.on_mark_complete.filter(|_| !props.completed) {
+                button {
+                    class: "mark-done-btn",
+                    title: "Mark as done",
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        on_mark.call(());
+                    },
+                    Icon { name: IconName::Check, size: Size::Sm }
+                }
+            }
+            
             button { class: "download-btn",
                 Icon { name: IconName::Download, size: Size::Sm }
             }
@@ -300,7 +412,7 @@ pub fn ReadAloudButton(props: ReadAloudButtonProps) -> Element {
             let result = tokio::task::spawn_blocking(move || {
                 // Stop any existing speech first
                 let _ = crate::core::stop_tts();
-                crate::core::speak_text(&text)
+                crate::core::speak_text_with_options(&text, &crate::core::saved_speech_options())
             }).await;
             
             match result {
@@ -321,7 +433,7 @@ pub fn ReadAloudButton(props: ReadAloudButtonProps) -> Element {
         {
             status.set(TtsStatus::Speaking);
             let _ = crate::core::stop_tts();
-            let result = crate::core::speak_text(&text);
+            let result = crate::core::speak_text_with_options(&text, &crate::core::saved_speech_options());
             if let Err(e) = result {
                 eprintln!("TTS Error: {}", e);
             }
@@ -358,7 +470,7 @@ pub fn ReadAloudButton(props: ReadAloudButtonProps) -> Element {
     }
 }
 
-/// TTS Control Bar - Shows during TTS playback with stop button
+/// TTS Control Bar - Shows during TTS playback with pause and stop buttons
 #[derive(Props, Clone, PartialEq)]
 pub struct TtsControlBarProps {
     /// Text being read
@@ -371,6 +483,7 @@ pub struct TtsControlBarProps {
 
 #[component]
 pub fn TtsControlBar(props: TtsControlBarProps) -> Element {
+    let mut paused = use_signal(crate::core::is_tts_paused);
     if !props.is_speaking {
         return rsx! {};
     }
@@ -393,6 +506,28 @@ pub fn TtsControlBar(props: TtsControlBarProps) -> Element {
                 span { "{display_text}" }
             }
             
+            button {
+                class: "tts-pause-btn",
+                onclick: move |_| {
+                    // Pausing keeps the position, so resuming carries on from it
+                    let result = if paused() {
+                        crate::core::resume_tts()
+                    } else {
+                        crate::core::pause_tts()
+                    };
+                    if result.is_ok() {
+                        paused.set(crate::core::is_tts_paused());
+                    }
+                },
+                if paused() {
+                    Icon { name: IconName::Play }
+                    "Resume"
+                } else {
+                    Icon { name: IconName::Pause }
+                    "Pause"
+                }
+            }
+            
             button { 
                 class: "tts-stop-btn",
                 onclick: move |_| props.on_stop.call(()),
@@ -425,12 +560,17 @@ pub fn TtsSettingsPanel(props: TtsSettingsPanelProps) -> Element {
         crate::core::get_tts_voices().unwrap_or_default()
     });
     
-    // Filter to English neural voices for better UX
-    let english_voices: Vec<_> = voices.read()
-        .iter()
-        .filter(|v| v.language.starts_with("en-") && v.is_neural)
-        .cloned()
-        .collect();
+    // Start on the selected voice's language, English otherwise
+    let selected_language = props.selected_voice_id.clone();
+    let mut language = use_signal(move || {
+        selected_language
+            .and_then(|id| voices.read().iter().find(|v| v.id == id).map(crate::core::voice_language))
+            .unwrap_or_else(|| "en".to_string())
+    });
+    let mut neural_only = use_signal(|| true);
+    
+    let languages = crate::core::voices_by_language(&voices.read());
+    let language_voices = crate::core::filter_voices(&voices.read(), &language.read(), *neural_only.read());
     
     let rate_percent = ((props.rate - 0.5) / 1.5 * 100.0) as i32;
     
@@ -441,6 +581,30 @@ pub fn TtsSettingsPanel(props: TtsSettingsPanelProps) -> Element {
                 "Text-to-Speech Settings"
             }
             
+            div { class: "setting-group",
+                label { "Language" }
+                select {
+                    class: "language-select",
+                    onchange: move |e| language.set(e.value()),
+                    
+                    for (code, group) in languages.iter() {
+                        option {
+                            value: "{code}",
+                            selected: *language.read() == *code,
+                            "{code} ({group.len()})"
+                        }
+                    }
+                }
+                label { class: "setting-checkbox",
+                    input {
+                        r#type: "checkbox",
+                        checked: *neural_only.read(),
+                        onchange: move |e| neural_only.set(e.checked()),
+                    }
+                    "Neural voices only"
+                }
+            }
+            
             div { class: "setting-group",
                 label { "Voice" }
                 select {
@@ -449,16 +613,16 @@ pub fn TtsSettingsPanel(props: TtsSettingsPanelProps) -> Element {
                     
                     option { value: "", "Default (Aria)" }
                     
-                    for voice in english_voices.iter() {
+                    for voice in language_voices.iter() {
                         option { 
                             value: "{voice.id}",
                             selected: props.selected_voice_id.as_ref() == Some(&voice.id),
-                            "{voice.name} ({voice.language})"
+                            "{voice.display_label()}"
                         }
                     }
                 }
                 span { class: "setting-hint", 
-                    "Total: {voices.read().len()} voices ({english_voices.len()} English neural)"
+                    "Total: {voices.read().len()} voices ({language_voices.len()} shown)"
                 }
             }
             
@@ -523,7 +687,7 @@ pub fn QuickTtsButton(props: QuickTtsButtonProps) -> Element {
             let result = tokio::task::spawn_blocking(move || {
                 // Stop any existing speech first
                 let _ = crate::core::stop_tts();
-                crate::core::speak_text(&text)
+                crate::core::speak_text_with_options(&text, &crate::core::saved_speech_options())
             }).await;
             
             if let Err(e) = result {
@@ -534,7 +698,7 @@ pub fn QuickTtsButton(props: QuickTtsButtonProps) -> Element {
         #[cfg(feature = "web")]
         {
             let _ = crate::core::stop_tts();
-            let result = crate::core::speak_text(&text);
+            let result = crate::core::speak_text_with_options(&text, &crate::core::saved_speech_options());
             if let Err(e) = result {
                 eprintln!("TTS error: {}", e);
             }
@@ -564,7 +728,7 @@ pub fn QuickTtsButton(props: QuickTtsButtonProps) -> Element {
 // =============================================================================
 
 use crate::common::{Bookmark, PlaybackSpeed};
-use crate::core::{SleepTimer, SearchResult, SearchEngine, LessonProgress};
+use crate::core::{highlight_segments, SleepTimer, SearchResult, SearchEngine, LessonProgress};
 
 /// Full player view with all controls
 #[derive(Props, Clone, PartialEq)]
@@ -845,7 +1009,10 @@ pub struct SearchModalProps {
 pub fn SearchModal(props: SearchModalProps) -> Element {
     let mut query = use_signal(|| String::new());
     let search_engine = use_memo(move || {
+        // Fuzzy matching, minus the weakest fuzzy-only transcript hits
         SearchEngine::new(props.courses.clone())
+            .with_fuzzy(true)
+            .with_min_score(1.0)
     });
     
     let results = use_memo(move || {
@@ -912,7 +1079,11 @@ pub fn SearchModal(props: SearchModalProps) -> Element {
                                         },
                                         span { class: "result-icon", "{course_result.course.icon}" }
                                         div { class: "result-info",
-                                            span { class: "result-title", "{course_result.course.title}" }
+                                            HighlightedText {
+                                                class: "result-title",
+                                                text: course_result.course.title.clone(),
+                                                ranges: course_result.highlights_for("title").to_vec(),
+                                            }
                                             span { class: "result-meta", 
                                                 "Course • {course_result.course.lesson_count()} lessons"
                                             }
@@ -932,7 +1103,11 @@ pub fn SearchModal(props: SearchModalProps) -> Element {
                                         },
                                         Icon { name: IconName::Headphones }
                                         div { class: "result-info",
-                                            span { class: "result-title", "{lesson_result.lesson.title}" }
+                                            HighlightedText {
+                                                class: "result-title",
+                                                text: lesson_result.lesson.title.clone(),
+                                                ranges: lesson_result.highlights_for("title").to_vec(),
+                                            }
                                             span { class: "result-meta", 
                                                 "Lesson • {lesson_result.course_title}"
                                             }
@@ -948,6 +1123,36 @@ pub fn SearchModal(props: SearchModalProps) -> Element {
     }
 }
 
+/// Text with matched ranges wrapped in `mark`
+#[derive(Props, Clone, PartialEq)]
+pub struct HighlightedTextProps {
+    pub text: String,
+    /// Byte ranges to highlight
+    pub ranges: Vec<(usize, usize)>,
+    #[props(default)]
+    pub class: String,
+}
+
+#[component]
+pub fn HighlightedText(props: HighlightedTextProps) -> Element {
+    let segments: Vec<(String, bool)> = highlight_segments(&props.text, &props.ranges)
+        .into_iter()
+        .map(|(segment, is_match)| (segment.to_string(), is_match))
+        .collect();
+    
+    rsx! {
+        span { class: "{props.class}",
+            for (segment, is_match) in segments {
+                if is_match {
+                    mark { class: "search-highlight", "{segment}" }
+                } else {
+                    "{segment}"
+                }
+            }
+        }
+    }
+}
+
 /// Bookmark list component
 #[derive(Props, Clone, PartialEq)]
 pub struct BookmarkListProps {
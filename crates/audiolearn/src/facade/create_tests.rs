@@ -3,7 +3,8 @@
 
 #[cfg(test)]
 mod create_page_tests {
-    use crate::facade::pages::CustomMaterial;
+    use crate::api::CustomMaterial;
+    use crate::facade::pages::uploaded_text;
     
     // ==========================================================================
     // CustomMaterial Tests
@@ -420,4 +421,28 @@ mod create_page_tests {
         assert!(filename.is_none());
         assert!(error.is_none());
     }
+    
+    #[test]
+    fn test_uploaded_text_title_defaults_to_file_stem() {
+        let upload = uploaded_text("rust-programming-guide.txt", "Ownership rules.".to_string()).unwrap();
+        
+        assert_eq!(upload.filename, "rust-programming-guide.txt");
+        assert_eq!(upload.title, "rust-programming-guide");
+        assert_eq!(upload.content, "Ownership rules.");
+        
+        let upload = uploaded_text("chapter.1.notes.md", "Notes".to_string()).unwrap();
+        assert_eq!(upload.title, "chapter.1.notes");
+    }
+    
+    #[test]
+    fn test_uploaded_text_without_stem_uses_filename() {
+        let upload = uploaded_text(".md", "Hidden file".to_string()).unwrap();
+        assert_eq!(upload.title, ".md");
+    }
+    
+    #[test]
+    fn test_uploaded_text_rejects_empty_files() {
+        assert_eq!(uploaded_text("empty.txt", String::new()), Err("empty.txt is empty".to_string()));
+        assert!(uploaded_text("blank.md", "  \n\t ".to_string()).is_err());
+    }
 }
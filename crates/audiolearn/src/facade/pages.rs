@@ -3,23 +3,48 @@
 use dioxus::prelude::*;
 use ::components::prelude::*;
 use crate::api::*;
-use crate::common::Timestamp;
+use crate::common::{Difficulty, Seconds, Timestamp};
+use crate::core::{
+    filter_courses, load_app_state, save_speech_rate, saved_speech_options, CourseFilter,
+    LessonProgress, MaterialRepository, PlaybackPosition,
+};
 use crate::facade::components::*;
 
 /// Home page
 #[derive(Props, Clone, PartialEq)]
 pub struct HomePageProps {
     pub courses: Vec<Course>,
+    pub completed_lessons: Vec<String>,
+    /// Lesson to offer resuming, if the user has played one
+    pub last_played: Option<PlaybackPosition>,
     pub on_course_click: EventHandler<String>,
+    pub on_resume: EventHandler<PlaybackPosition>,
 }
 
 #[component]
 pub fn HomePage(props: HomePageProps) -> Element {
+    // Only offer to resume lessons that still exist
+    let resume = props.last_played.clone().and_then(|last| {
+        let course = props.courses.iter().find(|c| c.id == last.course_id)?;
+        let lesson = course.get_lesson(&last.lesson_id)?;
+        let mut progress = LessonProgress::new(last.lesson_id.clone(), last.course_id.clone(), lesson.duration);
+        progress.position = last.position.min(lesson.duration);
+        Some((course.clone(), lesson.title.clone(), progress, last))
+    });
+    
     rsx! {
         div { class: "home-page",
-            section { class: "section",
-                h2 { "Continue Learning" }
-                // Continue card would go here
+            if let Some((course, lesson_title, progress, last)) = resume {
+                section { class: "section",
+                    h2 { "Continue Learning" }
+                    ContinueLearningCard {
+                        lesson_title,
+                        course_title: course.title,
+                        icon: course.icon,
+                        progress,
+                        on_click: move |_| props.on_resume.call(last.clone()),
+                    }
+                }
             }
             
             section { class: "section",
@@ -28,7 +53,7 @@ pub fn HomePage(props: HomePageProps) -> Element {
                     for course in props.courses.iter() {
                         CourseCard {
                             course: course.clone(),
-                            progress: 40.0,
+                            progress: course.progress(&props.completed_lessons),
                             on_click: props.on_course_click.clone(),
                         }
                     }
@@ -42,6 +67,108 @@ pub fn HomePage(props: HomePageProps) -> Element {
     }
 }
 
+/// Length limits offered by the library filter
+const DURATION_FILTERS: [(Seconds, &str); 3] = [
+    (3600, "Under 1 hour"),
+    (3 * 3600, "Under 3 hours"),
+    (10 * 3600, "Under 10 hours"),
+];
+
+/// Library page: every course, narrowed by difficulty, length and tags
+#[derive(Props, Clone, PartialEq)]
+pub struct LibraryPageProps {
+    pub courses: Vec<Course>,
+    pub completed_lessons: Vec<String>,
+    pub on_course_click: EventHandler<String>,
+}
+
+#[component]
+pub fn LibraryPage(props: LibraryPageProps) -> Element {
+    let mut filter = use_signal(CourseFilter::default);
+    
+    let mut tags: Vec<String> = props.courses.iter().flat_map(|c| c.tags.clone()).collect();
+    tags.sort();
+    tags.dedup();
+    let shown = filter_courses(&props.courses, &filter.read());
+    let current = filter.read().clone();
+    let chip = |active: bool| if active { "filter-chip active" } else { "filter-chip" };
+    
+    rsx! {
+        div { class: "library-page",
+            section { class: "section",
+                h2 { "Library" }
+                
+                div { class: "library-filters",
+                    div { class: "filter-row",
+                        button { class: chip(current.difficulty.is_none()),
+                            onclick: move |_| filter.write().difficulty = None,
+                            "All levels"
+                        }
+                        for difficulty in Difficulty::all() {
+                            button { class: chip(current.difficulty == Some(difficulty)),
+                                onclick: move |_| filter.write().difficulty = Some(difficulty),
+                                "{difficulty.icon()} {difficulty.label()}"
+                            }
+                        }
+                    }
+                    
+                    div { class: "filter-row",
+                        button { class: chip(current.max_duration.is_none()),
+                            onclick: move |_| filter.write().max_duration = None,
+                            "Any length"
+                        }
+                        for (limit, label) in DURATION_FILTERS {
+                            button { class: chip(current.max_duration == Some(limit)),
+                                onclick: move |_| filter.write().max_duration = Some(limit),
+                                "{label}"
+                            }
+                        }
+                    }
+                    
+                    div { class: "filter-row",
+                        for tag in tags {
+                            button { class: chip(current.tags.contains(&tag)),
+                                onclick: {
+                                    let tag = tag.clone();
+                                    move |_| {
+                                        let mut filter = filter.write();
+                                        match filter.tags.iter().position(|t| *t == tag) {
+                                            Some(i) => { filter.tags.remove(i); }
+                                            None => filter.tags.push(tag.clone()),
+                                        }
+                                    }
+                                },
+                                "#{tag}"
+                            }
+                        }
+                    }
+                }
+                
+                if shown.is_empty() {
+                    div { class: "library-empty",
+                        p { "No courses match these filters" }
+                        Button {
+                            variant: Variant::Ghost,
+                            onclick: move |_| filter.set(CourseFilter::default()),
+                            "Clear filters"
+                        }
+                    }
+                } else {
+                    div { class: "course-grid",
+                        for course in shown {
+                            CourseCard {
+                                progress: course.progress(&props.completed_lessons),
+                                course,
+                                on_click: props.on_course_click,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Course detail page
 #[derive(Props, Clone, PartialEq)]
 pub struct CoursePageProps {
@@ -49,6 +176,7 @@ pub struct CoursePageProps {
     pub completed_lessons: Vec<String>,
     pub current_lesson_id: Option<String>,
     pub on_lesson_click: EventHandler<String>,
+    pub on_lesson_complete: EventHandler<String>,
     pub on_back: EventHandler<()>,
 }
 
@@ -129,6 +257,10 @@ pub fn CoursePage(props: CoursePageProps) -> Element {
                                         let id = lesson.id.clone();
                                         move |_| props.on_lesson_click.call(id.clone())
                                     },
+                                    on_mark_complete: {
+                                        let id = lesson.id.clone();
+                                        move |_| props.on_lesson_complete.call(id.clone())
+                                    },
                                     on_read_aloud: move |text: String| {
                                         #[cfg(feature = "desktop")]
                                         spawn(async move {
@@ -136,13 +268,13 @@ pub fn CoursePage(props: CoursePageProps) -> Element {
                                             let _ = tokio::task::spawn_blocking(move || {
                                                 // Stop any existing speech first
                                                 let _ = crate::core::stop_tts();
-                                                crate::core::speak_text(&t)
+                                                crate::core::speak_text_with_options(&t, &saved_speech_options())
                                             }).await;
                                         });
                                         #[cfg(feature = "web")]
                                         {
                                             let _ = crate::core::stop_tts();
-                                            let _ = crate::core::speak_text(&text);
+                                            let _ = crate::core::speak_text_with_options(&text, &saved_speech_options());
                                         }
                                     },
                                 }
@@ -168,7 +300,7 @@ pub fn ProfilePage(props: ProfilePageProps) -> Element {
     
     // TTS settings state
     let mut selected_voice = use_signal(|| Option::<String>::None);
-    let mut speech_rate = use_signal(|| 1.0f32);
+    let mut speech_rate = use_signal(|| load_app_state().settings.tts.rate);
     
     rsx! {
         div { class: "profile-page",
@@ -201,6 +333,9 @@ pub fn ProfilePage(props: ProfilePageProps) -> Element {
                 rate: *speech_rate.read(),
                 on_rate_change: move |rate: f32| {
                     speech_rate.set(rate);
+                    if let Err(e) = save_speech_rate(rate) {
+                        eprintln!("Failed to save speech rate: {}", e);
+                    }
                 },
             }
             
@@ -224,15 +359,6 @@ pub fn ProfilePage(props: ProfilePageProps) -> Element {
 // Create Page - Upload/Paste Learning Material
 // =============================================================================
 
-/// Custom learning material
-#[derive(Clone, Debug, PartialEq)]
-pub struct CustomMaterial {
-    pub id: String,
-    pub title: String,
-    pub content: String,
-    pub created_at: String,
-}
-
 /// Create page props
 #[derive(Props, Clone, PartialEq)]
 pub struct CreatePageProps {
@@ -240,20 +366,72 @@ pub struct CreatePageProps {
     pub on_play: EventHandler<CustomMaterial>,
 }
 
+/// Repository of saved materials on this platform's storage
+fn material_repository() -> MaterialRepository {
+    MaterialRepository::default()
+}
+
+/// Text read from an uploaded file, ready to fill the create form
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UploadedText {
+    pub filename: String,
+    /// Title to use when the user hasn't entered one
+    pub title: String,
+    pub content: String,
+}
+
+/// Check the text read from `filename`; the title defaults to the file name
+/// without its extension
+pub(crate) fn uploaded_text(filename: &str, content: String) -> Result<UploadedText, String> {
+    if content.trim().is_empty() {
+        return Err(format!("{} is empty", filename));
+    }
+    let title = std::path::Path::new(filename)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or_else(|| filename.to_string());
+    Ok(UploadedText { filename: filename.to_string(), title, content })
+}
+
+/// ID of the hidden file input used for uploads on web
+#[cfg(feature = "web")]
+const FILE_INPUT_ID: &str = "material-file-input";
+
+/// Saved materials, or none if they can't be read
+fn load_saved_materials() -> Vec<CustomMaterial> {
+    material_repository().list().unwrap_or_else(|e| {
+        eprintln!("Failed to load saved materials: {}", e);
+        Vec::new()
+    })
+}
+
 #[component]
 pub fn CreatePage(props: CreatePageProps) -> Element {
     let mut title = use_signal(|| String::new());
     let mut content = use_signal(|| String::new());
     let mut is_playing = use_signal(|| false);
-    let mut saved_materials = use_signal(|| Vec::<CustomMaterial>::new());
+    let mut saved_materials = use_signal(load_saved_materials);
+    let mut save_error = use_signal(|| Option::<String>::None);
     let mut show_saved = use_signal(|| false);
     let mut uploaded_filename = use_signal(|| Option::<String>::None);
     let mut upload_error = use_signal(|| Option::<String>::None);
+    let mut export_status = use_signal(|| Option::<String>::None);
     
     // Calculate word count and estimated duration
     let word_count = content.read().split_whitespace().count();
     let estimated_minutes = (word_count as f32 / 150.0).ceil() as u32; // ~150 words/min
     
+    // Character count against the saved TTS limit; the browser engine speaks on web
+    let tts_settings = use_signal(|| {
+        let mut tts = load_app_state().settings.tts;
+        if cfg!(feature = "web") {
+            tts.use_edge_tts = false;
+        }
+        tts
+    });
+    let tts_length = tts_settings.read().check_length(&content.read());
+    
     // File upload handler - platform specific
     #[cfg(feature = "desktop")]
     let upload_file = {
@@ -272,7 +450,9 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
             // Open file dialog synchronously on main thread using pollster
             // (rfd needs to run on a thread with a message loop on Windows)
             let result = rfd::FileDialog::new()
+                .add_filter("Documents", &["txt", "md", "text", "pdf"])
                 .add_filter("Text files", &["txt", "md", "text"])
+                .add_filter("PDF documents", &["pdf"])
                 .add_filter("All files", &["*"])
                 .set_title("Select Learning Material")
                 .pick_file();
@@ -282,38 +462,87 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                     .map(|n| n.to_string_lossy().to_string())
                     .unwrap_or_else(|| "Unknown file".to_string());
                 
-                let file_stem = path.file_stem()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_else(|| filename.clone());
+                let is_pdf = path.extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"));
+                let read_result = if is_pdf {
+                    std::fs::read(&path)
+                        .map_err(|e| format!("Failed to read file: {}", e))
+                        .and_then(|bytes| {
+                            crate::core::extract_text_from_pdf(&bytes).map_err(|e| e.to_string())
+                        })
+                } else {
+                    std::fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))
+                };
                 
-                match std::fs::read_to_string(&path) {
-                    Ok(file_content) => {
-                        content.set(file_content);
-                        uploaded_filename.set(Some(filename));
+                match read_result.and_then(|text| uploaded_text(&filename, text)) {
+                    Ok(upload) => {
+                        content.set(upload.content);
+                        uploaded_filename.set(Some(upload.filename));
                         upload_error.set(None);
                         
                         if title.read().is_empty() {
-                            title.set(file_stem);
+                            title.set(upload.title);
                         }
                     }
                     Err(e) => {
-                        upload_error.set(Some(format!("Failed to read file: {}", e)));
+                        upload_error.set(Some(e));
                     }
                 }
             }
         }
     };
     
-    // Web version - file upload not supported yet
+    // Web version - the button opens a hidden file input, whose change
+    // handler reads the selected file
     #[cfg(feature = "web")]
-    let upload_file = {
-        let upload_error = upload_error.clone();
-        move |_| {
-            let mut upload_error = upload_error.clone();
-            upload_error.set(Some("File upload is only available in the desktop app.".to_string()));
+    let upload_file = move |_| {
+        // Reset the input so picking the same file again still fires `change`
+        document::eval(&format!(
+            "const input = document.getElementById('{}'); input.value = ''; input.click();",
+            FILE_INPUT_ID
+        ));
+    };
+    
+    #[cfg(feature = "web")]
+    let read_selected_file = move |evt: FormEvent| async move {
+        let Some(engine) = evt.files() else { return };
+        let Some(filename) = engine.files().into_iter().next() else { return };
+        
+        let read_result = engine
+            .read_file_to_string(&filename)
+            .await
+            .ok_or_else(|| format!("Failed to read {}", filename))
+            .and_then(|text| uploaded_text(&filename, text));
+        
+        match read_result {
+            Ok(upload) => {
+                content.set(upload.content);
+                uploaded_filename.set(Some(upload.filename));
+                upload_error.set(None);
+                
+                if title.read().is_empty() {
+                    title.set(upload.title);
+                }
+            }
+            Err(e) => {
+                upload_error.set(Some(e));
+            }
         }
     };
     
+    #[cfg(feature = "web")]
+    let file_input = rsx! {
+        input {
+            id: FILE_INPUT_ID,
+            r#type: "file",
+            accept: ".txt,.md,.text",
+            style: "display: none",
+            onchange: read_selected_file,
+        }
+    };
+    #[cfg(not(feature = "web"))]
+    let file_input = rsx! {};
+    
     // Clear uploaded file and content
     let clear_content = move |_| {
         content.set(String::new());
@@ -339,23 +568,19 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
             format!("{}. {}", material_title, text)
         };
         
-        // Limit text length for TTS
-        let text_to_speak = if full_text.len() > 5000 {
-            full_text.chars().take(5000).collect::<String>()
-        } else {
-            full_text
-        };
+        // Long text is chunked by the TTS layer, so no length limit here
+        let text_to_speak = full_text;
         
         // Platform-specific TTS
         #[cfg(feature = "desktop")]
         std::thread::spawn(move || {
             let _ = crate::core::stop_tts();
-            let _ = crate::core::speak_text(&text_to_speak);
+            let _ = crate::core::speak_text_with_options(&text_to_speak, &saved_speech_options());
         });
         #[cfg(feature = "web")]
         {
             let _ = crate::core::stop_tts();
-            let _ = crate::core::speak_text(&text_to_speak);
+            let _ = crate::core::speak_text_with_options(&text_to_speak, &saved_speech_options());
         }
     };
     
@@ -364,6 +589,61 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
         is_playing.set(false);
     };
     
+    // Export narration as MP3 - platform specific
+    #[cfg(feature = "desktop")]
+    let export_audio = move |_| {
+        let text = content.read().clone();
+        let material_title = title.read().clone();
+        
+        if text.is_empty() {
+            return;
+        }
+        
+        let full_text = if material_title.is_empty() {
+            text
+        } else {
+            format!("{}. {}", material_title, text)
+        };
+        let file_name = if material_title.is_empty() {
+            "narration.mp3".to_string()
+        } else {
+            format!("{}.mp3", material_title)
+        };
+        
+        let result = rfd::FileDialog::new()
+            .add_filter("MP3 audio", &["mp3"])
+            .set_file_name(&file_name)
+            .set_title("Save Narration")
+            .save_file();
+        
+        if let Some(path) = result {
+            export_status.set(Some("Saving audio...".to_string()));
+            
+            spawn(async move {
+                let display_path = path.display().to_string();
+                let result = tokio::task::spawn_blocking(move || {
+                    crate::core::synthesize_to_file(
+                        &full_text,
+                        &path,
+                        &crate::spi::tts::SpeechOptions::default(),
+                    )
+                }).await;
+                
+                match result {
+                    Ok(Ok(())) => export_status.set(Some(format!("Saved to {}", display_path))),
+                    Ok(Err(e)) => export_status.set(Some(format!("Export failed: {}", e))),
+                    Err(e) => export_status.set(Some(format!("Export failed: {}", e))),
+                }
+            });
+        }
+    };
+    
+    // Web version - audio export not supported yet
+    #[cfg(feature = "web")]
+    let export_audio = move |_| {
+        export_status.set(Some("Audio export is only available in the desktop app.".to_string()));
+    };
+    
     let save_material = move |_| {
         let material_title = title.read().clone();
         let material_content = content.read().clone();
@@ -383,11 +663,24 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
             created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string(),
         };
         
-        saved_materials.write().push(material);
-        
-        // Clear form
-        title.set(String::new());
-        content.set(String::new());
+        match material_repository().add(material) {
+            Ok(saved) => {
+                saved_materials.write().push(saved);
+                save_error.set(None);
+                
+                // Clear form
+                title.set(String::new());
+                content.set(String::new());
+            }
+            Err(e) => save_error.set(Some(format!("Failed to save: {}", e))),
+        }
+    };
+    
+    let mut delete_material = move |id: String| {
+        match material_repository().remove(&id) {
+            Ok(_) => saved_materials.write().retain(|m| m.id != id),
+            Err(e) => save_error.set(Some(format!("Failed to delete: {}", e))),
+        }
     };
     
     let mut play_saved_material = move |material: CustomMaterial| {
@@ -399,14 +692,14 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
         spawn(async move {
             let _ = tokio::task::spawn_blocking(move || {
                 let _ = crate::core::stop_tts();
-                crate::core::speak_text(&full_text)
+                crate::core::speak_text_with_options(&full_text, &saved_speech_options())
             }).await;
             is_playing.set(false);
         });
         #[cfg(feature = "web")]
         {
             let _ = crate::core::stop_tts();
-            let _ = crate::core::speak_text(&full_text);
+            let _ = crate::core::speak_text_with_options(&full_text, &saved_speech_options());
             is_playing.set(false);
         }
     };
@@ -434,7 +727,11 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                 }
                 button { 
                     class: if *show_saved.read() { "tab active" } else { "tab" },
-                    onclick: move |_| show_saved.set(true),
+                    onclick: move |_| {
+                        // Pick up materials saved elsewhere since this page mounted
+                        saved_materials.set(load_saved_materials());
+                        show_saved.set(true);
+                    },
                     Icon { name: IconName::Bookmark }
                     "Saved ({saved_materials.read().len()})"
                 }
@@ -445,6 +742,7 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                 div { class: "create-form",
                     // Upload section
                     div { class: "upload-section",
+                        {file_input}
                         Button {
                             variant: Variant::Ghost,
                             size: Size::Md,
@@ -491,7 +789,7 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                         label { "Learning Content" }
                         textarea {
                             class: "content-textarea",
-                            placeholder: "Paste or type your learning material here...\n\nOr click 'Upload File' to load a text file.\n\nSupported formats: .txt, .md, .text",
+                            placeholder: "Paste or type your learning material here...\n\nOr click 'Upload File' to load a text or PDF file.\n\nSupported formats: .txt, .md, .text, .pdf",
                             value: "{content}",
                             oninput: move |e| content.set(e.value()),
                             rows: 12,
@@ -508,6 +806,17 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                             Icon { name: IconName::Clock, size: Size::Sm }
                             "~{estimated_minutes} min"
                         }
+                        span {
+                            class: if tts_length.exceeds_limit() { "stat over-limit" } else { "stat" },
+                            "{tts_length.label()}"
+                        }
+                    }
+                    
+                    if let Some(warning) = tts_length.warning() {
+                        div { class: "content-warning",
+                            Icon { name: IconName::Warning, size: Size::Sm }
+                            "{warning}"
+                        }
                     }
                     
                     // Action buttons
@@ -539,6 +848,26 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                             Icon { name: IconName::Bookmark }
                             "Save"
                         }
+                        
+                        Button {
+                            variant: Variant::Ghost,
+                            size: Size::Lg,
+                            disabled: content.read().is_empty(),
+                            onclick: export_audio,
+                            Icon { name: IconName::Download }
+                            "Save as MP3"
+                        }
+                    }
+                    
+                    if let Some(status) = export_status.read().as_ref() {
+                        div { class: "export-status", "{status}" }
+                    }
+                    
+                    if let Some(error) = save_error.read().as_ref() {
+                        div { class: "upload-error",
+                            Icon { name: IconName::Warning, size: Size::Sm }
+                            "{error}"
+                        }
                     }
                 }
             } else {
@@ -555,6 +884,7 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                             {
                                 let mat = material.clone();
                                 let mat_for_play = material.clone();
+                                let id_for_delete = material.id.clone();
                                 rsx! {
                                     div { class: "saved-material-card",
                                         div { class: "material-info",
@@ -573,6 +903,12 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                                                 onclick: move |_| play_saved_material(mat_for_play.clone()),
                                                 Icon { name: IconName::Play }
                                             }
+                                            button {
+                                                class: "delete-btn",
+                                                title: "Delete",
+                                                onclick: move |_| delete_material(id_for_delete.clone()),
+                                                Icon { name: IconName::Trash }
+                                            }
                                         }
                                     }
                                 }
@@ -24,6 +24,12 @@ pub trait Analytics: Send + Sync {
     
     /// Track time spent
     fn track_time_spent(&self, user_id: &Id, seconds: Seconds) -> Result<()>;
+    
+    /// Track text read aloud by TTS
+    fn track_tts_played(&self, user_id: &Id, characters: usize) -> Result<()>;
+    
+    /// Track a search and how many results it returned
+    fn track_search(&self, user_id: &Id, query: &str, results: usize) -> Result<()>;
 }
 
 /// Noop analytics (for when analytics is disabled)
@@ -37,4 +43,6 @@ impl Analytics for NoopAnalytics {
     fn track_quiz_answer(&self, _: &Id, _: &Id, _: bool) -> Result<()> { Ok(()) }
     fn track_bookmark(&self, _: &Id, _: &Id, _: Seconds) -> Result<()> { Ok(()) }
     fn track_time_spent(&self, _: &Id, _: Seconds) -> Result<()> { Ok(()) }
+    fn track_tts_played(&self, _: &Id, _: usize) -> Result<()> { Ok(()) }
+    fn track_search(&self, _: &Id, _: &str, _: usize) -> Result<()> { Ok(()) }
 }
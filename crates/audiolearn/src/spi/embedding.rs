@@ -0,0 +1,12 @@
+//! Text embedding SPI
+//!
+//! Trait for models that map text to vectors, used for semantic search.
+//! Implementations can wrap a local language model or a remote service.
+
+use crate::common::Result;
+
+/// Maps text to a fixed-size vector where related texts point in similar directions
+pub trait TextEmbedder: Send + Sync {
+    /// Embed a piece of text
+    fn embed(&self, text: &str) -> Result<Vec<f32>>;
+}
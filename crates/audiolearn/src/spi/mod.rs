@@ -6,9 +6,11 @@
 mod audio;
 mod storage;
 mod analytics;
+mod embedding;
 pub mod tts;
 
 pub use audio::*;
 pub use storage::*;
 pub use analytics::*;
+pub use embedding::*;
 pub use tts::*;
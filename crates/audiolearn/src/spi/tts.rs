@@ -20,6 +20,14 @@ pub struct Voice {
     pub is_neural: bool,
 }
 
+impl Voice {
+    /// Label for voice pickers, e.g. "Aria (en-US, Female, Neural)"
+    pub fn display_label(&self) -> String {
+        let kind = if self.is_neural { ", Neural" } else { "" };
+        format!("{} ({}, {}{})", self.name, self.language, self.gender.label(), kind)
+    }
+}
+
 /// Voice gender
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VoiceGender {
@@ -28,8 +36,19 @@ pub enum VoiceGender {
     Neutral,
 }
 
+impl VoiceGender {
+    /// Get display label
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Male => "Male",
+            Self::Female => "Female",
+            Self::Neutral => "Neutral",
+        }
+    }
+}
+
 /// Speech synthesis options
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SpeechOptions {
     /// Voice to use (None = default)
     pub voice: Option<Voice>,
@@ -0,0 +1,293 @@
+//! Accordion (collapsible sections) component
+
+use dioxus::prelude::*;
+use crate::icon::{Icon, IconName};
+use crate::{Variant, Size};
+
+/// How many items of an accordion may be open at once
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum AccordionMode {
+    /// Opening an item closes the others
+    #[default]
+    Single,
+    /// Items open and close independently
+    Multiple,
+}
+
+impl AccordionMode {
+    /// Toggle `id` in the list of open items, honoring the mode
+    pub fn toggle(&self, open: &mut Vec<String>, id: &str) {
+        if let Some(pos) = open.iter().position(|o| o == id) {
+            open.remove(pos);
+            return;
+        }
+        if *self == AccordionMode::Single {
+            open.clear();
+        }
+        open.push(id.to_string());
+    }
+}
+
+/// Shared state between an `Accordion` and its items
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    mode: AccordionMode,
+    open: Signal<Vec<String>>,
+}
+
+/// Accordion props
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionProps {
+    /// `AccordionItem` children
+    children: Element,
+
+    /// Single-open or multi-open
+    #[props(default)]
+    pub mode: AccordionMode,
+
+    /// Visual variant
+    #[props(default)]
+    pub variant: Variant,
+
+    /// Size
+    #[props(default)]
+    pub size: Size,
+
+    /// Additional CSS class
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// Accordion component
+///
+/// Coordinates uncontrolled `AccordionItem`s so that, in `Single` mode,
+/// opening one closes the rest.
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     Accordion { mode: AccordionMode::Multiple,
+///         AccordionItem { id: "intro", title: "Introduction", "..." }
+///         AccordionItem { id: "basics", title: "Basics", default_open: true, "..." }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Accordion(props: AccordionProps) -> Element {
+    let open = use_signal(Vec::<String>::new);
+    use_context_provider(|| AccordionContext { mode: props.mode, open });
+
+    let class = format!(
+        "rust-ui-accordion {} {} {}",
+        props.variant.class(),
+        props.size.class(),
+        props.class.as_deref().unwrap_or(""),
+    );
+
+    rsx! {
+        div { class: "{class}",
+            {props.children}
+        }
+    }
+}
+
+/// Accordion item props
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionItemProps {
+    /// Item ID (unique within the accordion)
+    pub id: String,
+
+    /// Header text
+    #[props(default)]
+    pub title: Option<String>,
+
+    /// Custom header content (takes precedence over `title`)
+    #[props(default)]
+    pub header: Option<Element>,
+
+    /// Controlled open state; when `None` the item manages its own state
+    #[props(default)]
+    pub open: Option<bool>,
+
+    /// Initial open state for uncontrolled items
+    #[props(default = false)]
+    pub default_open: bool,
+
+    /// Disabled state
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// Toggle handler, called with the requested open state
+    #[props(default)]
+    pub on_toggle: Option<EventHandler<bool>>,
+
+    /// Panel content
+    children: Element,
+}
+
+/// Accordion item
+///
+/// Works inside an `Accordion` or standalone as a simple collapsible.
+#[component]
+pub fn AccordionItem(props: AccordionItemProps) -> Element {
+    let ctx = try_use_context::<AccordionContext>();
+    let mut local_open = use_signal(|| props.default_open);
+
+    use_hook(|| {
+        let Some(mut ctx) = ctx else { return };
+        if props.default_open {
+            let mode = ctx.mode;
+            mode.toggle(&mut ctx.open.write(), &props.id);
+        }
+    });
+
+    let is_open = props.open.unwrap_or_else(|| match ctx {
+        Some(ctx) => ctx.open.read().contains(&props.id),
+        None => local_open(),
+    });
+
+    let class = format!(
+        "rust-ui-accordion-item {} {}",
+        if is_open { "open" } else { "" },
+        if props.disabled { "disabled" } else { "" },
+    );
+
+    rsx! {
+        div { class: "{class}",
+            button {
+                class: "accordion-header",
+                r#type: "button",
+                aria_expanded: "{is_open}",
+                disabled: props.disabled,
+                onclick: {
+                    let id = props.id.clone();
+                    move |_| {
+                        if props.open.is_none() {
+                            match ctx {
+                                Some(mut ctx) => {
+                                    let mode = ctx.mode;
+                                    mode.toggle(&mut ctx.open.write(), &id);
+                                }
+                                None => local_open.set(!is_open),
+                            }
+                        }
+                        if let Some(handler) = &props.on_toggle {
+                            handler.call(!is_open);
+                        }
+                    }
+                },
+
+                span { class: "accordion-title",
+                    if let Some(header) = &props.header {
+                        {header}
+                    } else if let Some(title) = &props.title {
+                        "{title}"
+                    }
+                }
+                span {
+                    class: "accordion-chevron",
+                    class: if is_open { "open" } else { "" },
+                    Icon { name: IconName::ChevronDown, size: Size::Sm }
+                }
+            }
+
+            div {
+                class: "accordion-panel",
+                class: if is_open { "open" } else { "" },
+                role: "region",
+                aria_hidden: "{!is_open}",
+                div { class: "accordion-content",
+                    {props.children}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    #[test]
+    fn single_mode_keeps_one_item_open() {
+        let mut open = Vec::new();
+        AccordionMode::Single.toggle(&mut open, "a");
+        AccordionMode::Single.toggle(&mut open, "b");
+        assert_eq!(open, vec!["b".to_string()]);
+
+        AccordionMode::Single.toggle(&mut open, "b");
+        assert!(open.is_empty());
+    }
+
+    #[test]
+    fn multiple_mode_toggles_independently() {
+        let mut open = Vec::new();
+        AccordionMode::Multiple.toggle(&mut open, "a");
+        AccordionMode::Multiple.toggle(&mut open, "b");
+        assert_eq!(open, vec!["a".to_string(), "b".to_string()]);
+
+        AccordionMode::Multiple.toggle(&mut open, "a");
+        assert_eq!(open, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn clicking_header_flips_open_class() {
+        fn app() -> Element {
+            rsx! {
+                Accordion {
+                    AccordionItem { id: "intro", title: "Introduction", "Body" }
+                }
+            }
+        }
+
+        let mut dom = TestDom::new(app);
+        assert!(!dom.class_has("rust-ui-accordion-item", "open"));
+        assert!(!dom.class_has("accordion-chevron", "open"));
+
+        assert!(dom.click("accordion-header"));
+        assert!(dom.class_has("rust-ui-accordion-item", "open"));
+        assert!(dom.class_has("accordion-chevron", "open"));
+
+        dom.click("accordion-header");
+        assert!(!dom.class_has("rust-ui-accordion-item", "open"));
+    }
+
+    #[test]
+    fn single_mode_closes_sibling() {
+        fn app() -> Element {
+            rsx! {
+                Accordion {
+                    AccordionItem { id: "a", title: "A", default_open: true, "A body" }
+                    AccordionItem { id: "b", title: "B", "B body" }
+                }
+            }
+        }
+
+        let mut dom = TestDom::new(app);
+        let open_items = |dom: &TestDom| {
+            dom.find_all(
+                |n| n.has_class("rust-ui-accordion-item"),
+                |items| items.iter().map(|n| n.has_class("open")).collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(open_items(&dom), vec![true, false]);
+
+        dom.fire_nth("accordion-header", 1, "onclick", dioxus_html::SerializedMouseData::default());
+        assert_eq!(open_items(&dom), vec![false, true]);
+    }
+
+    #[test]
+    fn controlled_item_follows_prop() {
+        fn app() -> Element {
+            rsx! {
+                AccordionItem { id: "x", title: "X", open: true, "Body" }
+            }
+        }
+
+        let mut dom = TestDom::new(app);
+        assert!(dom.class_has("rust-ui-accordion-item", "open"));
+        dom.click("accordion-header");
+        assert!(dom.class_has("rust-ui-accordion-item", "open"));
+    }
+}
@@ -1,7 +1,8 @@
 //! Alert component
 
 use dioxus::prelude::*;
-use crate::Variant;
+use crate::icon::{Icon, IconName};
+use crate::{Variant, Size};
 
 /// Alert component props
 #[derive(Props, Clone, PartialEq)]
@@ -17,7 +18,7 @@ pub struct AlertProps {
     #[props(default = Variant::Default)]
     pub variant: Variant,
 
-    /// Dismissible
+    /// Show a close button
     #[props(default = false)]
     pub dismissible: bool,
 
@@ -25,9 +26,10 @@ pub struct AlertProps {
     #[props(default = true)]
     pub show_icon: bool,
 
-    /// Dismiss handler
+    /// Close handler. When set, the parent decides whether the alert stays
+    /// rendered; otherwise a dismissed alert hides itself.
     #[props(default)]
-    pub ondismiss: Option<EventHandler<()>>,
+    pub on_close: Option<EventHandler<()>>,
 }
 
 /// Alert component
@@ -46,6 +48,11 @@ pub struct AlertProps {
 /// ```
 #[component]
 pub fn Alert(props: AlertProps) -> Element {
+    let mut dismissed = use_signal(|| false);
+    if dismissed() {
+        return rsx! {};
+    }
+
     let class = format!("rust-ui-alert {}", props.variant.class());
 
     let icon = match props.variant {
@@ -94,14 +101,61 @@ pub fn Alert(props: AlertProps) -> Element {
             if props.dismissible {
                 button {
                     class: "alert-dismiss",
+                    r#type: "button",
+                    aria_label: "Dismiss",
                     onclick: move |_| {
-                        if let Some(handler) = &props.ondismiss {
-                            handler.call(());
+                        match &props.on_close {
+                            Some(handler) => handler.call(()),
+                            None => dismissed.set(true),
                         }
                     },
-                    "×"
+                    Icon { name: IconName::X, size: Size::Sm }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    #[test]
+    fn plain_alert_has_no_close_button() {
+        let dom = TestDom::new(|| rsx! {
+            Alert { variant: Variant::Warning, "Careful" }
+        });
+        assert_eq!(dom.count_class("alert-dismiss"), 0);
+        assert_eq!(dom.text_of("alert-message"), "Careful");
+    }
+
+    #[test]
+    fn close_button_fires_on_close() {
+        fn app() -> Element {
+            let mut closed = use_signal(|| 0);
+            rsx! {
+                Alert { dismissible: true, on_close: move |_| closed += 1, "Tip" }
+                span { class: "closed", "{closed}" }
+            }
+        }
+
+        let mut dom = TestDom::new(app);
+        assert_eq!(dom.count_class("alert-dismiss"), 1);
+        assert_eq!(dom.count_class("rust-ui-icon"), 1);
+
+        dom.click("alert-dismiss");
+        assert_eq!(dom.text_of("closed"), "1");
+        // The handler owns visibility, so the alert stays until the parent removes it
+        assert_eq!(dom.count_class("rust-ui-alert"), 1);
+    }
+
+    #[test]
+    fn uncontrolled_alert_hides_itself() {
+        let mut dom = TestDom::new(|| rsx! {
+            Alert { dismissible: true, "Tip" }
+        });
+        dom.click("alert-dismiss");
+        assert_eq!(dom.count_class("rust-ui-alert"), 0);
+    }
+}
@@ -75,8 +75,14 @@ pub struct AvatarProps {
 }
 
 /// Avatar component
+///
+/// Shows `src` when given and falls back to the initials if there is no
+/// image or it fails to load.
 #[component]
 pub fn Avatar(props: AvatarProps) -> Element {
+    // The source that failed to load, so a new `src` gets a fresh attempt
+    let mut failed_src = use_signal(|| None::<String>);
+
     let class = format!(
         "rust-ui-avatar {} {} {}",
         props.size.class(),
@@ -92,11 +98,15 @@ pub fn Avatar(props: AvatarProps) -> Element {
 
     rsx! {
         div { class: "{class}",
-            if let Some(src) = &props.src {
+            if let Some(src) = props.src.as_ref().filter(|src| failed_src.read().as_ref() != Some(*src)) {
                 img {
                     class: "avatar-image",
                     src: "{src}",
                     alt: props.alt.as_deref().unwrap_or("Avatar"),
+                    onerror: {
+                        let src = src.clone();
+                        move |_| failed_src.set(Some(src.clone()))
+                    },
                 }
             } else {
                 span { class: "avatar-fallback", "{initials}" }
@@ -144,3 +154,41 @@ pub fn AvatarGroup(props: AvatarGroupProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    #[test]
+    fn src_renders_image() {
+        let dom = TestDom::new(|| rsx! {
+            Avatar { src: "https://example.com/me.png", fallback: "JD" }
+        });
+
+        assert_eq!(dom.attr_of("avatar-image", "src").as_deref(), Some("https://example.com/me.png"));
+        assert_eq!(dom.count_class("avatar-fallback"), 0);
+    }
+
+    #[test]
+    fn no_src_shows_fallback_initials() {
+        let dom = TestDom::new(|| rsx! {
+            Avatar { fallback: "JD", size: Size::Lg }
+        });
+
+        assert_eq!(dom.count_tag("img"), 0);
+        assert_eq!(dom.text_of("avatar-fallback"), "JD");
+        assert!(dom.class_has("rust-ui-avatar", "size-lg"));
+    }
+
+    #[test]
+    fn load_error_falls_back_to_initials() {
+        let mut dom = TestDom::new(|| rsx! {
+            Avatar { src: "broken.png", alt: "jane doe" }
+        });
+
+        assert!(dom.image_error("avatar-image"));
+        assert_eq!(dom.count_tag("img"), 0);
+        assert_eq!(dom.text_of("avatar-fallback"), "JA");
+    }
+}
@@ -28,12 +28,54 @@ pub struct BadgeProps {
     /// Dot indicator (no text)
     #[props(default = false)]
     pub dot: bool,
+
+    /// Numeric count bubble (replaces the content)
+    #[props(default)]
+    pub count: Option<u32>,
+
+    /// Largest count shown before switching to "{max}+"
+    #[props(default = 99)]
+    pub max: u32,
+
+    /// Render the bubble when the count is zero
+    #[props(default = false)]
+    pub show_zero: bool,
+}
+
+/// Text for a count bubble, e.g. `100` with max `99` → `"99+"`
+pub fn format_count(count: u32, max: u32) -> String {
+    if count > max {
+        format!("{max}+")
+    } else {
+        count.to_string()
+    }
 }
 
 /// Badge component
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     Badge { variant: Variant::Success, "New" }
+///     Badge { variant: Variant::Danger, count: unread }
+///     Badge { variant: Variant::Danger, dot: true }
+/// }
+/// ```
 #[component]
 pub fn Badge(props: BadgeProps) -> Element {
-    if props.dot {
+    if let Some(count) = props.count {
+        if count == 0 && !props.show_zero {
+            return rsx! {};
+        }
+        let class = format!(
+            "rust-ui-badge-count {} {}",
+            props.variant.class(),
+            props.size.class(),
+        );
+        rsx! {
+            span { class: "{class}", aria_label: "{count}", "{format_count(count, props.max)}" }
+        }
+    } else if props.dot {
         let dot_class = format!("rust-ui-badge-dot {}", props.variant.class());
         rsx! {
             span { class: "{dot_class}" }
@@ -52,6 +94,61 @@ pub fn Badge(props: BadgeProps) -> Element {
     }
 }
 
+/// Where a badge sits on its wrapped element
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgePlacement {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+impl BadgePlacement {
+    pub fn class(&self) -> &'static str {
+        match self {
+            BadgePlacement::TopRight => "badge-top-right",
+            BadgePlacement::TopLeft => "badge-top-left",
+            BadgePlacement::BottomRight => "badge-bottom-right",
+            BadgePlacement::BottomLeft => "badge-bottom-left",
+        }
+    }
+}
+
+/// Badge wrapper props
+#[derive(Props, Clone, PartialEq)]
+pub struct BadgeWrapperProps {
+    /// Element the badge is positioned over
+    children: Element,
+
+    /// The badge (usually a count or dot `Badge`)
+    pub badge: Element,
+
+    /// Corner to pin the badge to
+    #[props(default)]
+    pub placement: BadgePlacement,
+}
+
+/// Positions a badge over the corner of another element
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     BadgeWrapper { badge: rsx! { Badge { variant: Variant::Danger, count: 3 } },
+///         Icon { name: IconName::Bell }
+///     }
+/// }
+/// ```
+#[component]
+pub fn BadgeWrapper(props: BadgeWrapperProps) -> Element {
+    rsx! {
+        span { class: "rust-ui-badge-wrapper",
+            {props.children}
+            span { class: "badge-anchor {props.placement.class()}", {props.badge} }
+        }
+    }
+}
+
 /// Status badge with dot indicator
 #[derive(Props, Clone, PartialEq)]
 pub struct StatusBadgeProps {
@@ -73,3 +170,67 @@ pub fn StatusBadge(props: StatusBadgeProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    #[test]
+    fn count_overflows_past_max() {
+        assert_eq!(format_count(0, 99), "0");
+        assert_eq!(format_count(99, 99), "99");
+        assert_eq!(format_count(100, 99), "99+");
+        assert_eq!(format_count(12, 9), "9+");
+    }
+
+    #[test]
+    fn count_mode_renders_bubble() {
+        let dom = TestDom::new(|| rsx! {
+            Badge { variant: Variant::Danger, count: 100 }
+        });
+        assert_eq!(dom.text_of("rust-ui-badge-count"), "99+");
+        assert!(dom.class_has("rust-ui-badge-count", "variant-danger"));
+    }
+
+    #[test]
+    fn zero_count_is_hidden_by_default() {
+        let dom = TestDom::new(|| rsx! { Badge { count: 0 } });
+        assert_eq!(dom.count_class("rust-ui-badge-count"), 0);
+
+        let dom = TestDom::new(|| rsx! { Badge { count: 0, show_zero: true } });
+        assert_eq!(dom.text_of("rust-ui-badge-count"), "0");
+    }
+
+    #[test]
+    fn dot_renders_no_text() {
+        let dom = TestDom::new(|| rsx! {
+            Badge { dot: true, "ignored" }
+        });
+        assert_eq!(dom.count_class("rust-ui-badge-dot"), 1);
+        assert_eq!(dom.text_of("rust-ui-badge-dot"), "");
+    }
+
+    #[test]
+    fn text_badge_unchanged() {
+        let dom = TestDom::new(|| rsx! {
+            Badge { pill: true, "New" }
+        });
+        assert_eq!(dom.text_of("rust-ui-badge"), "New");
+        assert!(dom.class_has("rust-ui-badge", "pill"));
+    }
+
+    #[test]
+    fn wrapper_positions_badge_over_child() {
+        let dom = TestDom::new(|| rsx! {
+            BadgeWrapper {
+                badge: rsx! { Badge { dot: true } },
+                placement: BadgePlacement::BottomLeft,
+                span { class: "bell", "bell" }
+            }
+        });
+        assert_eq!(dom.count_class("bell"), 1);
+        assert!(dom.class_has("badge-anchor", "badge-bottom-left"));
+        assert_eq!(dom.count_class("rust-ui-badge-dot"), 1);
+    }
+}
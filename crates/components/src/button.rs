@@ -1,6 +1,7 @@
 //! Button component
 
 use dioxus::prelude::*;
+use crate::spinner::Spinner;
 use crate::{Variant, Size};
 
 /// Button component props
@@ -21,7 +22,7 @@ pub struct ButtonProps {
     #[props(default = false)]
     pub disabled: bool,
 
-    /// Loading state
+    /// Loading state: shows a spinner, keeps the button's width and ignores clicks
     #[props(default = false)]
     pub loading: bool,
 
@@ -71,14 +72,24 @@ pub fn Button(props: ButtonProps) -> Element {
         props.class.as_deref().unwrap_or(""),
     );
 
+    // Loading implies disabled without touching the caller's `disabled` prop
     let disabled = props.disabled || props.loading;
+    let spinner_size = match props.size {
+        Size::Xs | Size::Sm => Size::Xs,
+        Size::Md => Size::Sm,
+        Size::Lg | Size::Xl => Size::Md,
+    };
 
     rsx! {
         button {
             class: "{class}",
             r#type: "{props.r#type}",
             disabled: disabled,
+            aria_busy: props.loading,
             onclick: move |evt| {
+                if disabled {
+                    return;
+                }
                 if let Some(handler) = &props.onclick {
                     handler.call(evt);
                 }
@@ -86,29 +97,7 @@ pub fn Button(props: ButtonProps) -> Element {
 
             if props.loading {
                 span { class: "button-spinner",
-                    // Spinner SVG
-                    svg {
-                        class: "animate-spin",
-                        width: "16",
-                        height: "16",
-                        view_box: "0 0 24 24",
-                        circle {
-                            cx: "12",
-                            cy: "12",
-                            r: "10",
-                            stroke: "currentColor",
-                            stroke_width: "3",
-                            fill: "none",
-                            opacity: "0.25",
-                        }
-                        path {
-                            d: "M12 2a10 10 0 0 1 10 10",
-                            stroke: "currentColor",
-                            stroke_width: "3",
-                            fill: "none",
-                            stroke_linecap: "round",
-                        }
-                    }
+                    Spinner { size: spinner_size }
                 }
             }
 
@@ -178,3 +167,48 @@ pub fn IconButton(props: IconButtonProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    fn counting_app(loading: bool) -> Element {
+        let mut clicks = use_signal(|| 0);
+        rsx! {
+            Button {
+                loading: loading,
+                onclick: move |_| clicks += 1,
+                "Save"
+            }
+            span { class: "clicks", "{clicks}" }
+        }
+    }
+
+    #[test]
+    fn loading_button_is_disabled_with_spinner() {
+        let dom = TestDom::new(|| counting_app(true));
+        assert!(dom.class_has("rust-ui-button", "loading"));
+        assert_eq!(dom.attr_of("rust-ui-button", "disabled").as_deref(), Some("true"));
+        assert_eq!(dom.count_class("rust-ui-spinner"), 1);
+        // Content stays rendered so the button keeps its width
+        assert_eq!(dom.count_class("button-content"), 1);
+    }
+
+    #[test]
+    fn loading_button_ignores_clicks() {
+        let mut dom = TestDom::new(|| counting_app(true));
+        dom.click("rust-ui-button");
+        assert_eq!(dom.text_of("clicks"), "0");
+    }
+
+    #[test]
+    fn idle_button_fires_onclick() {
+        let mut dom = TestDom::new(|| counting_app(false));
+        assert_eq!(dom.count_class("rust-ui-spinner"), 0);
+        assert_eq!(dom.attr_of("rust-ui-button", "disabled"), None);
+
+        dom.click("rust-ui-button");
+        assert_eq!(dom.text_of("clicks"), "1");
+    }
+}
@@ -1,7 +1,7 @@
 //! Input component
 
 use dioxus::prelude::*;
-use crate::Size;
+use crate::{Variant, Size};
 
 /// Input types
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
@@ -39,9 +39,13 @@ impl InputType {
 /// Input component props
 #[derive(Props, Clone, PartialEq)]
 pub struct InputProps {
-    /// Input value
+    /// Controlled value; when `None` the input keeps its own state
     #[props(default)]
-    pub value: String,
+    pub value: Option<String>,
+
+    /// Initial value for uncontrolled inputs
+    #[props(default)]
+    pub default_value: String,
 
     /// Placeholder text
     #[props(default)]
@@ -55,7 +59,7 @@ pub struct InputProps {
     #[props(default)]
     pub helper: Option<String>,
 
-    /// Error message
+    /// Error message (renders below the input with a danger border)
     #[props(default)]
     pub error: Option<String>,
 
@@ -63,6 +67,10 @@ pub struct InputProps {
     #[props(default)]
     pub input_type: InputType,
 
+    /// Visual variant (an error always renders as `Danger`)
+    #[props(default)]
+    pub variant: Variant,
+
     /// Input size
     #[props(default)]
     pub size: Size,
@@ -107,6 +115,10 @@ pub struct InputProps {
     #[props(default)]
     pub onblur: Option<EventHandler<FocusEvent>>,
 
+    /// Validation hook, called with the current value on change and blur
+    #[props(default)]
+    pub on_validate: Option<EventHandler<String>>,
+
     /// Additional CSS class
     #[props(default)]
     pub class: Option<String>,
@@ -114,6 +126,9 @@ pub struct InputProps {
 
 /// Input component
 ///
+/// Pass `value` + `oninput` for a controlled input, or `default_value` to let
+/// the input track its own state.
+///
 /// # Example
 /// ```rust
 /// rsx! {
@@ -121,24 +136,35 @@ pub struct InputProps {
 ///         label: "Email",
 ///         placeholder: "you@example.com",
 ///         input_type: InputType::Email,
-///         value: email,
-///         onchange: move |v| email.set(v),
+///         value: email(),
+///         oninput: move |v| email.set(v),
+///         error: email_error(),
+///         on_validate: move |v: String| {
+///             email_error.set((!v.contains('@')).then(|| "Enter a valid email".into()))
+///         },
 ///     }
 /// }
 /// ```
 #[component]
 pub fn Input(props: InputProps) -> Element {
+    let mut inner = use_signal(|| props.default_value.clone());
+    let controlled = props.value.is_some();
+    let value = props.value.clone().unwrap_or_else(|| inner.read().clone());
+
     let has_error = props.error.is_some();
+    let variant = if has_error { Variant::Danger } else { props.variant };
 
     let wrapper_class = format!(
-        "rust-ui-input-wrapper {} {}",
+        "rust-ui-input-wrapper {} {} {}",
+        variant.class(),
         props.size.class(),
         if has_error { "has-error" } else { "" },
     );
 
     let input_class = format!(
-        "rust-ui-input {} {}",
+        "rust-ui-input {} {} {}",
         if props.icon.is_some() { "has-icon" } else { "" },
+        if has_error { "has-error" } else { "" },
         props.class.as_deref().unwrap_or(""),
     );
 
@@ -165,12 +191,16 @@ pub fn Input(props: InputProps) -> Element {
                 input {
                     class: "{input_class}",
                     r#type: "{props.input_type.as_str()}",
-                    value: "{props.value}",
+                    value: "{value}",
                     placeholder: props.placeholder.as_deref().unwrap_or(""),
                     disabled: props.disabled,
                     readonly: props.readonly,
                     required: props.required,
+                    aria_invalid: has_error,
                     oninput: move |evt| {
+                        if !controlled {
+                            inner.set(evt.value());
+                        }
                         if let Some(handler) = &props.oninput {
                             handler.call(evt.value());
                         }
@@ -179,25 +209,40 @@ pub fn Input(props: InputProps) -> Element {
                         if let Some(handler) = &props.onchange {
                             handler.call(evt.value());
                         }
+                        if let Some(validate) = &props.on_validate {
+                            validate.call(evt.value());
+                        }
                     },
                     onfocus: move |evt| {
                         if let Some(handler) = &props.onfocus {
                             handler.call(evt);
                         }
                     },
-                    onblur: move |evt| {
-                        if let Some(handler) = &props.onblur {
-                            handler.call(evt);
+                    onblur: {
+                        let value = value.clone();
+                        move |evt| {
+                            if let Some(handler) = &props.onblur {
+                                handler.call(evt);
+                            }
+                            if let Some(validate) = &props.on_validate {
+                                validate.call(value.clone());
+                            }
                         }
                     },
                 }
 
                 // Clear button
-                if props.clearable && !props.value.is_empty() {
+                if props.clearable && !value.is_empty() {
                     button {
                         class: "input-clear",
                         r#type: "button",
                         onclick: move |_| {
+                            if !controlled {
+                                inner.set(String::new());
+                            }
+                            if let Some(handler) = &props.oninput {
+                                handler.call(String::new());
+                            }
                             if let Some(handler) = &props.onchange {
                                 handler.call(String::new());
                             }
@@ -214,7 +259,7 @@ pub fn Input(props: InputProps) -> Element {
 
             // Helper or error text
             if let Some(error) = &props.error {
-                p { class: "input-error", "{error}" }
+                p { class: "input-error", role: "alert", "{error}" }
             } else if let Some(helper) = &props.helper {
                 p { class: "input-helper", "{helper}" }
             }
@@ -288,3 +333,53 @@ pub fn Textarea(props: TextareaProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    #[test]
+    fn error_renders_message_and_error_class() {
+        let dom = TestDom::new(|| rsx! {
+            Input { value: "bob", error: "Enter a valid email" }
+        });
+
+        assert_eq!(dom.text_of("input-error"), "Enter a valid email");
+        assert!(dom.class_has("rust-ui-input-wrapper", "has-error"));
+        assert!(dom.class_has("rust-ui-input-wrapper", "variant-danger"));
+        assert!(dom.class_has("rust-ui-input", "has-error"));
+    }
+
+    #[test]
+    fn no_error_renders_helper() {
+        let dom = TestDom::new(|| rsx! {
+            Input { helper: "We never share it" }
+        });
+
+        assert_eq!(dom.count_class("input-error"), 0);
+        assert_eq!(dom.text_of("input-helper"), "We never share it");
+        assert!(!dom.class_has("rust-ui-input-wrapper", "has-error"));
+    }
+
+    #[test]
+    fn uncontrolled_input_tracks_its_own_value() {
+        let mut dom = TestDom::new(|| rsx! {
+            Input { default_value: "a" }
+        });
+        assert_eq!(dom.attr_of("rust-ui-input", "value").as_deref(), Some("a"));
+
+        dom.input("rust-ui-input", "abc");
+        assert_eq!(dom.attr_of("rust-ui-input", "value").as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn controlled_input_follows_value_prop() {
+        let mut dom = TestDom::new(|| rsx! {
+            Input { value: "fixed" }
+        });
+
+        dom.input("rust-ui-input", "changed");
+        assert_eq!(dom.attr_of("rust-ui-input", "value").as_deref(), Some("fixed"));
+    }
+}
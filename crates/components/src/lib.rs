@@ -18,6 +18,13 @@ pub mod avatar;
 pub mod tooltip;
 pub mod dropdown;
 pub mod icon;
+pub mod accordion;
+pub mod theme;
+
+mod timer;
+
+#[cfg(test)]
+mod test_utils;
 
 pub mod prelude {
     pub use crate::button::*;
@@ -36,6 +43,8 @@ pub mod prelude {
     pub use crate::tooltip::*;
     pub use crate::dropdown::*;
     pub use crate::icon::*;
+    pub use crate::accordion::*;
+    pub use crate::theme::*;
 
     pub use crate::{Variant, Size};
 }
@@ -67,6 +76,18 @@ impl Variant {
             Variant::Link => "variant-link",
         }
     }
+
+    /// Theme custom property holding this variant's color
+    pub fn token(&self) -> &'static str {
+        match self {
+            Variant::Default | Variant::Ghost => "--neutral",
+            Variant::Primary | Variant::Link => "--primary",
+            Variant::Secondary => "--secondary",
+            Variant::Success => "--success",
+            Variant::Warning => "--warning",
+            Variant::Danger => "--danger",
+        }
+    }
 }
 
 /// Component sizes
@@ -90,4 +111,15 @@ impl Size {
             Size::Xl => "size-xl",
         }
     }
+
+    /// Theme custom property holding this size's font size
+    pub fn token(&self) -> &'static str {
+        match self {
+            Size::Xs => "--font-size-xs",
+            Size::Sm => "--font-size-sm",
+            Size::Md => "--font-size-md",
+            Size::Lg => "--font-size-lg",
+            Size::Xl => "--font-size-xl",
+        }
+    }
 }
@@ -20,14 +20,112 @@ pub struct ModalProps {
     #[props(default)]
     pub size: Size,
 
+    /// Close when Escape is pressed
+    #[props(default = true)]
+    pub close_on_escape: bool,
+
     /// Close handler
     #[props(default)]
     pub on_close: Option<EventHandler<()>>,
 }
 
+/// What a key press inside an open modal should do
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModalKeyAction {
+    /// Call `on_close`
+    Close,
+    /// Move focus to the next focusable element, wrapping to the first
+    FocusNext,
+    /// Move focus to the previous focusable element, wrapping to the last
+    FocusPrev,
+    /// Let the key through
+    Ignore,
+}
+
+/// Map a key press to the modal's keyboard behavior
+pub fn modal_key_action(key: &Key, shift: bool, close_on_escape: bool) -> ModalKeyAction {
+    match key {
+        Key::Escape if close_on_escape => ModalKeyAction::Close,
+        Key::Tab if shift => ModalKeyAction::FocusPrev,
+        Key::Tab => ModalKeyAction::FocusNext,
+        _ => ModalKeyAction::Ignore,
+    }
+}
+
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Remember the focused element and focus the first focusable one in the modal
+fn focus_first_script(id: &str) -> String {
+    format!(
+        r#"const m = document.getElementById("{id}");
+        if (m) {{
+            window.__rustUiModalReturn = window.__rustUiModalReturn || {{}};
+            window.__rustUiModalReturn["{id}"] = document.activeElement;
+            (m.querySelector("{FOCUSABLE}") || m).focus();
+        }}"#
+    )
+}
+
+/// Cycle focus within the modal
+fn cycle_focus_script(id: &str, backwards: bool) -> String {
+    format!(
+        r#"const m = document.getElementById("{id}");
+        if (m) {{
+            const f = Array.from(m.querySelectorAll("{FOCUSABLE}"));
+            if (f.length === 0) {{
+                m.focus();
+            }} else {{
+                const i = f.indexOf(document.activeElement);
+                const n = {backwards}
+                    ? (i <= 0 ? f.length - 1 : i - 1)
+                    : (i < 0 || i === f.length - 1 ? 0 : i + 1);
+                f[n].focus();
+            }}
+        }}"#
+    )
+}
+
+/// Return focus to whatever was focused before the modal opened
+fn restore_focus_script(id: &str) -> String {
+    format!(
+        r#"const r = window.__rustUiModalReturn && window.__rustUiModalReturn["{id}"];
+        if (r) {{
+            delete window.__rustUiModalReturn["{id}"];
+            if (document.body.contains(r)) r.focus();
+        }}"#
+    )
+}
+
 /// Modal component
+///
+/// While open, focus moves into the modal, Tab/Shift+Tab cycle within it and
+/// Escape closes it (unless `close_on_escape` is false). Focus returns to the
+/// previously focused element on close.
 #[component]
 pub fn Modal(props: ModalProps) -> Element {
+    let id = use_hook(|| format!("rust-ui-modal-{}", uuid::Uuid::new_v4()));
+
+    let open = props.open;
+    use_effect({
+        let id = id.clone();
+        use_reactive!(|(open)| {
+            let script = if open {
+                focus_first_script(&id)
+            } else {
+                restore_focus_script(&id)
+            };
+            document::eval(&script);
+        })
+    });
+
+    use_drop({
+        let id = id.clone();
+        move || {
+            document::eval(&restore_focus_script(&id));
+        }
+    });
+
     if !props.open {
         return rsx! {};
     }
@@ -50,14 +148,42 @@ pub fn Modal(props: ModalProps) -> Element {
             },
 
             div {
+                id: "{id}",
                 class: "rust-ui-modal {modal_size_class}",
+                role: "dialog",
+                aria_modal: "true",
+                tabindex: "-1",
                 onclick: move |evt| evt.stop_propagation(),
+                onkeydown: {
+                    let id = id.clone();
+                    move |evt: KeyboardEvent| {
+                        let shift = evt.modifiers().contains(Modifiers::SHIFT);
+                        match modal_key_action(&evt.key(), shift, props.close_on_escape) {
+                            ModalKeyAction::Close => {
+                                evt.prevent_default();
+                                if let Some(handler) = &props.on_close {
+                                    handler.call(());
+                                }
+                            }
+                            ModalKeyAction::FocusNext => {
+                                evt.prevent_default();
+                                document::eval(&cycle_focus_script(&id, false));
+                            }
+                            ModalKeyAction::FocusPrev => {
+                                evt.prevent_default();
+                                document::eval(&cycle_focus_script(&id, true));
+                            }
+                            ModalKeyAction::Ignore => {}
+                        }
+                    }
+                },
 
                 if let Some(title) = &props.title {
                     div { class: "modal-header",
                         h2 { class: "modal-title", "{title}" }
                         button {
                             class: "modal-close",
+                            aria_label: "Close",
                             onclick: move |_| {
                                 if let Some(handler) = &props.on_close {
                                     handler.call(());
@@ -88,3 +214,32 @@ pub fn ModalFooter(props: ModalFooterProps) -> Element {
         div { class: "rust-ui-modal-footer", {props.children} }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_closes_when_enabled() {
+        assert_eq!(modal_key_action(&Key::Escape, false, true), ModalKeyAction::Close);
+        assert_eq!(modal_key_action(&Key::Escape, true, true), ModalKeyAction::Close);
+    }
+
+    #[test]
+    fn escape_ignored_when_disabled() {
+        assert_eq!(modal_key_action(&Key::Escape, false, false), ModalKeyAction::Ignore);
+    }
+
+    #[test]
+    fn tab_cycles_focus() {
+        assert_eq!(modal_key_action(&Key::Tab, false, true), ModalKeyAction::FocusNext);
+        assert_eq!(modal_key_action(&Key::Tab, true, true), ModalKeyAction::FocusPrev);
+    }
+
+    #[test]
+    fn other_keys_do_not_close() {
+        for key in [Key::Enter, Key::Character("q".into()), Key::ArrowDown, Key::Backspace] {
+            assert_eq!(modal_key_action(&key, false, true), ModalKeyAction::Ignore);
+        }
+    }
+}
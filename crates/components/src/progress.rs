@@ -7,6 +7,7 @@ use crate::{Variant, Size};
 #[derive(Props, Clone, PartialEq)]
 pub struct ProgressProps {
     /// Current value (0-100)
+    #[props(default)]
     pub value: f32,
 
     /// Maximum value
@@ -37,22 +38,33 @@ pub struct ProgressProps {
     #[props(default = false)]
     pub animated: bool,
 
-    /// Indeterminate (loading) state
+    /// Indeterminate (loading) state: an animated sweeping bar for work of
+    /// unknown length; `value` and `max` are ignored
     #[props(default = false)]
     pub indeterminate: bool,
 }
 
 /// Progress bar component
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     Progress { value: lessons_done as f32, max: lesson_count as f32 }
+///     // Total unknown, e.g. while synthesizing speech
+///     Progress { indeterminate: true }
+/// }
+/// ```
 #[component]
 pub fn Progress(props: ProgressProps) -> Element {
     let percentage = ((props.value / props.max) * 100.0).clamp(0.0, 100.0);
 
     let class = format!(
-        "rust-ui-progress {} {} {} {}",
+        "rust-ui-progress {} {} {} {} {}",
         props.variant.class(),
         props.size.class(),
         if props.striped { "striped" } else { "" },
         if props.animated { "animated" } else { "" },
+        if props.indeterminate { "indeterminate" } else { "" },
     );
 
     let label = props.label.clone().unwrap_or_else(|| format!("{:.0}%", percentage));
@@ -62,14 +74,15 @@ pub fn Progress(props: ProgressProps) -> Element {
             div {
                 class: "progress-track",
                 role: "progressbar",
-                aria_valuenow: "{props.value}",
+                aria_busy: props.indeterminate,
+                aria_valuenow: if !props.indeterminate { Some(props.value.to_string()) } else { None },
                 aria_valuemin: "0",
                 aria_valuemax: "{props.max}",
 
                 div {
                     class: "progress-bar",
                     class: if props.indeterminate { "indeterminate" } else { "" },
-                    style: if !props.indeterminate { format!("width: {}%", percentage) } else { String::new() },
+                    style: if !props.indeterminate { Some(format!("width: {}%", percentage)) } else { None },
 
                     if props.show_label && !props.indeterminate {
                         span { class: "progress-label", "{label}" }
@@ -160,3 +173,31 @@ pub fn CircularProgress(props: CircularProgressProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    #[test]
+    fn indeterminate_animates_without_fixed_width() {
+        let dom = TestDom::new(|| rsx! {
+            Progress { value: 40.0, indeterminate: true }
+        });
+
+        assert!(dom.class_has("progress-bar", "indeterminate"));
+        assert!(dom.class_has("rust-ui-progress", "indeterminate"));
+        assert_eq!(dom.attr_of("progress-bar", "style"), None);
+        assert_eq!(dom.attr_of("progress-track", "aria-valuenow"), None);
+    }
+
+    #[test]
+    fn determinate_sets_width_from_value() {
+        let dom = TestDom::new(|| rsx! {
+            Progress { value: 3.0, max: 4.0 }
+        });
+
+        assert!(!dom.class_has("progress-bar", "indeterminate"));
+        assert_eq!(dom.attr_of("progress-bar", "style").as_deref(), Some("width: 75%"));
+    }
+}
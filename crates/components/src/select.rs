@@ -21,6 +21,32 @@ impl SelectOption {
     }
 }
 
+impl SelectOption {
+    /// Case-insensitive substring match against the label or value
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.label.to_lowercase().contains(&query)
+            || self.value.to_lowercase().contains(&query)
+    }
+}
+
+/// Options matching a type-to-filter query
+pub fn filter_options<'a>(options: &'a [SelectOption], query: &str) -> Vec<&'a SelectOption> {
+    options.iter().filter(|opt| opt.matches(query)).collect()
+}
+
+/// Add `value` to a multi-select selection, or remove it if already selected
+pub fn toggle_value(selected: &[String], value: &str) -> Vec<String> {
+    if selected.iter().any(|v| v == value) {
+        selected.iter().filter(|v| *v != value).cloned().collect()
+    } else {
+        let mut next = selected.to_vec();
+        next.push(value.to_string());
+        next
+    }
+}
+
 impl<V: Into<String>, L: Into<String>> From<(V, L)> for SelectOption {
     fn from((value, label): (V, L)) -> Self {
         Self::new(value, label)
@@ -34,6 +60,18 @@ pub struct SelectProps {
     #[props(default)]
     pub value: String,
 
+    /// Selected values in `multiple` mode
+    #[props(default)]
+    pub values: Vec<String>,
+
+    /// Allow selecting several options
+    #[props(default = false)]
+    pub multiple: bool,
+
+    /// Show a type-to-filter box above the options
+    #[props(default = false)]
+    pub searchable: bool,
+
     /// Options list
     pub options: Vec<SelectOption>,
 
@@ -65,6 +103,10 @@ pub struct SelectProps {
     #[props(default)]
     pub onchange: Option<EventHandler<String>>,
 
+    /// Selection handler with every selected value (fires in both modes)
+    #[props(default)]
+    pub on_change: Option<EventHandler<Vec<String>>>,
+
     /// Additional CSS class
     #[props(default)]
     pub class: Option<String>,
@@ -87,16 +129,34 @@ pub struct SelectProps {
 ///     }
 /// }
 /// ```
+///
+/// Long lists can be narrowed with `searchable`, and `multiple` switches to a
+/// list of toggleable options:
+/// ```rust,ignore
+/// rsx! {
+///     Select {
+///         multiple: true,
+///         searchable: true,
+///         values: voices(),
+///         on_change: move |v| voices.set(v),
+///         options: voice_options,
+///     }
+/// }
+/// ```
 #[component]
 pub fn Select(props: SelectProps) -> Element {
+    let mut query = use_signal(String::new);
     let has_error = props.error.is_some();
 
     let wrapper_class = format!(
-        "rust-ui-select-wrapper {} {}",
+        "rust-ui-select-wrapper {} {} {}",
         props.size.class(),
         if has_error { "has-error" } else { "" },
+        if props.multiple { "multiple" } else { "" },
     );
 
+    let visible = filter_options(&props.options, &query.read());
+
     rsx! {
         div { class: "{wrapper_class}",
             if let Some(label) = &props.label {
@@ -108,48 +168,91 @@ pub fn Select(props: SelectProps) -> Element {
                 }
             }
 
-            div { class: "select-container",
-                select {
-                    class: "rust-ui-select {props.class.as_deref().unwrap_or(\"\")}",
+            if props.searchable {
+                input {
+                    class: "select-filter",
+                    r#type: "search",
+                    placeholder: "Filter...",
+                    value: "{query}",
                     disabled: props.disabled,
-                    required: props.required,
-                    onchange: move |evt| {
-                        if let Some(handler) = &props.onchange {
-                            handler.call(evt.value());
-                        }
-                    },
-
-                    // Placeholder option
-                    if let Some(placeholder) = &props.placeholder {
-                        option {
-                            value: "",
-                            disabled: true,
-                            selected: props.value.is_empty(),
-                            "{placeholder}"
-                        }
-                    }
+                    oninput: move |evt| query.set(evt.value()),
+                }
+            }
+
+            if props.multiple {
+                div {
+                    class: "select-multi {props.class.as_deref().unwrap_or(\"\")}",
+                    role: "listbox",
+                    aria_multiselectable: "true",
 
-                    // Options
-                    for opt in &props.options {
-                        option {
-                            value: "{opt.value}",
-                            disabled: opt.disabled,
-                            selected: props.value == opt.value,
+                    for opt in visible {
+                        button {
+                            class: "select-option",
+                            class: if props.values.contains(&opt.value) { "selected" } else { "" },
+                            r#type: "button",
+                            role: "option",
+                            aria_selected: "{props.values.contains(&opt.value)}",
+                            disabled: props.disabled || opt.disabled,
+                            onclick: {
+                                let value = opt.value.clone();
+                                let values = props.values.clone();
+                                move |_| {
+                                    if let Some(handler) = &props.on_change {
+                                        handler.call(toggle_value(&values, &value));
+                                    }
+                                }
+                            },
                             "{opt.label}"
                         }
                     }
                 }
+            } else {
+                div { class: "select-container",
+                    select {
+                        class: "rust-ui-select {props.class.as_deref().unwrap_or(\"\")}",
+                        disabled: props.disabled,
+                        required: props.required,
+                        onchange: move |evt| {
+                            if let Some(handler) = &props.onchange {
+                                handler.call(evt.value());
+                            }
+                            if let Some(handler) = &props.on_change {
+                                handler.call(vec![evt.value()]);
+                            }
+                        },
+
+                        // Placeholder option
+                        if let Some(placeholder) = &props.placeholder {
+                            option {
+                                value: "",
+                                disabled: true,
+                                selected: props.value.is_empty(),
+                                "{placeholder}"
+                            }
+                        }
+
+                        // Options
+                        for opt in visible {
+                            option {
+                                value: "{opt.value}",
+                                disabled: opt.disabled,
+                                selected: props.value == opt.value,
+                                "{opt.label}"
+                            }
+                        }
+                    }
 
-                // Dropdown arrow
-                span { class: "select-arrow",
-                    svg {
-                        width: "16",
-                        height: "16",
-                        view_box: "0 0 24 24",
-                        fill: "none",
-                        stroke: "currentColor",
-                        stroke_width: "2",
-                        polyline { points: "6 9 12 15 18 9" }
+                    // Dropdown arrow
+                    span { class: "select-arrow",
+                        svg {
+                            width: "16",
+                            height: "16",
+                            view_box: "0 0 24 24",
+                            fill: "none",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            polyline { points: "6 9 12 15 18 9" }
+                        }
                     }
                 }
             }
@@ -160,3 +263,71 @@ pub fn Select(props: SelectProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    fn voices() -> Vec<SelectOption> {
+        vec![
+            SelectOption::new("en-US-AriaNeural", "Aria (US)"),
+            SelectOption::new("en-GB-SoniaNeural", "Sonia (UK)"),
+            SelectOption::new("en-GB-RyanNeural", "Ryan (UK)"),
+            SelectOption::new("de-DE-KatjaNeural", "Katja (DE)"),
+        ]
+    }
+
+    #[test]
+    fn filter_matches_label_or_value_substring() {
+        let options = voices();
+        let labels: Vec<_> = filter_options(&options, "uk").iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(labels, vec!["Sonia (UK)", "Ryan (UK)"]);
+
+        assert_eq!(filter_options(&options, "de-DE").len(), 1);
+        assert_eq!(filter_options(&options, "  ").len(), 4);
+        assert!(filter_options(&options, "xyz").is_empty());
+    }
+
+    #[test]
+    fn toggle_value_adds_and_removes() {
+        let selected = toggle_value(&[], "a");
+        let selected = toggle_value(&selected, "b");
+        assert_eq!(selected, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(toggle_value(&selected, "a"), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn multi_select_yields_both_values() {
+        fn app() -> Element {
+            let mut selected = use_signal(Vec::<String>::new);
+            rsx! {
+                Select {
+                    multiple: true,
+                    options: voices(),
+                    values: selected(),
+                    on_change: move |v| selected.set(v),
+                }
+                span { class: "selection", "{selected.read().join(\",\")}" }
+            }
+        }
+
+        let mut dom = TestDom::new(app);
+        dom.fire_nth("select-option", 0, "onclick", dioxus_html::SerializedMouseData::default());
+        dom.fire_nth("select-option", 2, "onclick", dioxus_html::SerializedMouseData::default());
+
+        assert_eq!(dom.text_of("selection"), "en-US-AriaNeural,en-GB-RyanNeural");
+        assert_eq!(dom.count_class("selected"), 2);
+    }
+
+    #[test]
+    fn filter_box_narrows_visible_options() {
+        let mut dom = TestDom::new(|| rsx! {
+            Select { searchable: true, options: voices() }
+        });
+        assert_eq!(dom.count_tag("option"), 4);
+
+        dom.input("select-filter", "uk");
+        assert_eq!(dom.count_tag("option"), 2);
+    }
+}
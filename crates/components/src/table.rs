@@ -1,17 +1,38 @@
 //! Table component
 
+use std::cmp::Ordering;
+
 use dioxus::prelude::*;
+use crate::icon::{Icon, IconName};
+use crate::Size;
 
 /// Table column definition
-#[derive(Clone, PartialEq)]
+#[derive(Clone)]
 pub struct Column<T: Clone + PartialEq + 'static> {
     pub key: String,
     pub header: String,
     pub render: fn(&T) -> Element,
     pub sortable: bool,
+    /// Extracts the value used for client-side sorting
+    pub sort_value: Option<fn(&T) -> SortValue>,
     pub width: Option<String>,
 }
 
+impl<T: Clone + PartialEq + 'static> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.header == other.header
+            && std::ptr::fn_addr_eq(self.render, other.render)
+            && self.sortable == other.sortable
+            && match (self.sort_value, other.sort_value) {
+                (Some(a), Some(b)) => std::ptr::fn_addr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.width == other.width
+    }
+}
+
 impl<T: Clone + PartialEq + 'static> Column<T> {
     pub fn new(key: impl Into<String>, header: impl Into<String>, render: fn(&T) -> Element) -> Self {
         Self {
@@ -19,15 +40,24 @@ impl<T: Clone + PartialEq + 'static> Column<T> {
             header: header.into(),
             render,
             sortable: false,
+            sort_value: None,
             width: None,
         }
     }
 
+    /// Make the header clickable; sorting is left to the `on_sort` handler
     pub fn sortable(mut self) -> Self {
         self.sortable = true;
         self
     }
 
+    /// Make the column sortable on the client using `value` as the sort key
+    pub fn sort_by(mut self, value: fn(&T) -> SortValue) -> Self {
+        self.sortable = true;
+        self.sort_value = Some(value);
+        self
+    }
+
     pub fn width(mut self, width: impl Into<String>) -> Self {
         self.width = Some(width.into());
         self
@@ -35,7 +65,7 @@ impl<T: Clone + PartialEq + 'static> Column<T> {
 }
 
 /// Sort direction
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum SortDirection {
     #[default]
     None,
@@ -43,6 +73,105 @@ pub enum SortDirection {
     Desc,
 }
 
+impl SortDirection {
+    /// Direction after clicking the header again: none → asc → desc → none
+    pub fn next(&self) -> Self {
+        match self {
+            SortDirection::None => SortDirection::Asc,
+            SortDirection::Asc => SortDirection::Desc,
+            SortDirection::Desc => SortDirection::None,
+        }
+    }
+}
+
+/// Current sort column and direction
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct SortState {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+impl SortState {
+    pub fn new(column: impl Into<String>, direction: SortDirection) -> Self {
+        Self { column: column.into(), direction }
+    }
+}
+
+/// A cell value used for sorting
+#[derive(Clone, PartialEq, Debug)]
+pub enum SortValue {
+    Text(String),
+    Number(f64),
+}
+
+impl SortValue {
+    /// Ascending comparison: numbers numerically, text case-insensitively,
+    /// numbers before text
+    pub fn compare(&self, other: &SortValue) -> Ordering {
+        match (self, other) {
+            (SortValue::Number(a), SortValue::Number(b)) => a.total_cmp(b),
+            (SortValue::Text(a), SortValue::Text(b)) => a
+                .to_lowercase()
+                .cmp(&b.to_lowercase())
+                .then_with(|| a.cmp(b)),
+            (SortValue::Number(_), SortValue::Text(_)) => Ordering::Less,
+            (SortValue::Text(_), SortValue::Number(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl From<&str> for SortValue {
+    fn from(value: &str) -> Self {
+        SortValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for SortValue {
+    fn from(value: String) -> Self {
+        SortValue::Text(value)
+    }
+}
+
+impl From<f64> for SortValue {
+    fn from(value: f64) -> Self {
+        SortValue::Number(value)
+    }
+}
+
+impl From<i64> for SortValue {
+    fn from(value: i64) -> Self {
+        SortValue::Number(value as f64)
+    }
+}
+
+/// Compare two rows by a sort key in the given direction
+pub fn compare_rows<T>(a: &T, b: &T, key: fn(&T) -> SortValue, direction: SortDirection) -> Ordering {
+    match direction {
+        SortDirection::None => Ordering::Equal,
+        SortDirection::Asc => key(a).compare(&key(b)),
+        SortDirection::Desc => key(b).compare(&key(a)),
+    }
+}
+
+/// Stable-sort rows by the column named in `sort`, if it has a sort key
+pub fn sort_rows<T: Clone + PartialEq + 'static>(rows: &mut [T], columns: &[Column<T>], sort: &SortState) {
+    let key = columns
+        .iter()
+        .find(|c| c.key == sort.column)
+        .and_then(|c| c.sort_value);
+    if let Some(key) = key {
+        rows.sort_by(|a, b| compare_rows(a, b, key, sort.direction));
+    }
+}
+
+/// Number of pages needed for `total` rows
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        return 1;
+    }
+    total.div_ceil(page_size).max(1)
+}
+
 /// Table props
 #[derive(Props, Clone, PartialEq)]
 pub struct TableProps<T: Clone + PartialEq + 'static> {
@@ -79,11 +208,45 @@ pub struct TableProps<T: Clone + PartialEq + 'static> {
     /// Empty message
     #[props(default = "No data")]
     pub empty_message: &'static str,
+
+    /// Current sort (controlled)
+    #[props(default)]
+    pub sort: Option<SortState>,
+
+    /// Called with the next sort state when a sortable header is clicked
+    #[props(default)]
+    pub on_sort: Option<EventHandler<SortState>>,
+
+    /// Current page (zero-based); used with `page_size`
+    #[props(default = 0)]
+    pub page: usize,
+
+    /// Rows per page; `None` shows every row
+    #[props(default)]
+    pub page_size: Option<usize>,
+
+    /// Page change handler
+    #[props(default)]
+    pub on_page_change: Option<EventHandler<usize>>,
 }
 
 /// Table component
+///
+/// Columns built with `Column::sort_by` are sorted on the client according
+/// to the controlled `sort` prop; header clicks report the next state via
+/// `on_sort`. Setting `page_size` paginates the (sorted) rows.
 #[component]
 pub fn Table<T: Clone + PartialEq + 'static>(props: TableProps<T>) -> Element {
+    let mut rows = props.data.clone();
+    if let Some(sort) = &props.sort {
+        sort_rows(&mut rows, &props.columns, sort);
+    }
+
+    let pages = props.page_size.map(|size| page_count(rows.len(), size));
+    if let Some(size) = props.page_size.filter(|size| *size > 0) {
+        rows = rows.into_iter().skip(props.page * size).take(size).collect();
+    }
+
     let class = format!(
         "rust-ui-table {} {} {} {}",
         if props.striped { "striped" } else { "" },
@@ -104,10 +267,51 @@ pub fn Table<T: Clone + PartialEq + 'static>(props: TableProps<T>) -> Element {
                 thead {
                     tr {
                         for col in props.columns.iter() {
-                            th {
-                                style: col.width.as_ref().map(|w| format!("width: {}", w)),
-                                class: if col.sortable { "sortable" } else { "" },
-                                "{col.header}"
+                            {
+                                let direction = props
+                                    .sort
+                                    .as_ref()
+                                    .filter(|s| s.column == col.key)
+                                    .map(|s| s.direction)
+                                    .unwrap_or_default();
+                                let aria_sort = match direction {
+                                    SortDirection::None => "none",
+                                    SortDirection::Asc => "ascending",
+                                    SortDirection::Desc => "descending",
+                                };
+                                rsx! {
+                                    th {
+                                        style: col.width.as_ref().map(|w| format!("width: {}", w)),
+                                        class: if col.sortable { "sortable" } else { "" },
+                                        aria_sort: if col.sortable { Some(aria_sort) } else { None },
+                                        onclick: {
+                                            let key = col.key.clone();
+                                            let sortable = col.sortable;
+                                            move |_| {
+                                                if !sortable {
+                                                    return;
+                                                }
+                                                if let Some(handler) = &props.on_sort {
+                                                    handler.call(SortState::new(key.clone(), direction.next()));
+                                                }
+                                            }
+                                        },
+                                        "{col.header}"
+                                        match direction {
+                                            SortDirection::Asc => rsx! {
+                                                span { class: "sort-indicator",
+                                                    Icon { name: IconName::ChevronUp, size: Size::Xs }
+                                                }
+                                            },
+                                            SortDirection::Desc => rsx! {
+                                                span { class: "sort-indicator",
+                                                    Icon { name: IconName::ChevronDown, size: Size::Xs }
+                                                }
+                                            },
+                                            SortDirection::None => rsx! {},
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -121,7 +325,7 @@ pub fn Table<T: Clone + PartialEq + 'static>(props: TableProps<T>) -> Element {
                             }
                         }
                     } else {
-                        for row in props.data.iter() {
+                        for row in rows.iter() {
                             tr {
                                 onclick: {
                                     let row = row.clone();
@@ -139,6 +343,115 @@ pub fn Table<T: Clone + PartialEq + 'static>(props: TableProps<T>) -> Element {
                     }
                 }
             }
+
+            if let Some(pages) = pages.filter(|p| *p > 1) {
+                div { class: "table-pagination",
+                    button {
+                        class: "pagination-prev",
+                        r#type: "button",
+                        disabled: props.page == 0,
+                        onclick: move |_| {
+                            if let Some(handler) = &props.on_page_change {
+                                handler.call(props.page.saturating_sub(1));
+                            }
+                        },
+                        Icon { name: IconName::ChevronLeft, size: Size::Sm }
+                    }
+                    span { class: "pagination-info", "Page {props.page + 1} of {pages}" }
+                    button {
+                        class: "pagination-next",
+                        r#type: "button",
+                        disabled: props.page + 1 >= pages,
+                        onclick: move |_| {
+                            if let Some(handler) = &props.on_page_change {
+                                handler.call((props.page + 1).min(pages - 1));
+                            }
+                        },
+                        Icon { name: IconName::ChevronRight, size: Size::Sm }
+                    }
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Voice {
+        name: &'static str,
+        rating: f64,
+    }
+
+    fn voices() -> Vec<Voice> {
+        vec![
+            Voice { name: "sonia", rating: 4.5 },
+            Voice { name: "Aria", rating: 10.0 },
+            Voice { name: "ryan", rating: 2.0 },
+        ]
+    }
+
+    fn by_name(v: &Voice) -> SortValue {
+        v.name.into()
+    }
+
+    fn by_rating(v: &Voice) -> SortValue {
+        v.rating.into()
+    }
+
+    fn names(mut rows: Vec<Voice>, key: fn(&Voice) -> SortValue, direction: SortDirection) -> Vec<&'static str> {
+        rows.sort_by(|a, b| compare_rows(a, b, key, direction));
+        rows.iter().map(|v| v.name).collect()
+    }
+
+    #[test]
+    fn text_columns_sort_case_insensitively() {
+        assert_eq!(names(voices(), by_name, SortDirection::Asc), vec!["Aria", "ryan", "sonia"]);
+        assert_eq!(names(voices(), by_name, SortDirection::Desc), vec!["sonia", "ryan", "Aria"]);
+    }
+
+    #[test]
+    fn numeric_columns_sort_numerically() {
+        // "10" would sort before "2" as text
+        assert_eq!(names(voices(), by_rating, SortDirection::Asc), vec!["ryan", "sonia", "Aria"]);
+        assert_eq!(names(voices(), by_rating, SortDirection::Desc), vec!["Aria", "sonia", "ryan"]);
+    }
+
+    #[test]
+    fn no_direction_keeps_original_order() {
+        assert_eq!(names(voices(), by_rating, SortDirection::None), vec!["sonia", "Aria", "ryan"]);
+    }
+
+    #[test]
+    fn sort_rows_uses_matching_column() {
+        let columns = vec![
+            Column::new("name", "Name", |v: &Voice| rsx! { "{v.name}" }).sort_by(by_name),
+            Column::new("rating", "Rating", |v: &Voice| rsx! { "{v.rating}" }),
+        ];
+        let mut rows = voices();
+        sort_rows(&mut rows, &columns, &SortState::new("name", SortDirection::Asc));
+        assert_eq!(rows[0].name, "Aria");
+
+        // Columns without a sort key leave the rows alone
+        let mut rows = voices();
+        sort_rows(&mut rows, &columns, &SortState::new("rating", SortDirection::Asc));
+        assert_eq!(rows, voices());
+    }
+
+    #[test]
+    fn direction_cycles() {
+        assert_eq!(SortDirection::None.next(), SortDirection::Asc);
+        assert_eq!(SortDirection::Asc.next(), SortDirection::Desc);
+        assert_eq!(SortDirection::Desc.next(), SortDirection::None);
+    }
+
+    #[test]
+    fn page_count_rounds_up() {
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(10, 10), 1);
+        assert_eq!(page_count(11, 10), 2);
+        assert_eq!(page_count(5, 0), 1);
+    }
+}
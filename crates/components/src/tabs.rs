@@ -41,14 +41,44 @@ pub enum TabVariant {
     Boxed,
 }
 
+/// Index of the tab a navigation key moves to, skipping disabled tabs.
+///
+/// Arrow keys wrap around at either end; Home/End jump to the first/last
+/// enabled tab. Returns `None` for keys that don't navigate.
+pub fn tab_index_for_key(tabs: &[Tab], current: usize, key: &Key) -> Option<usize> {
+    let enabled: Vec<usize> = (0..tabs.len()).filter(|&i| !tabs[i].disabled).collect();
+    if enabled.is_empty() {
+        return None;
+    }
+
+    let step = |forward: bool| {
+        let len = tabs.len();
+        let mut i = current.min(len - 1);
+        loop {
+            i = if forward { (i + 1) % len } else { (i + len - 1) % len };
+            if !tabs[i].disabled {
+                return i;
+            }
+        }
+    };
+
+    match key {
+        Key::ArrowRight | Key::ArrowDown => Some(step(true)),
+        Key::ArrowLeft | Key::ArrowUp => Some(step(false)),
+        Key::Home => enabled.first().copied(),
+        Key::End => enabled.last().copied(),
+        _ => None,
+    }
+}
+
 /// Tabs props
 #[derive(Props, Clone, PartialEq)]
 pub struct TabsProps {
     /// Tab definitions
     pub tabs: Vec<Tab>,
 
-    /// Active tab ID
-    pub active: String,
+    /// Selected tab ID (controlled)
+    pub selected: String,
 
     /// Tab content
     children: Element,
@@ -67,14 +97,35 @@ pub struct TabsProps {
 }
 
 /// Tabs component
+///
+/// Follows the WAI-ARIA tabs pattern: only the selected tab is in the tab
+/// order, arrow keys move between tabs (wrapping), and Home/End jump to the
+/// first/last tab. Selection is controlled through `selected` + `on_change`.
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     Tabs {
+///         tabs: vec![Tab::new("new", "New"), Tab::new("saved", "Saved")],
+///         selected: tab(),
+///         on_change: move |id| tab.set(id),
+///         TabPanel { id: "new", selected: tab(), "..." }
+///         TabPanel { id: "saved", selected: tab(), "..." }
+///     }
+/// }
+/// ```
 #[component]
 pub fn Tabs(props: TabsProps) -> Element {
+    let tabs_id = use_hook(|| format!("rust-ui-tabs-{}", uuid::Uuid::new_v4()));
+
     let variant_class = match props.variant {
         TabVariant::Line => "tabs-line",
         TabVariant::Pills => "tabs-pills",
         TabVariant::Boxed => "tabs-boxed",
     };
 
+    let selected_index = props.tabs.iter().position(|t| t.id == props.selected).unwrap_or(0);
+
     rsx! {
         div { class: "rust-ui-tabs",
             // Tab headers
@@ -82,13 +133,35 @@ pub fn Tabs(props: TabsProps) -> Element {
                 class: "tabs-list {variant_class}",
                 class: if props.full_width { "full-width" } else { "" },
                 role: "tablist",
+                onkeydown: {
+                    let tabs = props.tabs.clone();
+                    let tabs_id = tabs_id.clone();
+                    move |evt: KeyboardEvent| {
+                        let Some(next) = tab_index_for_key(&tabs, selected_index, &evt.key()) else {
+                            return;
+                        };
+                        evt.prevent_default();
+                        let id = tabs[next].id.clone();
+                        document::eval(&format!(
+                            "document.getElementById(\"{tabs_id}-tab-{id}\")?.focus();"
+                        ));
+                        if let Some(handler) = &props.on_change {
+                            handler.call(id);
+                        }
+                    }
+                },
 
                 for tab in props.tabs.iter() {
                     button {
+                        id: "{tabs_id}-tab-{tab.id}",
                         class: "tab-item",
-                        class: if tab.id == props.active { "active" } else { "" },
+                        class: if tab.id == props.selected { "active" } else { "" },
                         class: if tab.disabled { "disabled" } else { "" },
+                        r#type: "button",
                         role: "tab",
+                        aria_selected: "{tab.id == props.selected}",
+                        aria_controls: "{tabs_id}-panel",
+                        tabindex: if tab.id == props.selected { "0" } else { "-1" },
                         disabled: tab.disabled,
                         onclick: {
                             let id = tab.id.clone();
@@ -108,7 +181,12 @@ pub fn Tabs(props: TabsProps) -> Element {
             }
 
             // Tab content
-            div { class: "tabs-content", role: "tabpanel",
+            div {
+                id: "{tabs_id}-panel",
+                class: "tabs-content",
+                role: "tabpanel",
+                aria_labelledby: "{tabs_id}-tab-{props.selected}",
+                tabindex: "0",
                 {props.children}
             }
         }
@@ -119,13 +197,13 @@ pub fn Tabs(props: TabsProps) -> Element {
 #[derive(Props, Clone, PartialEq)]
 pub struct TabPanelProps {
     pub id: String,
-    pub active: String,
+    pub selected: String,
     children: Element,
 }
 
 #[component]
 pub fn TabPanel(props: TabPanelProps) -> Element {
-    if props.id != props.active {
+    if props.id != props.selected {
         return rsx! {};
     }
 
@@ -135,3 +213,78 @@ pub fn TabPanel(props: TabPanelProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+
+    fn tabs() -> Vec<Tab> {
+        vec![Tab::new("a", "A"), Tab::new("b", "B"), Tab::new("c", "C")]
+    }
+
+    #[test]
+    fn arrows_move_and_wrap() {
+        let tabs = tabs();
+        assert_eq!(tab_index_for_key(&tabs, 0, &Key::ArrowRight), Some(1));
+        assert_eq!(tab_index_for_key(&tabs, 2, &Key::ArrowRight), Some(0));
+        assert_eq!(tab_index_for_key(&tabs, 0, &Key::ArrowLeft), Some(2));
+        assert_eq!(tab_index_for_key(&tabs, 1, &Key::ArrowUp), Some(0));
+        assert_eq!(tab_index_for_key(&tabs, 1, &Key::ArrowDown), Some(2));
+    }
+
+    #[test]
+    fn home_and_end_jump() {
+        let tabs = tabs();
+        assert_eq!(tab_index_for_key(&tabs, 1, &Key::Home), Some(0));
+        assert_eq!(tab_index_for_key(&tabs, 1, &Key::End), Some(2));
+    }
+
+    #[test]
+    fn navigation_skips_disabled_tabs() {
+        let tabs = vec![Tab::new("a", "A"), Tab::new("b", "B").disabled(), Tab::new("c", "C").disabled()];
+        assert_eq!(tab_index_for_key(&tabs, 0, &Key::ArrowRight), Some(0));
+        assert_eq!(tab_index_for_key(&tabs, 0, &Key::End), Some(0));
+
+        let tabs = vec![Tab::new("a", "A").disabled(), Tab::new("b", "B"), Tab::new("c", "C")];
+        assert_eq!(tab_index_for_key(&tabs, 2, &Key::ArrowRight), Some(1));
+        assert_eq!(tab_index_for_key(&tabs, 2, &Key::Home), Some(1));
+    }
+
+    #[test]
+    fn other_keys_do_nothing() {
+        assert_eq!(tab_index_for_key(&tabs(), 0, &Key::Enter), None);
+        assert_eq!(tab_index_for_key(&[], 0, &Key::ArrowRight), None);
+    }
+
+    #[test]
+    fn emits_aria_roles_and_selection() {
+        let dom = TestDom::new(|| rsx! {
+            Tabs { tabs: tabs(), selected: "b", "content" }
+        });
+
+        let tab_attrs = dom.find_all(
+            |n| n.has_class("tab-item"),
+            |found| {
+                found
+                    .iter()
+                    .map(|n| {
+                        let attr = |name| n.attr(name).map(str::to_string);
+                        (attr("role"), attr("aria-selected"), attr("tabindex"))
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(
+            tab_attrs,
+            vec![
+                (some("tab"), some("false"), some("-1")),
+                (some("tab"), some("true"), some("0")),
+                (some("tab"), some("false"), some("-1")),
+            ]
+        );
+        assert_eq!(dom.attr_of("tabs-list", "role").as_deref(), Some("tablist"));
+        assert_eq!(dom.attr_of("tabs-content", "role").as_deref(), Some("tabpanel"));
+    }
+}
@@ -0,0 +1,292 @@
+//! Test helpers for rendering components without a browser or webview
+
+// Not every helper is used by every component's tests
+#![allow(dead_code)]
+
+use std::any::Any;
+use std::rc::Rc;
+
+use dioxus::dioxus_core::{
+    AttributeValue, DynamicNode, NoOpMutations, TemplateAttribute, TemplateNode, VNode,
+};
+use dioxus_html::{
+    HasImageData, ImageData, PlatformEventData, SerializedFormData, SerializedHtmlEventConverter,
+    SerializedImageData, SerializedMouseData,
+};
+use dioxus::prelude::*;
+
+type Listener = EventHandler<Event<dyn Any>>;
+
+/// A rendered element with its merged attributes and listeners
+pub(crate) struct TestNode {
+    pub tag: String,
+    pub attrs: Vec<(String, String)>,
+    listeners: Vec<(String, Listener)>,
+    pub children: Vec<TestChild>,
+}
+
+pub(crate) enum TestChild {
+    Element(TestNode),
+    Text(String),
+}
+
+impl TestNode {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attr("class")
+            .map(|c| c.split_whitespace().any(|c| c == class))
+            .unwrap_or(false)
+    }
+
+    /// Concatenated text content of this element and its descendants
+    pub fn text(&self) -> String {
+        let mut out = String::new();
+        for child in &self.children {
+            match child {
+                TestChild::Element(node) => out.push_str(&node.text()),
+                TestChild::Text(text) => out.push_str(text),
+            }
+        }
+        out
+    }
+
+    fn collect<'a>(&'a self, pred: &dyn Fn(&TestNode) -> bool, out: &mut Vec<&'a TestNode>) {
+        if pred(self) {
+            out.push(self);
+        }
+        for child in &self.children {
+            if let TestChild::Element(node) = child {
+                node.collect(pred, out);
+            }
+        }
+    }
+
+    fn write_html(&self, out: &mut String) {
+        out.push('<');
+        out.push_str(&self.tag);
+        for (name, value) in &self.attrs {
+            out.push_str(&format!(" {name}=\"{value}\""));
+        }
+        out.push('>');
+        for child in &self.children {
+            match child {
+                TestChild::Element(node) => node.write_html(out),
+                TestChild::Text(text) => out.push_str(text),
+            }
+        }
+        out.push_str(&format!("</{}>", self.tag));
+    }
+}
+
+/// A virtual dom that can be inspected and poked from unit tests
+pub(crate) struct TestDom {
+    dom: VirtualDom,
+}
+
+impl TestDom {
+    pub fn new(app: fn() -> Element) -> Self {
+        dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.render_immediate(&mut NoOpMutations);
+        Self { dom }
+    }
+
+    /// Root elements of the current render
+    pub fn roots(&self) -> Vec<TestNode> {
+        let mut out = Vec::new();
+        self.walk_vnode(self.dom.base_scope().root_node(), &mut out);
+        out.into_iter()
+            .filter_map(|child| match child {
+                TestChild::Element(node) => Some(node),
+                TestChild::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Render the current tree to an HTML-like string
+    pub fn html(&self) -> String {
+        let mut out = String::new();
+        for root in self.roots() {
+            root.write_html(&mut out);
+        }
+        out
+    }
+
+    /// Run `f` over every element matching `pred`
+    pub fn find_all<R>(&self, pred: impl Fn(&TestNode) -> bool, f: impl FnOnce(Vec<&TestNode>) -> R) -> R {
+        let roots = self.roots();
+        let mut found = Vec::new();
+        for root in &roots {
+            root.collect(&pred, &mut found);
+        }
+        f(found)
+    }
+
+    /// Number of elements carrying `class`
+    pub fn count_class(&self, class: &str) -> usize {
+        self.find_all(|n| n.has_class(class), |found| found.len())
+    }
+
+    /// Number of elements with the given tag
+    pub fn count_tag(&self, tag: &str) -> usize {
+        self.find_all(|n| n.tag == tag, |found| found.len())
+    }
+
+    /// Whether the first element with `class` also has `other`
+    pub fn class_has(&self, class: &str, other: &str) -> bool {
+        self.find_all(
+            |n| n.has_class(class),
+            |found| found.first().map(|n| n.has_class(other)).unwrap_or(false),
+        )
+    }
+
+    /// Attribute of the first element carrying `class`
+    pub fn attr_of(&self, class: &str, name: &str) -> Option<String> {
+        self.find_all(
+            |n| n.has_class(class),
+            |found| found.first().and_then(|n| n.attr(name).map(str::to_string)),
+        )
+    }
+
+    /// Text content of the first element carrying `class`
+    pub fn text_of(&self, class: &str) -> String {
+        self.find_all(
+            |n| n.has_class(class),
+            |found| found.first().map(|n| n.text()).unwrap_or_default(),
+        )
+    }
+
+    /// Dispatch `event` (e.g. `"onclick"`) with `data` to the nth element carrying `class`.
+    ///
+    /// Returns false when no such element or listener exists.
+    pub fn fire_nth(&mut self, class: &str, nth: usize, event: &str, data: impl Any) -> bool {
+        let listener = self.find_all(
+            |n| n.has_class(class),
+            |found| {
+                found.get(nth).and_then(|n| {
+                    n.listeners
+                        .iter()
+                        .find(|(name, _)| name == event)
+                        .map(|(_, cb)| *cb)
+                })
+            },
+        );
+        let Some(listener) = listener else {
+            return false;
+        };
+
+        let data = PlatformEventData::new(Box::new(data));
+        let event = Event::new(Rc::new(data) as Rc<dyn Any>, true);
+        self.dom.in_runtime(|| listener.call(event));
+        self.dom.render_immediate(&mut NoOpMutations);
+        true
+    }
+
+    pub fn fire(&mut self, class: &str, event: &str, data: impl Any) -> bool {
+        self.fire_nth(class, 0, event, data)
+    }
+
+    pub fn click(&mut self, class: &str) -> bool {
+        self.fire(class, "onclick", SerializedMouseData::default())
+    }
+
+    pub fn input(&mut self, class: &str, value: &str) -> bool {
+        let data = SerializedFormData::new(value.to_string(), Default::default());
+        self.fire(class, "oninput", data)
+    }
+
+    /// Fire `onerror` as if an image failed to load
+    pub fn image_error(&mut self, class: &str) -> bool {
+        struct LoadError;
+        impl HasImageData for LoadError {
+            fn load_error(&self) -> bool {
+                true
+            }
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+        }
+        let data = SerializedImageData::from(&ImageData::new(LoadError));
+        self.fire(class, "onerror", data)
+    }
+
+    fn walk_vnode(&self, vnode: &VNode, out: &mut Vec<TestChild>) {
+        for root in vnode.template.roots.iter() {
+            self.walk_template(vnode, root, out);
+        }
+    }
+
+    fn walk_template(&self, vnode: &VNode, node: &TemplateNode, out: &mut Vec<TestChild>) {
+        match node {
+            TemplateNode::Element { tag, attrs, children, .. } => {
+                let mut element = TestNode {
+                    tag: tag.to_string(),
+                    attrs: Vec::new(),
+                    listeners: Vec::new(),
+                    children: Vec::new(),
+                };
+                for attr in attrs.iter() {
+                    match attr {
+                        TemplateAttribute::Static { name, value, .. } => {
+                            push_attr(&mut element.attrs, name, value.to_string());
+                        }
+                        TemplateAttribute::Dynamic { id } => {
+                            for attr in vnode.dynamic_attrs[*id].iter() {
+                                match &attr.value {
+                                    AttributeValue::Text(v) => push_attr(&mut element.attrs, attr.name, v.clone()),
+                                    AttributeValue::Float(v) => push_attr(&mut element.attrs, attr.name, v.to_string()),
+                                    AttributeValue::Int(v) => push_attr(&mut element.attrs, attr.name, v.to_string()),
+                                    AttributeValue::Bool(true) => push_attr(&mut element.attrs, attr.name, "true".into()),
+                                    AttributeValue::Listener(cb) => {
+                                        element.listeners.push((attr.name.to_string(), *cb));
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                    }
+                }
+                for child in children.iter() {
+                    self.walk_template(vnode, child, &mut element.children);
+                }
+                out.push(TestChild::Element(element));
+            }
+            TemplateNode::Text { text } => out.push(TestChild::Text(text.to_string())),
+            TemplateNode::Dynamic { id } => match &vnode.dynamic_nodes[*id] {
+                DynamicNode::Text(text) => out.push(TestChild::Text(text.value.clone())),
+                DynamicNode::Fragment(nodes) => {
+                    for node in nodes {
+                        self.walk_vnode(node, out);
+                    }
+                }
+                DynamicNode::Component(component) => {
+                    if let Some(scope) = component.mounted_scope(*id, vnode, &self.dom) {
+                        self.walk_vnode(scope.root_node(), out);
+                    }
+                }
+                DynamicNode::Placeholder(_) => {}
+            },
+        }
+    }
+}
+
+/// Attributes with the same name (e.g. several `class:` entries) are joined like the renderers do
+fn push_attr(attrs: &mut Vec<(String, String)>, name: &str, value: String) {
+    if let Some((_, existing)) = attrs.iter_mut().find(|(n, _)| n == name) {
+        if !value.is_empty() {
+            if !existing.is_empty() {
+                existing.push(' ');
+            }
+            existing.push_str(&value);
+        }
+    } else {
+        attrs.push((name.to_string(), value));
+    }
+}
@@ -0,0 +1,209 @@
+//! Theme provider exposing design tokens as CSS custom properties
+
+use dioxus::prelude::*;
+
+/// Design tokens for the component library
+///
+/// Values are plain CSS values (colors, lengths) and are emitted as custom
+/// properties such as `--primary` and `--font-size-md`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Theme {
+    pub name: String,
+    pub primary: String,
+    pub secondary: String,
+    pub success: String,
+    pub warning: String,
+    pub danger: String,
+    pub neutral: String,
+    pub background: String,
+    pub surface: String,
+    pub text: String,
+    pub text_muted: String,
+    pub border: String,
+    pub radius: String,
+    pub font_size_xs: String,
+    pub font_size_sm: String,
+    pub font_size_md: String,
+    pub font_size_lg: String,
+    pub font_size_xl: String,
+}
+
+impl Theme {
+    /// Built-in light theme
+    pub fn light() -> Self {
+        Self {
+            name: "light".into(),
+            primary: "#6366f1".into(),
+            secondary: "#64748b".into(),
+            success: "#22c55e".into(),
+            warning: "#f59e0b".into(),
+            danger: "#ef4444".into(),
+            neutral: "#e2e8f0".into(),
+            background: "#ffffff".into(),
+            surface: "#f8fafc".into(),
+            text: "#0f172a".into(),
+            text_muted: "#64748b".into(),
+            border: "#e2e8f0".into(),
+            radius: "8px".into(),
+            font_size_xs: "0.75rem".into(),
+            font_size_sm: "0.875rem".into(),
+            font_size_md: "1rem".into(),
+            font_size_lg: "1.125rem".into(),
+            font_size_xl: "1.25rem".into(),
+        }
+    }
+
+    /// Built-in dark theme
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".into(),
+            primary: "#818cf8".into(),
+            secondary: "#94a3b8".into(),
+            success: "#4ade80".into(),
+            warning: "#fbbf24".into(),
+            danger: "#f87171".into(),
+            neutral: "#334155".into(),
+            background: "#0f172a".into(),
+            surface: "#1e293b".into(),
+            text: "#f1f5f9".into(),
+            text_muted: "#94a3b8".into(),
+            border: "#334155".into(),
+            ..Self::light()
+        }
+    }
+
+    /// Custom property declarations, e.g. `--primary: #6366f1; ...`
+    pub fn css_vars(&self) -> String {
+        [
+            ("--primary", &self.primary),
+            ("--secondary", &self.secondary),
+            ("--success", &self.success),
+            ("--warning", &self.warning),
+            ("--danger", &self.danger),
+            ("--neutral", &self.neutral),
+            ("--background", &self.background),
+            ("--surface", &self.surface),
+            ("--text", &self.text),
+            ("--text-muted", &self.text_muted),
+            ("--border", &self.border),
+            ("--radius", &self.radius),
+            ("--font-size-xs", &self.font_size_xs),
+            ("--font-size-sm", &self.font_size_sm),
+            ("--font-size-md", &self.font_size_md),
+            ("--font-size-lg", &self.font_size_lg),
+            ("--font-size-xl", &self.font_size_xl),
+        ]
+        .iter()
+        .map(|(name, value)| format!("{name}: {value};"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Theme provider props
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeProviderProps {
+    children: Element,
+
+    /// Active theme
+    #[props(default)]
+    pub theme: Theme,
+}
+
+/// Sets the theme's custom properties on a wrapper element and makes the
+/// theme available through [`use_theme`]
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     ThemeProvider { theme: if dark() { Theme::dark() } else { Theme::light() },
+///         App {}
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
+    let mut current = use_context_provider(|| Signal::new(props.theme.clone()));
+
+    let theme = props.theme.clone();
+    use_effect(use_reactive!(|(theme)| {
+        if *current.peek() != theme {
+            current.set(theme);
+        }
+    }));
+
+    rsx! {
+        div {
+            class: "rust-ui-theme theme-{props.theme.name}",
+            style: "{props.theme.css_vars()}",
+            {props.children}
+        }
+    }
+}
+
+/// The theme of the nearest [`ThemeProvider`], or the light theme if none
+pub fn use_theme() -> Theme {
+    match try_use_context::<Signal<Theme>>() {
+        Some(theme) => theme.read().clone(),
+        None => Theme::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+    use crate::{Variant, Size};
+
+    #[test]
+    fn css_vars_lists_tokens() {
+        let vars = Theme::light().css_vars();
+        assert!(vars.starts_with("--primary: #6366f1; --secondary: #64748b;"));
+        assert!(vars.contains("--danger: #ef4444;"));
+        assert!(vars.ends_with("--font-size-xl: 1.25rem;"));
+    }
+
+    #[test]
+    fn provider_renders_custom_properties() {
+        fn app() -> Element {
+            let theme = Theme { primary: "rebeccapurple".into(), ..Theme::dark() };
+            rsx! {
+                ThemeProvider { theme: theme, span { "child" } }
+            }
+        }
+
+        let dom = TestDom::new(app);
+        let style = dom.attr_of("rust-ui-theme", "style").unwrap();
+        assert!(style.contains("--primary: rebeccapurple;"));
+        assert!(style.contains("--background: #0f172a;"));
+        assert!(style.contains("--radius: 8px;"));
+        assert!(dom.class_has("rust-ui-theme", "theme-dark"));
+    }
+
+    #[test]
+    fn use_theme_reads_provider() {
+        #[component]
+        fn Reader() -> Element {
+            let theme = use_theme();
+            rsx! { span { class: "theme-name", "{theme.name}" } }
+        }
+
+        let dom = TestDom::new(|| rsx! {
+            ThemeProvider { theme: Theme::dark(), Reader {} }
+        });
+        assert_eq!(dom.text_of("theme-name"), "dark");
+    }
+
+    #[test]
+    fn tokens_map_variants_and_sizes() {
+        assert_eq!(Variant::Danger.token(), "--danger");
+        assert_eq!(Variant::Link.token(), "--primary");
+        assert_eq!(Size::Lg.token(), "--font-size-lg");
+    }
+}
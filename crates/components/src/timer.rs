@@ -0,0 +1,11 @@
+//! Platform timers for components that need delays
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep_ms(ms: u64) {
+    gloo_timers::future::TimeoutFuture::new(ms.min(u32::MAX as u64) as u32).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep_ms(ms: u64) {
+    tokio::time::sleep(std::time::Duration::from_millis(ms)).await;
+}
@@ -1,7 +1,10 @@
 //! Toast/notification component
 
+use std::collections::VecDeque;
+
 use dioxus::prelude::*;
 use crate::Variant;
+use crate::timer::sleep_ms;
 
 /// Toast position
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
@@ -161,3 +164,214 @@ pub fn Toast(props: ToastProps) -> Element {
         }
     }
 }
+
+/// Visible toasts plus an overflow queue
+///
+/// At most `max_visible` toasts are shown at once; extra toasts wait in
+/// FIFO order and are promoted as visible ones are dismissed or expire.
+#[derive(Clone, PartialEq)]
+pub struct ToastQueue {
+    max_visible: usize,
+    visible: Vec<ToastData>,
+    pending: VecDeque<ToastData>,
+}
+
+impl ToastQueue {
+    pub fn new(max_visible: usize) -> Self {
+        Self {
+            max_visible: max_visible.max(1),
+            visible: Vec::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Add a toast. Returns it back if it became visible immediately.
+    pub fn push(&mut self, toast: ToastData) -> Option<ToastData> {
+        if self.visible.len() < self.max_visible {
+            self.visible.push(toast.clone());
+            Some(toast)
+        } else {
+            self.pending.push_back(toast);
+            None
+        }
+    }
+
+    /// Remove a toast (visible or queued). Returns the toast promoted from
+    /// the queue to take its place, if any.
+    pub fn dismiss(&mut self, id: &str) -> Option<ToastData> {
+        if let Some(pos) = self.visible.iter().position(|t| t.id == id) {
+            self.visible.remove(pos);
+            let next = self.pending.pop_front()?;
+            self.visible.push(next.clone());
+            return Some(next);
+        }
+        self.pending.retain(|t| t.id != id);
+        None
+    }
+
+    pub fn clear(&mut self) {
+        self.visible.clear();
+        self.pending.clear();
+    }
+
+    pub fn visible(&self) -> &[ToastData] {
+        &self.visible
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// Handle for pushing toasts, obtained with [`use_toast`]
+#[derive(Clone, Copy)]
+pub struct ToastManager {
+    queue: Signal<ToastQueue>,
+    scope: ScopeId,
+}
+
+impl ToastManager {
+    /// Show a toast, or queue it if the maximum are already visible
+    pub fn push(&self, toast: ToastData) {
+        let mut queue = self.queue;
+        let shown = queue.write().push(toast);
+        self.schedule_expiry(shown);
+    }
+
+    /// Dismiss a toast by ID
+    pub fn dismiss(&self, id: &str) {
+        let mut queue = self.queue;
+        let promoted = queue.write().dismiss(id);
+        self.schedule_expiry(promoted);
+    }
+
+    /// Dismiss every toast, including queued ones
+    pub fn clear(&self) {
+        let mut queue = self.queue;
+        queue.write().clear();
+    }
+
+    /// Start the auto-dismiss timer once a toast is on screen.
+    ///
+    /// Timers run in the provider's scope so they outlive the component
+    /// that pushed the toast.
+    fn schedule_expiry(&self, toast: Option<ToastData>) {
+        let Some(toast) = toast else { return };
+        let Some(ms) = toast.duration_ms else { return };
+        let manager = *self;
+        self.scope.spawn(async move {
+            sleep_ms(ms).await;
+            manager.dismiss(&toast.id);
+        });
+    }
+}
+
+/// Access the nearest [`ToastProvider`]
+///
+/// # Example
+/// ```rust,ignore
+/// let toast = use_toast();
+/// rsx! {
+///     Button {
+///         onclick: move |_| toast.push(ToastData::success("Saved").duration(3000)),
+///         "Save"
+///     }
+/// }
+/// ```
+pub fn use_toast() -> ToastManager {
+    use_context::<ToastManager>()
+}
+
+/// Toast provider props
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastProviderProps {
+    children: Element,
+
+    /// Position of the toast stack
+    #[props(default)]
+    pub position: ToastPosition,
+
+    /// Maximum toasts on screen; extras wait in a queue
+    #[props(default = 3)]
+    pub max_visible: usize,
+}
+
+/// Provides [`use_toast`] to its children and renders the toast stack
+#[component]
+pub fn ToastProvider(props: ToastProviderProps) -> Element {
+    let queue = use_signal(|| ToastQueue::new(props.max_visible));
+    let manager = use_context_provider(|| ToastManager {
+        queue,
+        scope: current_scope_id().expect("ToastProvider rendered outside the runtime"),
+    });
+
+    rsx! {
+        {props.children}
+        ToastContainer {
+            toasts: queue.read().visible().to_vec(),
+            position: props.position,
+            on_dismiss: move |id: String| manager.dismiss(&id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toast(id: &str) -> ToastData {
+        ToastData { id: id.to_string(), ..ToastData::new(id) }
+    }
+
+    fn ids(toasts: &[ToastData]) -> Vec<&str> {
+        toasts.iter().map(|t| t.id.as_str()).collect()
+    }
+
+    #[test]
+    fn push_beyond_cap_queues_extra() {
+        let mut queue = ToastQueue::new(2);
+        assert!(queue.push(toast("a")).is_some());
+        assert!(queue.push(toast("b")).is_some());
+        assert!(queue.push(toast("c")).is_none());
+
+        assert_eq!(ids(queue.visible()), vec!["a", "b"]);
+        assert_eq!(queue.pending_len(), 1);
+    }
+
+    #[test]
+    fn expiry_advances_queue() {
+        let mut queue = ToastQueue::new(2);
+        for id in ["a", "b", "c", "d"] {
+            queue.push(toast(id));
+        }
+
+        let promoted = queue.dismiss("a");
+        assert_eq!(promoted.map(|t| t.id), Some("c".to_string()));
+        assert_eq!(ids(queue.visible()), vec!["b", "c"]);
+        assert_eq!(queue.pending_len(), 1);
+
+        queue.dismiss("b");
+        queue.dismiss("c");
+        assert_eq!(ids(queue.visible()), vec!["d"]);
+        assert_eq!(queue.pending_len(), 0);
+    }
+
+    #[test]
+    fn dismissing_queued_toast_does_not_promote() {
+        let mut queue = ToastQueue::new(1);
+        queue.push(toast("a"));
+        queue.push(toast("b"));
+
+        assert!(queue.dismiss("b").is_none());
+        assert_eq!(ids(queue.visible()), vec!["a"]);
+        assert_eq!(queue.pending_len(), 0);
+        assert!(queue.dismiss("a").is_none());
+        assert!(queue.visible().is_empty());
+    }
+
+    #[test]
+    fn zero_cap_still_shows_one() {
+        let mut queue = ToastQueue::new(0);
+        assert!(queue.push(toast("a")).is_some());
+    }
+}
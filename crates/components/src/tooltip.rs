@@ -1,10 +1,11 @@
 //! Tooltip component
 
 use dioxus::prelude::*;
+use crate::timer::sleep_ms;
 
-/// Tooltip position
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
-pub enum TooltipPosition {
+/// Tooltip placement relative to the trigger
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Placement {
     #[default]
     Top,
     Bottom,
@@ -12,17 +13,27 @@ pub enum TooltipPosition {
     Right,
 }
 
-impl TooltipPosition {
+impl Placement {
     pub fn class(&self) -> &'static str {
         match self {
-            TooltipPosition::Top => "tooltip-top",
-            TooltipPosition::Bottom => "tooltip-bottom",
-            TooltipPosition::Left => "tooltip-left",
-            TooltipPosition::Right => "tooltip-right",
+            Placement::Top => "tooltip-top",
+            Placement::Bottom => "tooltip-bottom",
+            Placement::Left => "tooltip-left",
+            Placement::Right => "tooltip-right",
         }
     }
 }
 
+/// Former name of [`Placement`]
+pub type TooltipPosition = Placement;
+
+/// Resolves once the page scrolls (capturing scrolls of any container)
+const WAIT_FOR_SCROLL: &str = r#"
+    await new Promise((resolve) =>
+        window.addEventListener("scroll", resolve, { once: true, capture: true, passive: true }));
+    dioxus.send(true);
+"#;
+
 /// Tooltip props
 #[derive(Props, Clone, PartialEq)]
 pub struct TooltipProps {
@@ -32,13 +43,13 @@ pub struct TooltipProps {
     /// Tooltip content
     pub content: String,
 
-    /// Position
+    /// Placement
     #[props(default)]
-    pub position: TooltipPosition,
+    pub placement: Placement,
 
-    /// Show delay (ms)
+    /// Show delay (ms); 0 shows immediately
     #[props(default = 200)]
-    pub delay: u32,
+    pub delay_ms: u32,
 
     /// Disabled
     #[props(default = false)]
@@ -46,25 +57,62 @@ pub struct TooltipProps {
 }
 
 /// Tooltip component
+///
+/// Shows after `delay_ms` of hovering and hides on mouse leave or when the
+/// page scrolls.
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     Tooltip { content: "Read aloud", placement: Placement::Bottom, delay_ms: 0,
+///         IconButton { icon: rsx! { Icon { name: IconName::Volume } } }
+///     }
+/// }
+/// ```
 #[component]
 pub fn Tooltip(props: TooltipProps) -> Element {
     let mut visible = use_signal(|| false);
+    let mut pending = use_signal(|| None::<Task>);
 
     if props.disabled {
         return rsx! { {props.children} };
     }
 
+    let delay_ms = props.delay_ms;
+
     rsx! {
         div {
             class: "rust-ui-tooltip-wrapper",
-            onmouseenter: move |_| visible.set(true),
-            onmouseleave: move |_| visible.set(false),
+            onmouseenter: move |_| {
+                if delay_ms == 0 {
+                    visible.set(true);
+                }
+                let task = spawn(async move {
+                    if delay_ms > 0 {
+                        sleep_ms(delay_ms as u64).await;
+                        visible.set(true);
+                    }
+                    let mut scrolled = document::eval(WAIT_FOR_SCROLL);
+                    if scrolled.recv::<bool>().await.is_ok() {
+                        visible.set(false);
+                    }
+                });
+                if let Some(old) = pending.replace(Some(task)) {
+                    old.cancel();
+                }
+            },
+            onmouseleave: move |_| {
+                if let Some(task) = pending.take() {
+                    task.cancel();
+                }
+                visible.set(false);
+            },
 
             {props.children}
 
             if *visible.read() {
                 div {
-                    class: "rust-ui-tooltip {props.position.class()}",
+                    class: "rust-ui-tooltip {props.placement.class()}",
                     role: "tooltip",
                     "{props.content}"
                 }
@@ -72,3 +120,54 @@ pub fn Tooltip(props: TooltipProps) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestDom;
+    use dioxus_html::SerializedMouseData;
+
+    const PLACEMENTS: [Placement; 4] = [Placement::Top, Placement::Bottom, Placement::Left, Placement::Right];
+
+    #[test]
+    fn each_placement_applies_its_class() {
+        fn app() -> Element {
+            rsx! {
+                for placement in PLACEMENTS {
+                    Tooltip { content: "Tip", placement: placement, delay_ms: 0,
+                        span { "trigger" }
+                    }
+                }
+            }
+        }
+
+        let mut dom = TestDom::new(app);
+        for i in 0..PLACEMENTS.len() {
+            dom.fire_nth("rust-ui-tooltip-wrapper", i, "onmouseenter", SerializedMouseData::default());
+        }
+
+        let classes = dom.find_all(
+            |n| n.has_class("rust-ui-tooltip"),
+            |found| found.iter().map(|n| n.attr("class").unwrap_or("").to_string()).collect::<Vec<_>>(),
+        );
+        assert_eq!(classes.len(), 4);
+        for (class, placement) in classes.iter().zip(PLACEMENTS) {
+            assert!(class.split_whitespace().any(|c| c == placement.class()), "{class}");
+        }
+    }
+
+    #[test]
+    fn zero_delay_shows_immediately_and_hides_on_leave() {
+        let mut dom = TestDom::new(|| rsx! {
+            Tooltip { content: "Tip", delay_ms: 0, span { "trigger" } }
+        });
+        assert_eq!(dom.count_class("rust-ui-tooltip"), 0);
+
+        dom.fire("rust-ui-tooltip-wrapper", "onmouseenter", SerializedMouseData::default());
+        assert_eq!(dom.count_class("rust-ui-tooltip"), 1);
+        assert!(dom.class_has("rust-ui-tooltip", "tooltip-top"));
+
+        dom.fire("rust-ui-tooltip-wrapper", "onmouseleave", SerializedMouseData::default());
+        assert_eq!(dom.count_class("rust-ui-tooltip"), 0);
+    }
+}
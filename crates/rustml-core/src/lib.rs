@@ -26,6 +26,8 @@
 pub mod error;
 pub mod shape;
 pub mod tensor;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub use error::{TensorError, TensorResult};
 pub use shape::Shape;
@@ -48,3 +50,28 @@ pub enum DType {
     I32,
     I64,
 }
+
+impl DType {
+    /// Check if this is an integer type
+    pub fn is_integer(&self) -> bool {
+        matches!(self, DType::I32 | DType::I64)
+    }
+
+    /// Size of one element in bytes
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            DType::F32 | DType::I32 => 4,
+            DType::F64 | DType::I64 => 8,
+        }
+    }
+}
+
+/// How `Tensor::cast` turns floats into integers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to the nearest integer, ties to even (`2.5 -> 2`, `-0.5 -> 0`)
+    #[default]
+    HalfEven,
+    /// Drop the fractional part (`1.9 -> 1`, `-1.9 -> -1`)
+    Truncate,
+}
@@ -111,6 +111,11 @@ impl Shape {
         dims.remove(idx);
         Some(Shape::new(dims))
     }
+
+    /// Remove every dimension of size 1
+    pub fn squeeze_all(&self) -> Shape {
+        Shape::new(self.dims.iter().copied().filter(|&d| d != 1).collect::<Vec<_>>())
+    }
 }
 
 impl fmt::Debug for Shape {
@@ -169,4 +174,11 @@ mod tests {
         let c = a.broadcast_with(&b);
         assert_eq!(c, Some(Shape::new(vec![2, 3, 4])));
     }
+
+    #[test]
+    fn test_squeeze_all() {
+        assert_eq!(Shape::new(vec![1, 3, 1]).squeeze_all(), Shape::new(vec![3]));
+        assert_eq!(Shape::new(vec![1, 1]).squeeze_all(), Shape::scalar());
+        assert_eq!(Shape::new(vec![2, 3]).squeeze_all(), Shape::new(vec![2, 3]));
+    }
 }
@@ -5,8 +5,9 @@
 
 use crate::error::{TensorError, TensorResult};
 use crate::shape::Shape;
-use crate::Device;
-use rand::Rng;
+use crate::{DType, Device, Rounding};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::fmt;
 use std::sync::Arc;
 
@@ -42,6 +43,61 @@ impl Tensor {
         })
     }
 
+    /// Create a 2D tensor from rows, e.g. `vec![vec![1.0, 2.0], vec![3.0, 4.0]]`
+    ///
+    /// The shape is `[rows.len(), row length]`; rows of different lengths
+    /// give a `ShapeMismatch` naming the expected and offending row shape.
+    pub fn from_nested_2d(rows: Vec<Vec<f32>>) -> TensorResult<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let shape = vec![rows.len(), cols];
+        Self::from_vec(Self::flatten_rows(rows, cols)?, shape)
+    }
+
+    /// Create a 3D tensor from matrices given as rows
+    ///
+    /// Every matrix must have the same number of rows and every row the
+    /// same length, otherwise this fails with a `ShapeMismatch`.
+    pub fn from_nested_3d(matrices: Vec<Vec<Vec<f32>>>) -> TensorResult<Self> {
+        let rows = matrices.first().map_or(0, Vec::len);
+        let cols = matrices
+            .first()
+            .and_then(|m| m.first())
+            .map_or(0, Vec::len);
+        let shape = vec![matrices.len(), rows, cols];
+
+        let mut data = Vec::with_capacity(shape.iter().product());
+        for matrix in matrices {
+            if matrix.len() != rows {
+                return Err(TensorError::ShapeMismatch {
+                    expected: vec![rows, cols],
+                    got: vec![matrix.len(), matrix.first().map_or(0, Vec::len)],
+                });
+            }
+            data.extend(Self::flatten_rows(matrix, cols)?);
+        }
+        Self::from_vec(data, shape)
+    }
+
+    /// Concatenate rows that must all be `cols` long
+    fn flatten_rows(rows: Vec<Vec<f32>>, cols: usize) -> TensorResult<Vec<f32>> {
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            if row.len() != cols {
+                return Err(TensorError::ShapeMismatch {
+                    expected: vec![cols],
+                    got: vec![row.len()],
+                });
+            }
+            data.extend(row);
+        }
+        Ok(data)
+    }
+
+    /// Create a tensor by copying a slice, e.g. an external sample buffer
+    pub fn from_slice(data: &[f32], shape: impl Into<Shape>) -> TensorResult<Self> {
+        Self::from_vec(data.to_vec(), shape)
+    }
+
     /// Create a tensor filled with zeros
     pub fn zeros(shape: impl Into<Shape>) -> Self {
         let shape = shape.into();
@@ -86,8 +142,12 @@ impl Tensor {
 
     /// Create a tensor with random values from standard normal distribution
     pub fn randn(shape: impl Into<Shape>) -> Self {
+        Self::randn_with_rng(shape, &mut rand::thread_rng())
+    }
+
+    /// Create a standard normal tensor drawing from `rng`
+    pub fn randn_with_rng<R: Rng + ?Sized>(shape: impl Into<Shape>, rng: &mut R) -> Self {
         let shape = shape.into();
-        let mut rng = rand::thread_rng();
         let data: Vec<f32> = (0..shape.numel())
             .map(|_| {
                 // Box-Muller transform for normal distribution
@@ -106,10 +166,19 @@ impl Tensor {
         }
     }
 
+    /// Create a standard normal tensor that is the same for the same seed
+    pub fn randn_seeded(shape: impl Into<Shape>, seed: u64) -> Self {
+        Self::randn_with_rng(shape, &mut StdRng::seed_from_u64(seed))
+    }
+
     /// Create a tensor with random uniform values in [0, 1)
     pub fn rand(shape: impl Into<Shape>) -> Self {
+        Self::rand_with_rng(shape, &mut rand::thread_rng())
+    }
+
+    /// Create a uniform [0, 1) tensor drawing from `rng`
+    pub fn rand_with_rng<R: Rng + ?Sized>(shape: impl Into<Shape>, rng: &mut R) -> Self {
         let shape = shape.into();
-        let mut rng = rand::thread_rng();
         let data: Vec<f32> = (0..shape.numel()).map(|_| rng.r#gen()).collect();
         let strides = Self::compute_strides(&shape);
         Self {
@@ -121,6 +190,11 @@ impl Tensor {
         }
     }
 
+    /// Create a uniform [0, 1) tensor that is the same for the same seed
+    pub fn rand_seeded(shape: impl Into<Shape>, seed: u64) -> Self {
+        Self::rand_with_rng(shape, &mut StdRng::seed_from_u64(seed))
+    }
+
     /// Create an identity matrix
     pub fn eye(n: usize) -> Self {
         let mut data = vec![0.0; n * n];
@@ -211,6 +285,14 @@ impl Tensor {
         self.shape.numel()
     }
 
+    /// Get the number of bytes the tensor's elements take up
+    ///
+    /// Elements are stored as f32. Views count only the elements they
+    /// cover, not the whole buffer they share.
+    pub fn byte_size(&self) -> usize {
+        self.numel() * DType::F32.size_in_bytes()
+    }
+
     /// Get the device
     pub fn device(&self) -> Device {
         self.device
@@ -227,6 +309,37 @@ impl Tensor {
         }
     }
 
+    /// Get the data as a mutable slice for in-place writes
+    ///
+    /// Only possible when the tensor is contiguous and no clone or view
+    /// shares its buffer; returns `None` otherwise.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [f32]> {
+        if !self.is_contiguous() {
+            return None;
+        }
+        let numel = self.numel();
+        Arc::get_mut(&mut self.data).map(|data| &mut data[..numel])
+    }
+
+    /// Set every element to `value` in place
+    ///
+    /// Writes into the existing buffer when this tensor owns it alone;
+    /// a shared buffer is copied first, so clones keep their values.
+    pub fn fill_(&mut self, value: f32) -> &mut Self {
+        if self.is_contiguous() {
+            let numel = self.numel();
+            Arc::make_mut(&mut self.data)[..numel].fill(value);
+        } else {
+            *self = Self::full(self.shape.clone(), value);
+        }
+        self
+    }
+
+    /// Set every element to zero in place (see [`Tensor::fill_`])
+    pub fn zero_(&mut self) -> &mut Self {
+        self.fill_(0.0)
+    }
+
     /// Convert to a Vec (always works, may copy)
     pub fn to_vec(&self) -> Vec<f32> {
         if self.is_contiguous() {
@@ -502,9 +615,55 @@ impl Tensor {
         Self::from_vec(new_data, broadcast_shape)
     }
 
+    /// Elements where `mask` is nonzero, as a 1D tensor in row-major order
+    ///
+    /// The mask and this tensor are broadcast together first.
+    pub fn masked_select(&self, mask: &Tensor) -> TensorResult<Self> {
+        let broadcast_shape = self
+            .shape
+            .broadcast_with(&mask.shape)
+            .ok_or_else(|| TensorError::BroadcastError {
+                shape1: self.shape.dims().to_vec(),
+                shape2: mask.shape.dims().to_vec(),
+            })?;
+
+        let selected: Vec<f32> = self
+            .expand(broadcast_shape.clone())?
+            .iter()
+            .zip(mask.expand(broadcast_shape)?.iter())
+            .filter(|&(_, m)| m != 0.0)
+            .map(|(v, _)| v)
+            .collect();
+
+        let len = selected.len();
+        Self::from_vec(selected, vec![len])
+    }
+
+    /// Coordinates of every nonzero element, in row-major order
+    pub fn nonzero(&self) -> Vec<Vec<usize>> {
+        let dims = self.shape.dims();
+        self.iter()
+            .enumerate()
+            .filter(|&(_, v)| v != 0.0)
+            .map(|(flat, _)| {
+                let mut index = vec![0; dims.len()];
+                let mut rest = flat;
+                for (i, &size) in dims.iter().enumerate().rev() {
+                    index[i] = rest % size;
+                    rest /= size;
+                }
+                index
+            })
+            .collect()
+    }
+
     // ==================== Shape Operations ====================
 
     /// Reshape the tensor
+    ///
+    /// Every dimension must be given; use [`Tensor::view`] or
+    /// [`Tensor::reshape_infer`] to infer one
+    /// from the element count with `-1`.
     pub fn reshape(&self, new_shape: impl Into<Shape>) -> TensorResult<Self> {
         let new_shape = new_shape.into();
         if new_shape.numel() != self.numel() {
@@ -524,6 +683,66 @@ impl Tensor {
         })
     }
 
+    /// Reshape with at most one dimension given as -1, inferred from the
+    /// number of elements
+    pub fn view(&self, dims: &[i64]) -> TensorResult<Self> {
+        let mismatch = || TensorError::InvalidOperation(format!(
+            "Cannot view tensor of shape {:?} as {:?}",
+            self.shape.dims(),
+            dims
+        ));
+
+        let mut inferred = None;
+        let mut known = 1usize;
+        for (i, &d) in dims.iter().enumerate() {
+            match d {
+                -1 if inferred.is_none() => inferred = Some(i),
+                d if d >= 0 => known *= d as usize,
+                _ => return Err(mismatch()),
+            }
+        }
+
+        let mut new_dims: Vec<usize> = dims.iter().map(|&d| d.max(0) as usize).collect();
+        if let Some(i) = inferred {
+            if known == 0 || !self.numel().is_multiple_of(known) {
+                return Err(mismatch());
+            }
+            new_dims[i] = self.numel() / known;
+        }
+        self.reshape(new_dims)
+    }
+
+    /// Reshape with one dimension inferred from `-1`; same as [`Tensor::view`]
+    pub fn reshape_infer(&self, dims: &[i64]) -> TensorResult<Self> {
+        self.view(dims)
+    }
+
+    /// Collapse dimensions `start_dim..=end_dim` into one
+    ///
+    /// Negative dims count from the end, so `flatten(1, -1)` keeps the first
+    /// dimension and flattens the rest.
+    pub fn flatten(&self, start_dim: i64, end_dim: i64) -> TensorResult<Self> {
+        let start = self.normalize_dim(start_dim)?;
+        let end = self.normalize_dim(end_dim)?;
+        if start > end {
+            return Err(TensorError::InvalidOperation(format!(
+                "flatten start_dim {} is after end_dim {}",
+                start_dim, end_dim
+            )));
+        }
+
+        let dims = self.shape.dims();
+        let mut new_dims = dims[..start].to_vec();
+        new_dims.push(dims[start..=end].iter().product());
+        new_dims.extend_from_slice(&dims[end + 1..]);
+        self.reshape(new_dims)
+    }
+
+    /// Flatten to 1D
+    pub fn flatten_all(&self) -> Self {
+        self.reshape(vec![self.numel()]).unwrap()
+    }
+
     /// Add a dimension of size 1 at the specified position
     pub fn unsqueeze(&self, dim: i64) -> TensorResult<Self> {
         let ndim = self.ndim() as i64 + 1;
@@ -554,6 +773,13 @@ impl Tensor {
         self.reshape(new_dims)
     }
 
+    /// Remove every dimension of size 1
+    ///
+    /// A tensor with a single element becomes a scalar.
+    pub fn squeeze_all(&self) -> Self {
+        self.reshape(self.shape.squeeze_all()).unwrap()
+    }
+
     /// Transpose two dimensions
     pub fn transpose(&self, dim0: i64, dim1: i64) -> TensorResult<Self> {
         let dim0_idx = self.normalize_dim(dim0)?;
@@ -624,6 +850,14 @@ impl Tensor {
         }
     }
 
+    /// Shape that tensors of shapes `a` and `b` broadcast to, or `None` if
+    /// they are incompatible
+    ///
+    /// Lets callers validate shapes before running an elementwise op.
+    pub fn broadcast_shapes(a: &[usize], b: &[usize]) -> Option<Shape> {
+        Shape::new(a).broadcast_with(&Shape::new(b))
+    }
+
     /// Broadcast to a new shape
     pub fn broadcast_to(&self, shape: &Shape) -> TensorResult<Self> {
         if self.shape.dims() == shape.dims() {
@@ -651,6 +885,44 @@ impl Tensor {
         Self::from_vec(new_data, shape.clone())
     }
 
+    /// Broadcast to a new shape without copying
+    ///
+    /// Size-1 dimensions and new leading dimensions get stride 0, so the
+    /// result shares this tensor's storage and repeats its values on read.
+    /// Every other dimension must already match. Use [`Tensor::contiguous`]
+    /// before writing to the result.
+    pub fn expand(&self, shape: impl Into<Shape>) -> TensorResult<Self> {
+        let shape = shape.into();
+        let broadcast_error = || TensorError::BroadcastError {
+            shape1: self.shape.dims().to_vec(),
+            shape2: shape.dims().to_vec(),
+        };
+
+        let target = shape.dims();
+        let lead = target
+            .len()
+            .checked_sub(self.ndim())
+            .ok_or_else(broadcast_error)?;
+
+        let mut strides = vec![0; target.len()];
+        for (j, (&size, &stride)) in self.shape.dims().iter().zip(&self.strides).enumerate() {
+            let new_size = target[lead + j];
+            if new_size == size {
+                strides[lead + j] = stride;
+            } else if size != 1 {
+                return Err(broadcast_error());
+            }
+        }
+
+        Ok(Self {
+            data: Arc::clone(&self.data),
+            shape,
+            strides,
+            offset: self.offset,
+            device: self.device,
+        })
+    }
+
     fn collect_broadcast(
         &self,
         result: &mut Vec<f32>,
@@ -680,6 +952,62 @@ impl Tensor {
         }
     }
 
+    /// Repeat the tensor along each dimension, materializing the copies
+    ///
+    /// Dimension `i` of the result is `shape[i] * repeats[i]` (PyTorch
+    /// `repeat` semantics), e.g. a `[1, d]` row repeated by `[n, 1]` gives
+    /// `[n, d]`.
+    pub fn repeat(&self, repeats: &[usize]) -> TensorResult<Self> {
+        if repeats.len() != self.ndim() {
+            return Err(TensorError::InvalidOperation(format!(
+                "Expected {} repeat counts, got {}",
+                self.ndim(),
+                repeats.len()
+            )));
+        }
+
+        let new_dims: Vec<usize> = self
+            .shape
+            .dims()
+            .iter()
+            .zip(repeats)
+            .map(|(&size, &count)| size * count)
+            .collect();
+        let new_shape = Shape::new(new_dims);
+
+        let mut new_data = Vec::with_capacity(new_shape.numel());
+        self.collect_repeat(&mut new_data, &new_shape, &[], 0);
+
+        Self::from_vec(new_data, new_shape)
+    }
+
+    fn collect_repeat(
+        &self,
+        result: &mut Vec<f32>,
+        target_shape: &Shape,
+        indices: &[usize],
+        depth: usize,
+    ) {
+        if depth == target_shape.ndim() {
+            // Each repetition maps back onto the source by wrapping around
+            let src_indices: Vec<usize> = indices
+                .iter()
+                .zip(self.shape.dims())
+                .map(|(&idx, &size)| idx % size)
+                .collect();
+            if let Ok(val) = self.get(&src_indices) {
+                result.push(val);
+            }
+            return;
+        }
+
+        for i in 0..target_shape.dims()[depth] {
+            let mut ni = indices.to_vec();
+            ni.push(i);
+            self.collect_repeat(result, target_shape, &ni, depth + 1);
+        }
+    }
+
     // ==================== Math Operations ====================
 
     /// Element-wise addition
@@ -793,6 +1121,18 @@ impl Tensor {
         exp_vals.div(&sum_broadcast.broadcast_to(&self.shape)?)
     }
 
+    /// Log-softmax along a dimension
+    ///
+    /// Computed as `x - max - log(sum(exp(x - max)))`, which stays finite
+    /// where `softmax(x).log()` would underflow to `-inf`.
+    pub fn log_softmax(&self, dim: i64) -> TensorResult<Self> {
+        let max_vals = self.max(dim)?.0;
+        let shifted = self.sub(&max_vals.unsqueeze(dim)?.broadcast_to(&self.shape)?)?;
+
+        let log_sum_exp = shifted.exp().sum(dim)?.log();
+        shifted.sub(&log_sum_exp.unsqueeze(dim)?.broadcast_to(&self.shape)?)
+    }
+
     // ==================== Reduction Operations ====================
 
     /// Sum all elements
@@ -806,13 +1146,17 @@ impl Tensor {
     }
 
     /// Sum along a dimension
+    ///
+    /// Summing over a dimension of size 0 gives zeros.
     pub fn sum(&self, dim: i64) -> TensorResult<Self> {
         self.reduce(dim, 0.0, |acc, x| acc + x)
     }
 
     /// Mean along a dimension
+    ///
+    /// Fails with `EmptyTensor` if the dimension has size 0.
     pub fn mean(&self, dim: i64) -> TensorResult<Self> {
-        let dim_idx = self.normalize_dim(dim)?;
+        let dim_idx = self.nonempty_dim(dim)?;
         let dim_size = self.shape.dims()[dim_idx] as f32;
         let sum = self.sum(dim)?;
         Ok(sum.div_scalar(dim_size))
@@ -828,8 +1172,10 @@ impl Tensor {
     }
 
     /// Max along a dimension
+    ///
+    /// Fails with `EmptyTensor` if the dimension has size 0.
     pub fn max(&self, dim: i64) -> TensorResult<(Self, Self)> {
-        let dim_idx = self.normalize_dim(dim)?;
+        let dim_idx = self.nonempty_dim(dim)?;
         let dim_size = self.shape.dims()[dim_idx];
 
         let mut new_dims = self.shape.dims().to_vec();
@@ -932,6 +1278,102 @@ impl Tensor {
         Ok((max_vals.neg(), indices))
     }
 
+    /// Argmin along a dimension
+    pub fn argmin(&self, dim: i64) -> TensorResult<Self> {
+        let (_, indices) = self.min(dim)?;
+        Ok(indices)
+    }
+
+    /// Sort along a dimension
+    ///
+    /// Returns the sorted values and, for each, its index along `dim` in the
+    /// original tensor. The sort is stable, so equal values keep their order.
+    pub fn sort(&self, dim: i64, descending: bool) -> TensorResult<(Self, Self)> {
+        let dim_idx = self.normalize_dim(dim)?;
+        let dim_size = self.shape.dims()[dim_idx];
+        let stride = Self::compute_strides(&self.shape)[dim_idx];
+
+        let data = self.to_vec();
+        let mut values = vec![0.0; data.len()];
+        let mut indices = vec![0.0; data.len()];
+
+        let lanes = data.len().checked_div(dim_size).unwrap_or(0);
+        for lane in 0..lanes {
+            // Flat index of the lane's first element
+            let base = (lane / stride) * dim_size * stride + lane % stride;
+            let mut order: Vec<usize> = (0..dim_size).collect();
+            order.sort_by(|&a, &b| {
+                let (a, b) = (data[base + a * stride], data[base + b * stride]);
+                if descending { b.total_cmp(&a) } else { a.total_cmp(&b) }
+            });
+            for (k, &i) in order.iter().enumerate() {
+                values[base + k * stride] = data[base + i * stride];
+                indices[base + k * stride] = i as f32;
+            }
+        }
+
+        Ok((
+            Self::from_vec(values, self.shape.clone())?,
+            Self::from_vec(indices, self.shape.clone())?,
+        ))
+    }
+
+    // ==================== Comparison ====================
+
+    /// Check that `other` has the same shape and every element satisfies
+    /// `|a - b| <= atol + rtol * |b|` (NumPy `allclose` semantics)
+    ///
+    /// NaN is never close to anything.
+    pub fn allclose(&self, other: &Tensor, rtol: f32, atol: f32) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| (a - b).abs() <= atol + rtol * b.abs())
+    }
+
+    /// Check that `other` has the same shape and every element differs by
+    /// at most `tol`
+    pub fn approx_eq(&self, other: &Tensor, tol: f32) -> bool {
+        self.allclose(other, 0.0, tol)
+    }
+
+    // ==================== Type Conversion ====================
+
+    /// Convert values to `dtype`, rounding half to even for integer types
+    ///
+    /// See [`cast_with`](Self::cast_with).
+    pub fn cast(&self, dtype: DType) -> TensorResult<Self> {
+        self.cast_with(dtype, Rounding::default())
+    }
+
+    /// Convert values to `dtype`
+    ///
+    /// Elements are still stored as `f32`, so casting to an integer type
+    /// rounds every value to a whole number with `rounding`, saturating at
+    /// the type's range. Fails on NaN or infinite values. Float types are
+    /// returned unchanged.
+    pub fn cast_with(&self, dtype: DType, rounding: Rounding) -> TensorResult<Self> {
+        if !dtype.is_integer() {
+            return Ok(self.clone());
+        }
+        if let Some(bad) = self.iter().find(|x| !x.is_finite()) {
+            return Err(TensorError::ConversionError(format!(
+                "Cannot cast {} to {:?}",
+                bad, dtype
+            )));
+        }
+
+        let round = |x: f32| match rounding {
+            Rounding::HalfEven => x.round_ties_even(),
+            Rounding::Truncate => x.trunc(),
+        };
+        Ok(match dtype {
+            DType::I32 => self.unary_op(|x| round(x) as i32 as f32),
+            _ => self.unary_op(|x| round(x) as i64 as f32),
+        })
+    }
+
     // ==================== Matrix Operations ====================
 
     /// Matrix multiplication
@@ -995,37 +1437,11 @@ impl Tensor {
                 for j in 0..n {
                     let mut sum = 0.0;
                     for l in 0..k {
-                        let mut self_idx = batch_indices
-                            .iter()
-                            .enumerate()
-                            .map(|(bi, &idx)| {
-                                if bi < self_batch.len() {
-                                    idx % self_shape[bi]
-                                } else {
-                                    0
-                                }
-                            })
-                            .collect::<Vec<_>>();
-                        while self_idx.len() < self_shape.len() - 2 {
-                            self_idx.push(0);
-                        }
+                        let mut self_idx = Self::broadcast_batch_index(&batch_indices, &self_batch);
                         self_idx.push(i);
                         self_idx.push(l);
 
-                        let mut other_idx = batch_indices
-                            .iter()
-                            .enumerate()
-                            .map(|(bi, &idx)| {
-                                if bi < other_batch.len() {
-                                    idx % other_shape[bi]
-                                } else {
-                                    0
-                                }
-                            })
-                            .collect::<Vec<_>>();
-                        while other_idx.len() < other_shape.len() - 2 {
-                            other_idx.push(0);
-                        }
+                        let mut other_idx = Self::broadcast_batch_index(&batch_indices, &other_batch);
                         other_idx.push(l);
                         other_idx.push(j);
 
@@ -1043,6 +1459,17 @@ impl Tensor {
 
     // ==================== Utility Methods ====================
 
+    /// Map an index into broadcast batch dimensions onto a tensor's own
+    /// batch dimensions (right-aligned; size-1 dimensions repeat)
+    fn broadcast_batch_index(batch_indices: &[usize], batch_dims: &[usize]) -> Vec<usize> {
+        let offset = batch_indices.len() - batch_dims.len();
+        batch_dims
+            .iter()
+            .zip(&batch_indices[offset..])
+            .map(|(&dim, &idx)| idx % dim)
+            .collect()
+    }
+
     fn compute_strides(shape: &Shape) -> Vec<usize> {
         let mut strides = Vec::with_capacity(shape.ndim());
         let mut stride = 1;
@@ -1061,6 +1488,15 @@ impl Tensor {
         })
     }
 
+    /// Normalize a dimension that is reduced over, which must not be empty
+    fn nonempty_dim(&self, dim: i64) -> TensorResult<usize> {
+        let dim_idx = self.normalize_dim(dim)?;
+        if self.shape.dims()[dim_idx] == 0 {
+            return Err(TensorError::EmptyTensor);
+        }
+        Ok(dim_idx)
+    }
+
     fn unary_op(&self, f: impl Fn(f32) -> f32) -> Self {
         let data: Vec<f32> = self.iter().map(f).collect();
         Self::from_vec(data, self.shape.clone()).unwrap()
@@ -1202,21 +1638,53 @@ impl fmt::Debug for Tensor {
 impl fmt::Display for Tensor {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.numel() <= 100 {
-            write!(f, "Tensor({}, {:?})", self.shape, self.to_vec())
+            write!(f, "Tensor({}, ", self.shape)?;
+            fmt_nested(f, &self.to_vec(), self.shape.dims())?;
+            write!(f, ")")
         } else {
+            let ([first, second], [second_last, last]) = self.preview_values();
             write!(
                 f,
                 "Tensor({}, [{:.4}, {:.4}, ..., {:.4}, {:.4}])",
-                self.shape,
-                self.get(&vec![0; self.ndim()]).unwrap_or(0.0),
-                self.iter().nth(1).unwrap_or(0.0),
-                self.iter().nth(self.numel() - 2).unwrap_or(0.0),
-                self.iter().last().unwrap_or(0.0),
+                self.shape, first, second, second_last, last,
             )
         }
     }
 }
 
+impl Tensor {
+    /// First two and last two elements (requires at least two elements)
+    fn preview_values(&self) -> ([f32; 2], [f32; 2]) {
+        if let Ok(data) = self.data() {
+            let n = data.len();
+            return ([data[0], data[1]], [data[n - 2], data[n - 1]]);
+        }
+
+        // Non-contiguous: a single pass over the elements
+        let mut iter = self.iter();
+        let head = [iter.next().unwrap_or(0.0), iter.next().unwrap_or(0.0)];
+        let tail = iter.fold(head, |[_, prev], x| [prev, x]);
+        (head, tail)
+    }
+}
+
+/// Write row-major `values` with one level of brackets per dimension
+fn fmt_nested(f: &mut fmt::Formatter<'_>, values: &[f32], dims: &[usize]) -> fmt::Result {
+    let Some((&len, inner)) = dims.split_first() else {
+        return write!(f, "{:?}", values.first().copied().unwrap_or(0.0));
+    };
+
+    let chunk = values.len().checked_div(len).unwrap_or(0);
+    write!(f, "[")?;
+    for i in 0..len {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt_nested(f, &values[i * chunk..(i + 1) * chunk], inner)?;
+    }
+    write!(f, "]")
+}
+
 // ==================== Tests ====================
 
 #[cfg(test)]
@@ -1230,6 +1698,115 @@ mod tests {
         assert_eq!(t.numel(), 4);
     }
 
+    #[test]
+    fn test_from_nested_infers_shape() {
+        let t = Tensor::from_nested_2d(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(t.shape(), &[2, 3]);
+        assert_eq!(t.to_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(t.get(&[1, 0]).unwrap(), 4.0);
+
+        let t = Tensor::from_nested_3d(vec![
+            vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]],
+            vec![vec![7.0, 8.0], vec![9.0, 10.0], vec![11.0, 12.0]],
+        ])
+        .unwrap();
+        assert_eq!(t.shape(), &[2, 3, 2]);
+        assert_eq!(t.get(&[1, 2, 0]).unwrap(), 11.0);
+
+        assert_eq!(Tensor::from_nested_2d(vec![]).unwrap().shape(), &[0, 0]);
+        assert_eq!(Tensor::from_nested_2d(vec![vec![], vec![]]).unwrap().shape(), &[2, 0]);
+    }
+
+    #[test]
+    fn test_from_nested_rejects_ragged_input() {
+        let ragged = Tensor::from_nested_2d(vec![vec![1.0, 2.0], vec![3.0]]);
+        assert_eq!(
+            ragged.unwrap_err(),
+            TensorError::ShapeMismatch { expected: vec![2], got: vec![1] }
+        );
+
+        let ragged_rows = Tensor::from_nested_3d(vec![
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+            vec![vec![5.0, 6.0]],
+        ]);
+        assert!(matches!(ragged_rows, Err(TensorError::ShapeMismatch { .. })));
+
+        let ragged_cols = Tensor::from_nested_3d(vec![
+            vec![vec![1.0, 2.0]],
+            vec![vec![3.0, 4.0, 5.0]],
+        ]);
+        assert!(matches!(ragged_cols, Err(TensorError::ShapeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_from_slice_copies() {
+        let samples = [0.5, -0.5, 0.25, 1.0];
+        let t = Tensor::from_slice(&samples, vec![2, 2]).unwrap();
+        assert_eq!(t.to_vec(), samples);
+        assert!(Tensor::from_slice(&samples, vec![3]).is_err());
+    }
+
+    #[test]
+    fn test_as_mut_slice_fills_in_place() {
+        let mut t = Tensor::zeros(vec![2, 2]);
+        t.as_mut_slice().unwrap().copy_from_slice(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(t.to_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_as_mut_slice_requires_unique_contiguous_data() {
+        let mut t = Tensor::zeros(vec![2, 3]);
+        let shared = t.clone();
+        assert!(t.as_mut_slice().is_none());
+
+        drop(shared);
+        assert!(t.as_mut_slice().is_some());
+
+        // A strided view can't be written as one slice even when unshared
+        let mut view = transposed_view(&t);
+        drop(t);
+        assert!(view.as_mut_slice().is_none());
+    }
+
+    #[test]
+    fn test_zero_keeps_shape() {
+        let mut t = Tensor::randn(vec![2, 3, 4]);
+        t.zero_();
+        assert_eq!(t.shape(), &[2, 3, 4]);
+        assert!(t.to_vec().iter().all(|&x| x == 0.0));
+
+        assert_eq!(t.fill_(1.5).to_vec(), vec![1.5; 24]);
+    }
+
+    #[test]
+    fn test_fill_copies_shared_data() {
+        let mut t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2]).unwrap();
+        let before = t.clone();
+
+        t.fill_(7.0);
+        assert_eq!(t.to_vec(), vec![7.0; 4]);
+        assert_eq!(before.to_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+
+        // A strided view gets a fresh buffer of its own
+        let mut view = transposed_view(&before);
+        view.zero_();
+        assert_eq!(view.shape(), &[2, 2]);
+        assert_eq!(view.to_vec(), vec![0.0; 4]);
+        assert_eq!(before.to_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_seeded_random_is_reproducible() {
+        let a = Tensor::randn_seeded(vec![4, 8], 42);
+        let b = Tensor::randn_seeded(vec![4, 8], 42);
+        assert_eq!(a.to_vec(), b.to_vec());
+        assert_ne!(a.to_vec(), Tensor::randn_seeded(vec![4, 8], 43).to_vec());
+
+        let u = Tensor::rand_seeded(vec![16], 7);
+        assert_eq!(u.to_vec(), Tensor::rand_seeded(vec![16], 7).to_vec());
+        assert!(u.iter().all(|x| (0.0..1.0).contains(&x)));
+    }
+
     #[test]
     fn test_zeros_ones() {
         let zeros = Tensor::zeros(vec![2, 3]);
@@ -1296,6 +1873,242 @@ mod tests {
         assert!((row1_sum - 1.0).abs() < 1e-5);
     }
 
+    #[test]
+    fn test_log_softmax() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let expected = t.softmax(-1).unwrap().log();
+        assert!(t.log_softmax(-1).unwrap().allclose(&expected, 1e-5, 1e-6));
+
+        // Large gaps don't underflow to -inf
+        let t = Tensor::from_vec(vec![0.0, 1000.0], vec![2]).unwrap();
+        let ls = t.log_softmax(-1).unwrap();
+        assert!((ls.get(&[0]).unwrap() + 1000.0).abs() < 1e-3);
+        assert!(ls.get(&[1]).unwrap().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_matmul_broadcasts_batch_dims() {
+        let a = Tensor::randn(vec![2, 3, 4]);
+        let b = Tensor::randn(vec![4, 5]);
+
+        let c = a.matmul(&b).unwrap();
+        assert_eq!(c.shape(), &[2, 3, 5]);
+        for batch in 0..2 {
+            let expected = a.select(0, batch).unwrap().matmul(&b).unwrap();
+            assert!(c.select(0, batch).unwrap().allclose(&expected, 1e-5, 1e-6));
+        }
+
+        // Batch dims are right-aligned and size-1 dims repeat
+        let a = Tensor::randn(vec![3, 1, 2, 4]);
+        let b = Tensor::randn(vec![2, 4, 5]);
+        let c = a.matmul(&b).unwrap();
+        assert_eq!(c.shape(), &[3, 2, 2, 5]);
+        let expected = a.select(0, 2).unwrap().select(0, 0).unwrap()
+            .matmul(&b.select(0, 1).unwrap())
+            .unwrap();
+        let actual = c.select(0, 2).unwrap().select(0, 1).unwrap();
+        assert!(actual.allclose(&expected, 1e-5, 1e-6));
+    }
+
+    #[test]
+    fn test_byte_size() {
+        assert_eq!(Tensor::zeros(vec![1000, 1000]).byte_size(), 4_000_000);
+        assert_eq!(Tensor::zeros(vec![0, 3]).byte_size(), 0);
+
+        // A view counts only its own elements
+        let t = Tensor::zeros(vec![4, 10]);
+        assert_eq!(t.slice(0, 1, 3).unwrap().byte_size(), 80);
+    }
+
+    #[test]
+    fn test_expand_shares_storage() {
+        let row = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        let expanded = row.expand(vec![4, 3]).unwrap();
+
+        assert_eq!(expanded.shape(), &[4, 3]);
+        assert!(Arc::ptr_eq(&row.data, &expanded.data));
+        assert!(!expanded.is_contiguous());
+        assert_eq!(expanded.to_vec(), [1.0, 2.0, 3.0].repeat(4));
+        assert_eq!(expanded.to_vec(), row.broadcast_to(&Shape::new(vec![4, 3])).unwrap().to_vec());
+    }
+
+    #[test]
+    fn test_expand_adds_leading_dims() {
+        let column = Tensor::from_vec(vec![1.0, 2.0], vec![2, 1]).unwrap();
+        let expanded = column.expand(vec![2, 2, 3]).unwrap();
+
+        assert_eq!(expanded.shape(), &[2, 2, 3]);
+        assert_eq!(expanded.iter().collect::<Vec<_>>(), [[1.0; 3], [2.0; 3]].concat().repeat(2));
+        assert_eq!(expanded.get(&[1, 1, 2]).unwrap(), 2.0);
+
+        // Reads go through strides, so ops see the broadcast values
+        assert_eq!(expanded.sum_all(), 18.0);
+        assert_eq!(expanded.contiguous().to_vec(), expanded.to_vec());
+    }
+
+    #[test]
+    fn test_expand_rejects_non_singleton_dims() {
+        let t = Tensor::zeros(vec![2, 3]);
+        assert!(matches!(t.expand(vec![4, 3]), Err(TensorError::BroadcastError { .. })));
+        assert!(matches!(t.expand(vec![3]), Err(TensorError::BroadcastError { .. })));
+        assert_eq!(t.expand(vec![5, 2, 3]).unwrap().shape(), &[5, 2, 3]);
+    }
+
+    #[test]
+    fn test_masked_select() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], vec![4]).unwrap();
+        let mask = Tensor::from_vec(vec![1.0, 0.0, 1.0, 0.0], vec![4]).unwrap();
+        assert_eq!(t.masked_select(&mask).unwrap().to_vec(), vec![1.0, 3.0]);
+
+        // The mask broadcasts across rows
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let columns = Tensor::from_vec(vec![0.0, 1.0, 1.0], vec![3]).unwrap();
+        let selected = t.masked_select(&columns).unwrap();
+        assert_eq!(selected.shape(), &[4]);
+        assert_eq!(selected.to_vec(), vec![2.0, 3.0, 5.0, 6.0]);
+
+        assert_eq!(t.masked_select(&Tensor::zeros(vec![2, 3])).unwrap().shape(), &[0]);
+        assert!(t.masked_select(&Tensor::ones(vec![2])).is_err());
+    }
+
+    #[test]
+    fn test_nonzero() {
+        let t = Tensor::from_vec(vec![0.0, 5.0, 0.0, -1.0, 0.0, 2.0], vec![2, 3]).unwrap();
+        assert_eq!(t.nonzero(), vec![vec![0, 1], vec![1, 0], vec![1, 2]]);
+
+        assert!(Tensor::zeros(vec![3, 2]).nonzero().is_empty());
+        assert_eq!(Tensor::ones(vec![2]).nonzero(), vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn test_cast_rounds_half_to_even() {
+        let t = Tensor::from_vec(vec![1.9, 2.0, -0.5, 2.5, 3.5], vec![5]).unwrap();
+        assert_eq!(t.cast(DType::I64).unwrap().to_vec(), vec![2.0, 2.0, 0.0, 2.0, 4.0]);
+        assert_eq!(t.cast(DType::I32).unwrap().to_vec(), vec![2.0, 2.0, 0.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_cast_truncates() {
+        let t = Tensor::from_vec(vec![1.9, 2.0, -0.5, -1.9], vec![4]).unwrap();
+        let cast = t.cast_with(DType::I32, Rounding::Truncate).unwrap();
+        assert_eq!(cast.to_vec(), vec![1.0, 2.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn test_cast_edge_cases() {
+        // Saturates at the integer range
+        let t = Tensor::from_vec(vec![1e10, -1e10], vec![2]).unwrap();
+        assert_eq!(t.cast(DType::I32).unwrap().to_vec(), vec![i32::MAX as f32, i32::MIN as f32]);
+
+        // Float casts leave values untouched
+        let t = Tensor::from_vec(vec![1.9, -0.5], vec![2]).unwrap();
+        assert_eq!(t.cast(DType::F32).unwrap().to_vec(), vec![1.9, -0.5]);
+
+        let t = Tensor::from_vec(vec![1.0, f32::NAN], vec![2]).unwrap();
+        assert!(matches!(t.cast(DType::I64), Err(TensorError::ConversionError(_))));
+    }
+
+    #[test]
+    fn test_cast_keeps_large_token_ids_exact() {
+        let ids = Tensor::from_vec(vec![50256.0, 50255.9999, 12345.0001], vec![3]).unwrap();
+        assert_eq!(ids.cast(DType::I64).unwrap().to_vec(), vec![50256.0, 50256.0, 12345.0]);
+    }
+
+    #[test]
+    fn test_display_nests_rows() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        assert_eq!(t.to_string(), "Tensor([2, 3], [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])");
+
+        let t = Tensor::from_vec(vec![1.5, -2.0], vec![2]).unwrap();
+        assert_eq!(t.to_string(), "Tensor([2], [1.5, -2.0])");
+        assert_eq!(Tensor::zeros(vec![2, 0]).to_string(), "Tensor([2, 0], [[], []])");
+    }
+
+    /// A transposed view of `t` sharing its storage (strides swapped)
+    fn transposed_view(t: &Tensor) -> Tensor {
+        let dims = t.shape().to_vec();
+        Tensor {
+            data: t.data.clone(),
+            shape: Shape::new(vec![dims[1], dims[0]]),
+            strides: vec![t.strides[1], t.strides[0]],
+            offset: 0,
+            device: t.device,
+        }
+    }
+
+    #[test]
+    fn test_display_non_contiguous() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let view = transposed_view(&t);
+        assert!(!view.is_contiguous());
+        assert_eq!(view.to_string(), "Tensor([3, 2], [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]])");
+
+        // Large tensors show the first and last two elements in logical order
+        let t = Tensor::from_vec((0..200).map(|x| x as f32).collect(), vec![20, 10]).unwrap();
+        assert_eq!(
+            t.to_string(),
+            "Tensor([20, 10], [0.0000, 1.0000, ..., 198.0000, 199.0000])"
+        );
+        assert_eq!(
+            transposed_view(&t).to_string(),
+            "Tensor([10, 20], [0.0000, 10.0000, ..., 189.0000, 199.0000])"
+        );
+    }
+
+    #[test]
+    fn test_broadcast_shapes() {
+        assert_eq!(Tensor::broadcast_shapes(&[3, 1], &[1, 4]), Some(Shape::new(vec![3, 4])));
+        assert_eq!(Tensor::broadcast_shapes(&[2, 3, 4], &[4]), Some(Shape::new(vec![2, 3, 4])));
+        assert_eq!(Tensor::broadcast_shapes(&[3, 2], &[3]), None);
+
+        // Agrees with what elementwise ops actually do
+        let a = Tensor::randn(vec![3, 1]);
+        let b = Tensor::randn(vec![1, 4]);
+        let expected = Tensor::broadcast_shapes(a.shape(), b.shape()).unwrap();
+        assert_eq!(a.add(&b).unwrap().shape(), expected.dims());
+    }
+
+    #[test]
+    fn test_squeeze_all() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3, 1]).unwrap();
+        let squeezed = t.squeeze_all();
+        assert_eq!(squeezed.shape(), &[3]);
+        assert_eq!(squeezed.to_vec(), vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(Tensor::ones(vec![1, 1]).squeeze_all().ndim(), 0);
+    }
+
+    #[test]
+    fn test_scalar_ops_reject_dimensions() {
+        let scalar = Tensor::from_vec(vec![2.0], Shape::scalar()).unwrap();
+        let invalid = |r: TensorResult<Tensor>| matches!(r, Err(TensorError::InvalidDimension { .. }));
+
+        assert!(invalid(scalar.softmax(-1)));
+        assert!(invalid(scalar.log_softmax(0)));
+        assert!(invalid(scalar.sum(0)));
+        assert!(invalid(scalar.mean(0)));
+        assert!(invalid(scalar.select(0, 0)));
+        assert!(matches!(scalar.max(0), Err(TensorError::InvalidDimension { .. })));
+    }
+
+    #[test]
+    fn test_reducing_empty_dimension() {
+        let empty = Tensor::zeros(vec![0]);
+        assert_eq!(empty.max(0).unwrap_err(), TensorError::EmptyTensor);
+        assert_eq!(empty.argmin(0).unwrap_err(), TensorError::EmptyTensor);
+        assert_eq!(empty.mean(0).unwrap_err(), TensorError::EmptyTensor);
+        assert_eq!(empty.var(0).unwrap_err(), TensorError::EmptyTensor);
+        assert!(matches!(empty.select(0, 0), Err(TensorError::IndexOutOfBounds { .. })));
+
+        let rows = Tensor::zeros(vec![2, 0]);
+        assert_eq!(rows.max(-1).unwrap_err(), TensorError::EmptyTensor);
+        assert_eq!(rows.softmax(-1).unwrap_err(), TensorError::EmptyTensor);
+        // The empty sum is zero
+        assert_eq!(rows.sum(-1).unwrap().to_vec(), vec![0.0, 0.0]);
+        // Reducing a non-empty dimension of an empty tensor is fine
+        assert_eq!(rows.max(0).unwrap().0.shape(), &[0]);
+    }
+
     #[test]
     fn test_gelu() {
         let t = Tensor::from_vec(vec![-1.0, 0.0, 1.0], vec![3]).unwrap();
@@ -1312,4 +2125,149 @@ mod tests {
         assert_eq!(idx.get(&[0]).unwrap(), 1.0); // max at index 1 (value 3)
         assert_eq!(idx.get(&[1]).unwrap(), 2.0); // max at index 2 (value 6)
     }
+
+    #[test]
+    fn test_argmin() {
+        let t = Tensor::from_vec(vec![3.0, 1.0, 2.0], vec![3]).unwrap();
+        assert_eq!(t.argmin(0).unwrap().to_vec(), vec![1.0]);
+
+        let t = Tensor::from_vec(vec![1.0, 3.0, 2.0, 5.0, 4.0, 6.0], vec![2, 3]).unwrap();
+        assert_eq!(t.argmin(-1).unwrap().to_vec(), vec![0.0, 1.0]);
+        assert_eq!(t.argmin(0).unwrap().to_vec(), vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_sort_descending() {
+        let t = Tensor::from_vec(vec![1.0, 3.0, 2.0, 5.0, 4.0, 6.0], vec![2, 3]).unwrap();
+        let (values, indices) = t.sort(-1, true).unwrap();
+        assert_eq!(values.shape(), &[2, 3]);
+        assert_eq!(values.to_vec(), vec![3.0, 2.0, 1.0, 6.0, 5.0, 4.0]);
+        assert_eq!(indices.to_vec(), vec![1.0, 2.0, 0.0, 2.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_sort_along_first_dim() {
+        let t = Tensor::from_vec(vec![4.0, 1.0, 2.0, 3.0, 0.0, 5.0], vec![3, 2]).unwrap();
+        let (values, indices) = t.sort(0, false).unwrap();
+        assert_eq!(values.to_vec(), vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(indices.to_vec(), vec![2.0, 0.0, 1.0, 1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_sort_is_stable() {
+        let t = Tensor::from_vec(vec![2.0, 1.0, 2.0, 1.0], vec![4]).unwrap();
+        let (_, ascending) = t.sort(0, false).unwrap();
+        assert_eq!(ascending.to_vec(), vec![1.0, 3.0, 0.0, 2.0]);
+        let (_, descending) = t.sort(0, true).unwrap();
+        assert_eq!(descending.to_vec(), vec![0.0, 2.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_repeat_rows() {
+        let row = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        let r = row.repeat(&[2, 1]).unwrap();
+        assert_eq!(r.shape(), &[2, 3]);
+        assert_eq!(r.to_vec(), vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_repeat_1d() {
+        let t = Tensor::from_vec(vec![1.0, 2.0], vec![2]).unwrap();
+        let r = t.repeat(&[3]).unwrap();
+        assert_eq!(r.shape(), &[6]);
+        assert_eq!(r.to_vec(), vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_repeat_tiles_both_dims() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2]).unwrap();
+        let r = t.repeat(&[2, 2]).unwrap();
+        assert_eq!(r.shape(), &[4, 4]);
+        assert_eq!(r.select(0, 0).unwrap().to_vec(), vec![1.0, 2.0, 1.0, 2.0]);
+        assert_eq!(r.select(0, 3).unwrap().to_vec(), vec![3.0, 4.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_repeat_requires_count_per_dim() {
+        let t = Tensor::zeros(vec![2, 3]);
+        assert!(t.repeat(&[2]).is_err());
+        assert!(t.repeat(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let t = Tensor::arange(0.0, 24.0, 1.0).unwrap().reshape(vec![2, 3, 4]).unwrap();
+
+        let f = t.flatten(1, 2).unwrap();
+        assert_eq!(f.shape(), &[2, 12]);
+        assert_eq!(f.to_vec(), t.to_vec());
+
+        assert_eq!(t.flatten(0, 1).unwrap().shape(), &[6, 4]);
+        assert_eq!(t.flatten(1, -1).unwrap().shape(), &[2, 12]);
+        assert_eq!(t.flatten(-1, -1).unwrap().shape(), &[2, 3, 4]);
+        assert!(t.flatten(2, 1).is_err());
+        assert!(t.flatten(0, 3).is_err());
+    }
+
+    #[test]
+    fn test_flatten_all() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let f = t.flatten_all();
+        assert_eq!(f.shape(), &[6]);
+        assert_eq!(f.to_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_view_infers_dim() {
+        let t = Tensor::zeros(vec![2, 3, 4]);
+        assert_eq!(t.view(&[-1, 4]).unwrap().shape(), &[6, 4]);
+        assert_eq!(t.view(&[2, -1]).unwrap().shape(), &[2, 12]);
+        assert_eq!(t.view(&[24]).unwrap().shape(), &[24]);
+
+        assert!(t.view(&[-1, -1]).is_err());
+        assert!(t.view(&[5, -1]).is_err());
+        assert!(t.view(&[-2, 12]).is_err());
+        assert!(t.view(&[5, 5]).is_err());
+
+        assert_eq!(t.reshape_infer(&[-1, 4]).unwrap().shape(), &[6, 4]);
+        assert_eq!(t.reshape_infer(&[2, -1]).unwrap().shape(), &[2, 12]);
+        assert!(t.reshape_infer(&[5, -1]).is_err());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let tol = 1e-4;
+        let a = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2]).unwrap();
+        assert!(a.approx_eq(&a.clone(), tol));
+
+        let b = a.add_scalar(tol / 2.0);
+        assert!(a.approx_eq(&b, tol));
+        assert!(!a.approx_eq(&a.add_scalar(tol * 2.0), tol));
+
+        // Same elements, different shape
+        let flat = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], vec![4]).unwrap();
+        assert!(!a.approx_eq(&flat, tol));
+    }
+
+    #[test]
+    fn test_allclose() {
+        let a = Tensor::from_vec(vec![100.0, 1.0], vec![2]).unwrap();
+        let b = Tensor::from_vec(vec![100.05, 1.0], vec![2]).unwrap();
+        // 0.05 is within 1e-3 relative to 100, but not within 1e-3 absolute
+        assert!(a.allclose(&b, 1e-3, 0.0));
+        assert!(!a.allclose(&b, 0.0, 1e-3));
+
+        let nan = Tensor::from_vec(vec![f32::NAN, 1.0], vec![2]).unwrap();
+        assert!(!nan.allclose(&nan, 1e-3, 1e-3));
+    }
+
+    #[test]
+    fn test_approx_eq_transposed() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let transposed = t.t().unwrap();
+
+        let expected = Tensor::from_vec(vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0], vec![3, 2]).unwrap();
+        assert!(transposed.approx_eq(&expected, 1e-6));
+        assert!(expected.approx_eq(&transposed, 1e-6));
+    }
 }
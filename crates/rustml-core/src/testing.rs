@@ -0,0 +1,69 @@
+//! Numerical differentiation for checking layer math in tests
+//!
+//! Compiled for this crate's tests and, for other crates, with the
+//! `testing` feature (e.g. as a dev-dependency feature).
+
+use crate::Tensor;
+
+/// Gradient of a scalar function at `x` by central differences
+///
+/// Each element is perturbed by `±eps` in turn, so `f` is evaluated
+/// `2 * x.numel()` times. With `f32` values, `eps` around `1e-2` to `1e-3`
+/// balances truncation and rounding error.
+pub fn numerical_gradient(f: impl Fn(&Tensor) -> f32, x: &Tensor, eps: f32) -> Tensor {
+    let shape = x.shape().to_vec();
+    let values = x.to_vec();
+    let eval_with = |i: usize, delta: f32| {
+        let mut shifted = values.clone();
+        shifted[i] += delta;
+        f64::from(f(&Tensor::from_vec(shifted, shape.clone()).unwrap()))
+    };
+
+    let grad = (0..values.len())
+        .map(|i| ((eval_with(i, eps) - eval_with(i, -eps)) / (2.0 * f64::from(eps))) as f32)
+        .collect();
+    Tensor::from_vec(grad, shape).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient_of_sum_of_squares() {
+        let x = Tensor::from_vec(vec![1.0, -2.0, 0.5, 3.0, 0.0, -0.25], vec![2, 3]).unwrap();
+        let grad = numerical_gradient(|t| t.mul(t).unwrap().sum_all(), &x, 1e-2);
+
+        assert_eq!(grad.shape(), &[2, 3]);
+        assert!(grad.allclose(&x.mul_scalar(2.0), 1e-3, 1e-3));
+    }
+
+    #[test]
+    fn test_gelu_derivative() {
+        let x = Tensor::from_vec(vec![-3.0, -1.0, -0.1, 0.0, 0.5, 2.0], vec![6]).unwrap();
+        let grad = numerical_gradient(|t| t.gelu().sum_all(), &x, 1e-2);
+
+        // d/dx of the tanh approximation
+        let c = (2.0 / std::f32::consts::PI).sqrt();
+        let expected: Vec<f32> = x
+            .iter()
+            .map(|x| {
+                let u = c * (x + 0.044715 * x.powi(3));
+                let du = c * (1.0 + 3.0 * 0.044715 * x * x);
+                0.5 * (1.0 + u.tanh()) + 0.5 * x * (1.0 - u.tanh().powi(2)) * du
+            })
+            .collect();
+        assert!(grad.allclose(&Tensor::from_vec(expected, vec![6]).unwrap(), 1e-2, 1e-3));
+    }
+
+    #[test]
+    fn test_reduction_routes_gradient_per_column() {
+        // f = sum_j w_j * sum_i x[i, j], so df/dx[i, j] = w_j
+        let x = Tensor::randn(vec![3, 4]);
+        let w = Tensor::from_vec(vec![1.0, -2.0, 0.5, 3.0], vec![4]).unwrap();
+        let grad = numerical_gradient(|t| t.sum(0).unwrap().mul(&w).unwrap().sum_all(), &x, 1e-2);
+
+        let expected = w.unsqueeze(0).unwrap().broadcast_to(&vec![3, 4].into()).unwrap();
+        assert!(grad.allclose(&expected, 1e-3, 1e-3));
+    }
+}
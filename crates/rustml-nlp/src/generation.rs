@@ -5,16 +5,37 @@
 //! - Temperature sampling
 //! - Top-k sampling
 //! - Top-p (nucleus) sampling
+//! - Logit bias and bad-words filtering
+//! - No-repeat n-gram blocking
+//! - Wall-clock time budget
+//! - Left-truncation of prompts longer than the context window
 
-use crate::{GptModel, NlpResult};
+use crate::{GptModel, NlpError, NlpResult};
 use rand::Rng;
 use rustml_core::Tensor;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// What to do when the sequence is longer than the model's context window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Truncation {
+    /// Feed the model only the last `n_positions` tokens, so an over-long
+    /// prompt is trimmed from the left and generation can run past the
+    /// context size. Any `prefix_ids` always stay at the front of the window.
+    LeftTruncate,
+    /// Reject prompts longer than the context and stop generating once the
+    /// context is full
+    #[default]
+    Error,
+}
 
 /// Configuration for text generation
 #[derive(Debug, Clone)]
 pub struct GenerationConfig {
     /// Maximum number of new tokens to generate
     pub max_new_tokens: usize,
+    /// Minimum number of new tokens to generate before EOS is allowed
+    pub min_new_tokens: usize,
     /// Temperature for sampling (1.0 = normal, less than 1.0 = more deterministic, greater than 1.0 = more random)
     pub temperature: f32,
     /// Top-k sampling: keep only top k tokens
@@ -25,23 +46,42 @@ pub struct GenerationConfig {
     pub do_sample: bool,
     /// Repetition penalty (1.0 = no penalty)
     pub repetition_penalty: f32,
+    /// Forbid repeating any n-gram of this size (0 = disabled)
+    pub no_repeat_ngram_size: usize,
     /// End-of-sequence token ID
     pub eos_token_id: Option<u32>,
     /// Pad token ID
     pub pad_token_id: Option<u32>,
+    /// Additive bias applied to the logits of specific token IDs before sampling
+    pub logit_bias: HashMap<u32, f32>,
+    /// Token IDs that must never be generated
+    pub bad_words_ids: Vec<u32>,
+    /// Token IDs fed to the model ahead of the input but left out of the output
+    pub prefix_ids: Vec<u32>,
+    /// Stop generating once this much time has passed, keeping the tokens so far
+    pub max_time: Option<Duration>,
+    /// Handling of sequences longer than the model's context window
+    pub truncation: Truncation,
 }
 
 impl Default for GenerationConfig {
     fn default() -> Self {
         Self {
             max_new_tokens: 50,
+            min_new_tokens: 0,
             temperature: 1.0,
             top_k: None,
             top_p: None,
             do_sample: true,
             repetition_penalty: 1.0,
+            no_repeat_ngram_size: 0,
             eos_token_id: Some(50256), // GPT-2 EOS token
             pad_token_id: Some(50256),
+            logit_bias: HashMap::new(),
+            bad_words_ids: Vec::new(),
+            prefix_ids: Vec::new(),
+            max_time: None,
+            truncation: Truncation::default(),
         }
     }
 }
@@ -109,6 +149,7 @@ impl<'a> TextGenerator<'a> {
     /// # Returns
     /// Generated token IDs including the input
     pub fn generate(&self, input_ids: &Tensor, config: &GenerationConfig) -> NlpResult<Tensor> {
+        let start = Instant::now();
         let mut rng = rand::thread_rng();
 
         // Ensure input is 2D [batch, seq]
@@ -119,19 +160,51 @@ impl<'a> TextGenerator<'a> {
             input_ids.clone()
         };
 
+        // Prepend the prefix to every sequence in the batch
+        let prefix_len = config.prefix_ids.len();
+        if prefix_len > 0 {
+            let batch_size = current_ids.shape()[0];
+            let prefix: Vec<f32> = config.prefix_ids.iter().map(|&id| id as f32).collect();
+            let prefix = Tensor::from_vec(prefix.repeat(batch_size), vec![batch_size, prefix_len])?;
+            current_ids = Tensor::cat(&[&prefix, &current_ids], 1)?;
+        }
+
         let max_length = self.model.max_sequence_length();
+        let prompt_len = current_ids.shape()[1];
+        if prompt_len > max_length && config.truncation == Truncation::Error {
+            return Err(NlpError::GenerationError(format!(
+                "Prompt has {} tokens but the model's context is {}",
+                prompt_len, max_length
+            )));
+        }
+        if prefix_len >= max_length && config.truncation == Truncation::LeftTruncate {
+            return Err(NlpError::GenerationError(format!(
+                "Prefix has {} tokens, leaving no room in the model's context of {}",
+                prefix_len, max_length
+            )));
+        }
 
-        for _ in 0..config.max_new_tokens {
+        for step in 0..config.max_new_tokens {
             let seq_len = current_ids.shape()[1];
 
             // Check if we've exceeded max length
-            if seq_len >= max_length {
+            if seq_len >= max_length && config.truncation == Truncation::Error {
                 break;
             }
 
-            // Truncate if needed (sliding window)
+            // Check if we've run out of time
+            if config.max_time.is_some_and(|max_time| start.elapsed() >= max_time) {
+                break;
+            }
+
+            // Truncate if needed (sliding window), keeping the prefix in view
             let model_input = if seq_len > max_length {
-                current_ids.slice(1, seq_len - max_length, seq_len)?
+                let tail = current_ids.slice(1, seq_len - (max_length - prefix_len), seq_len)?;
+                if prefix_len > 0 {
+                    Tensor::cat(&[&current_ids.slice(1, 0, prefix_len)?, &tail], 1)?
+                } else {
+                    tail
+                }
             } else {
                 current_ids.clone()
             };
@@ -149,6 +222,28 @@ impl<'a> TextGenerator<'a> {
                 last_logits
             };
 
+            // Apply logit bias and bad-words filtering
+            let last_logits = if !config.logit_bias.is_empty() || !config.bad_words_ids.is_empty() {
+                self.apply_logit_bias(&last_logits, config)?
+            } else {
+                last_logits
+            };
+
+            // Block tokens that would repeat a previously seen n-gram
+            let last_logits = if config.no_repeat_ngram_size > 0 {
+                self.apply_no_repeat_ngram(&last_logits, &current_ids, config.no_repeat_ngram_size)?
+            } else {
+                last_logits
+            };
+
+            // Suppress EOS until the minimum number of new tokens is reached
+            let last_logits = match config.eos_token_id {
+                Some(eos_id) if step < config.min_new_tokens => {
+                    self.mask_tokens(&last_logits, &[eos_id])?
+                }
+                _ => last_logits,
+            };
+
             // Sample next token
             let next_token = if config.do_sample {
                 self.sample(&last_logits, config, &mut rng)?
@@ -170,6 +265,11 @@ impl<'a> TextGenerator<'a> {
             current_ids = Tensor::cat(&[&current_ids, &next_token_2d], 1)?;
         }
 
+        if prefix_len > 0 {
+            let seq_len = current_ids.shape()[1];
+            current_ids = current_ids.slice(1, prefix_len, seq_len)?;
+        }
+
         // Return to original dimensionality if input was 1D
         if is_1d {
             Ok(current_ids.squeeze(0)?)
@@ -323,6 +423,100 @@ impl<'a> TextGenerator<'a> {
 
         Ok(Tensor::from_vec(penalized_data, logits.shape().to_vec())?)
     }
+
+    /// Apply logit bias and bad-words filtering to logits
+    ///
+    /// Biases are added to the logits of their token IDs; bad words are set to
+    /// negative infinity so they can never be selected.
+    fn apply_logit_bias(&self, logits: &Tensor, config: &GenerationConfig) -> NlpResult<Tensor> {
+        let batch_size = logits.shape()[0];
+        let vocab_size = logits.shape()[1];
+
+        let mut biased_data = logits.to_vec();
+
+        for b in 0..batch_size {
+            for (&token_id, &bias) in &config.logit_bias {
+                let token_id = token_id as usize;
+                if token_id < vocab_size {
+                    biased_data[b * vocab_size + token_id] += bias;
+                }
+            }
+            for &token_id in &config.bad_words_ids {
+                let token_id = token_id as usize;
+                if token_id < vocab_size {
+                    biased_data[b * vocab_size + token_id] = f32::NEG_INFINITY;
+                }
+            }
+        }
+
+        Ok(Tensor::from_vec(biased_data, logits.shape().to_vec())?)
+    }
+
+    /// Block tokens that would complete an n-gram already present in each batch row
+    fn apply_no_repeat_ngram(
+        &self,
+        logits: &Tensor,
+        generated_ids: &Tensor,
+        ngram_size: usize,
+    ) -> NlpResult<Tensor> {
+        let batch_size = logits.shape()[0];
+        let vocab_size = logits.shape()[1];
+
+        let mut blocked_data = logits.to_vec();
+
+        for b in 0..batch_size {
+            let tokens: Vec<u32> = generated_ids.select(0, b)?.iter().map(|t| t as u32).collect();
+            for token_id in Self::banned_ngram_tokens(&tokens, ngram_size) {
+                let token_id = token_id as usize;
+                if token_id < vocab_size {
+                    blocked_data[b * vocab_size + token_id] = f32::NEG_INFINITY;
+                }
+            }
+        }
+
+        Ok(Tensor::from_vec(blocked_data, logits.shape().to_vec())?)
+    }
+
+    /// Compute the tokens that would complete a previously seen n-gram
+    ///
+    /// The current suffix of length `ngram_size - 1` is matched against every
+    /// earlier position; the token that followed each match is banned.
+    fn banned_ngram_tokens(tokens: &[u32], ngram_size: usize) -> Vec<u32> {
+        if ngram_size == 0 || tokens.len() + 1 < ngram_size {
+            return Vec::new();
+        }
+
+        let prefix_len = ngram_size - 1;
+        let suffix = &tokens[tokens.len() - prefix_len..];
+
+        let mut banned: Vec<u32> = tokens
+            .windows(ngram_size)
+            .filter(|ngram| &ngram[..prefix_len] == suffix)
+            .map(|ngram| ngram[prefix_len])
+            .collect();
+        banned.sort_unstable();
+        banned.dedup();
+        banned
+    }
+
+    /// Set the logits of the given token IDs to negative infinity for every batch row
+    fn mask_tokens(&self, logits: &Tensor, token_ids: &[u32]) -> NlpResult<Tensor> {
+        let batch_size = logits.shape()[0];
+        let vocab_size = logits.shape()[1];
+
+        let mut masked_data = logits.to_vec();
+
+        for b in 0..batch_size {
+            for &token_id in token_ids {
+                let token_id = token_id as usize;
+                if token_id < vocab_size {
+                    masked_data[b * vocab_size + token_id] = f32::NEG_INFINITY;
+                }
+            }
+        }
+
+        Ok(Tensor::from_vec(masked_data, logits.shape().to_vec())?)
+    }
 }
 
 #[cfg(test)]
@@ -338,8 +532,9 @@ mod tests {
             n_layer: 2,
             n_head: 4,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         };
-        GptModel::new(config)
+        GptModel::new(config).unwrap()
     }
 
     #[test]
@@ -372,6 +567,103 @@ mod tests {
         assert!(output.shape()[1] <= 8);
     }
 
+    #[test]
+    fn test_max_time_stops_generation_early() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+        let input = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+
+        let mut config = GenerationConfig {
+            eos_token_id: None,
+            ..GenerationConfig::greedy(25)
+        };
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 28]);
+
+        // A budget far below the time for 25 forward passes
+        config.max_time = Some(Duration::from_micros(100));
+        let output = generator.generate(&input, &config).unwrap();
+        assert!(output.shape()[1] < 28, "generated {} tokens", output.shape()[1] - 3);
+        assert_eq!(&output.to_vec()[..3], &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_long_prompt_is_left_truncated() {
+        let model = GptModel::new(GptConfig {
+            vocab_size: 100,
+            n_positions: 8,
+            n_embd: 16,
+            n_layer: 1,
+            n_head: 2,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        })
+        .unwrap();
+        let generator = TextGenerator::new(&model);
+        let prompt: Vec<f32> = (10..22).map(|i| i as f32).collect();
+        let input = Tensor::from_vec(prompt.clone(), vec![1, 12]).unwrap();
+
+        let mut config = GenerationConfig {
+            eos_token_id: None,
+            ..GenerationConfig::greedy(3)
+        };
+        let result = generator.generate(&input, &config);
+        assert!(matches!(result, Err(NlpError::GenerationError(_))));
+
+        config.truncation = Truncation::LeftTruncate;
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 15]);
+        assert_eq!(&output.to_vec()[..12], prompt.as_slice());
+
+        // The first token is predicted from the last 8 prompt tokens
+        let window = input.slice(1, 4, 12).unwrap();
+        let logits = model.forward(&window).unwrap();
+        let expected = logits.select(1, 7).unwrap().argmax(-1).unwrap().get(&[0]).unwrap();
+        assert_eq!(output.get(&[0, 12]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_left_truncation_keeps_prefix() {
+        let model = GptModel::new(GptConfig {
+            vocab_size: 100,
+            n_positions: 8,
+            n_embd: 16,
+            n_layer: 1,
+            n_head: 2,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        })
+        .unwrap();
+        let generator = TextGenerator::new(&model);
+        let prompt: Vec<f32> = (10..22).map(|i| i as f32).collect();
+        let input = Tensor::from_vec(prompt.clone(), vec![1, 12]).unwrap();
+
+        let mut config = GenerationConfig {
+            eos_token_id: None,
+            prefix_ids: vec![50, 51, 52],
+            truncation: Truncation::LeftTruncate,
+            ..GenerationConfig::greedy(1)
+        };
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 13]);
+        assert_eq!(&output.to_vec()[..12], prompt.as_slice());
+
+        // The token is predicted from the prefix plus the last 5 prompt tokens
+        let window = Tensor::from_vec(
+            vec![50.0, 51.0, 52.0, 17.0, 18.0, 19.0, 20.0, 21.0],
+            vec![1, 8],
+        )
+        .unwrap();
+        let logits = model.forward(&window).unwrap();
+        let expected = logits.select(1, 7).unwrap().argmax(-1).unwrap().get(&[0]).unwrap();
+        assert_eq!(output.get(&[0, 12]).unwrap(), expected);
+
+        // A prefix filling the whole context leaves nothing to slide
+        config.prefix_ids = (50..58).collect();
+        let result = generator.generate(&input, &config);
+        assert!(matches!(result, Err(NlpError::GenerationError(_))));
+    }
+
     #[test]
     fn test_top_k_filter() {
         let model = create_test_model();
@@ -404,4 +696,151 @@ mod tests {
         assert!(filtered_vec[2] > 0.0); // 0.15 (cumsum 0.95 > 0.85, so include)
         assert_eq!(filtered_vec[3], 0.0); // 0.05 excluded
     }
+
+    #[test]
+    fn test_bad_words_never_generated() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+
+        let input = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        let config = GenerationConfig {
+            bad_words_ids: (0..90).collect(),
+            ..GenerationConfig::greedy(5)
+        };
+
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 8]);
+        for t in 3..8 {
+            assert!(output.get(&[0, t]).unwrap() >= 90.0);
+        }
+    }
+
+    #[test]
+    fn test_logit_bias_forces_token() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+
+        let input = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        let mut config = GenerationConfig::greedy(4);
+        config.logit_bias.insert(42, 1e4);
+
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 7]);
+        for t in 3..7 {
+            assert_eq!(output.get(&[0, t]).unwrap(), 42.0);
+        }
+    }
+
+    #[test]
+    fn test_apply_logit_bias() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+
+        let logits = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let mut config = GenerationConfig {
+            bad_words_ids: vec![2],
+            ..Default::default()
+        };
+        config.logit_bias.insert(0, 10.0);
+
+        let biased = generator.apply_logit_bias(&logits, &config).unwrap();
+        let biased_vec = biased.to_vec();
+        assert_eq!(biased_vec[0], 11.0);
+        assert_eq!(biased_vec[1], 2.0);
+        assert_eq!(biased_vec[2], f32::NEG_INFINITY);
+        assert_eq!(biased_vec[3], 14.0);
+        assert_eq!(biased_vec[5], f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_min_new_tokens_suppresses_early_eos() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+
+        let input = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+
+        // Bias EOS so it is the argmax at every step
+        let mut config = GenerationConfig::greedy(20);
+        config.eos_token_id = Some(7);
+        config.logit_bias.insert(7, 1e4);
+
+        // Without min_new_tokens, generation stops immediately
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 3]);
+
+        // With min_new_tokens, at least 5 tokens are emitted before EOS stops generation
+        config.min_new_tokens = 5;
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 8]);
+        for t in 3..8 {
+            assert_ne!(output.get(&[0, t]).unwrap(), 7.0);
+        }
+    }
+
+    #[test]
+    fn test_banned_ngram_tokens() {
+        // A B A -> the bigram (A, B) was seen, so B is banned after A
+        assert_eq!(TextGenerator::banned_ngram_tokens(&[1, 2, 1], 2), vec![2]);
+        // Trigram: suffix (2, 1) was followed by 3
+        assert_eq!(TextGenerator::banned_ngram_tokens(&[2, 1, 3, 2, 1], 3), vec![3]);
+        // Nothing seen yet
+        assert!(TextGenerator::banned_ngram_tokens(&[1, 2], 2).is_empty());
+        assert!(TextGenerator::banned_ngram_tokens(&[1], 3).is_empty());
+        // Unigram blocking bans every previous token
+        assert_eq!(TextGenerator::banned_ngram_tokens(&[3, 1, 3], 1), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_no_repeat_ngram_breaks_loop() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+
+        let input = Tensor::from_vec(vec![10.0, 11.0], vec![1, 2]).unwrap();
+
+        // Bias tokens A=5 and B=6 so greedy decoding would loop on them
+        let mut config = GenerationConfig::greedy(6);
+        config.logit_bias.insert(5, 1e4);
+        config.logit_bias.insert(6, 1e4 - 1.0);
+
+        let output = generator.generate(&input, &config).unwrap();
+        let tokens: Vec<u32> = output.iter().map(|t| t as u32).collect();
+        assert_eq!(&tokens[2..], &[5, 5, 5, 5, 5, 5]);
+
+        // With bigram blocking, no bigram may appear twice
+        config.no_repeat_ngram_size = 2;
+        let output = generator.generate(&input, &config).unwrap();
+        let tokens: Vec<u32> = output.iter().map(|t| t as u32).collect();
+        assert_eq!(tokens.len(), 8);
+        let mut bigrams: Vec<&[u32]> = tokens.windows(2).collect();
+        let total = bigrams.len();
+        bigrams.sort();
+        bigrams.dedup();
+        assert_eq!(bigrams.len(), total);
+    }
+
+    #[test]
+    fn test_prefix_conditions_but_is_stripped() {
+        let model = create_test_model();
+        let generator = TextGenerator::new(&model);
+        let input = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+
+        // With unigram blocking, no token can be generated twice or repeat the context
+        let mut config = GenerationConfig {
+            no_repeat_ngram_size: 1,
+            eos_token_id: None,
+            ..GenerationConfig::greedy(5)
+        };
+        let plain = generator.generate(&input, &config).unwrap();
+        let plain: Vec<u32> = plain.iter().map(|t| t as u32).collect();
+
+        config.prefix_ids = plain[3..].to_vec();
+        let output = generator.generate(&input, &config).unwrap();
+        assert_eq!(output.shape(), &[1, 8]);
+
+        let tokens: Vec<u32> = output.iter().map(|t| t as u32).collect();
+        assert_eq!(&tokens[..3], &[1, 2, 3]);
+        // The prefix was seen by the model, so none of its tokens come back
+        assert!(tokens[3..].iter().all(|t| !config.prefix_ids.contains(t)));
+        assert_ne!(tokens, plain);
+    }
 }
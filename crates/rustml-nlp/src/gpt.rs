@@ -3,11 +3,16 @@
 //! This module provides a complete GPT-2 implementation compatible with
 //! HuggingFace pre-trained weights.
 
-use crate::{NlpError, NlpResult};
+use crate::{BpeTokenizer, NlpError, NlpResult};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rustml_core::Tensor;
-use rustml_hub::{Gpt2WeightMapper, WeightMapper};
-use rustml_nn::{CausalSelfAttention, Embedding, LayerNorm, Linear};
+use rustml_hub::{Gpt2WeightMapper, HubApi, ModelBundle, WeightMapper};
+use rustml_nn::{CausalSelfAttention, Embedding, InitScheme, LayerNorm, Linear};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
 
 /// GPT-2 Model Configuration
 ///
@@ -16,7 +21,11 @@ use std::collections::HashMap;
 /// - Medium (355M): 1024 embed, 24 layers, 16 heads
 /// - Large (774M): 1280 embed, 36 layers, 20 heads
 /// - XL (1.5B): 1600 embed, 48 layers, 25 heads
-#[derive(Debug, Clone)]
+///
+/// Serializes with its own field names, so a saved config can be read
+/// back with serde; use [`GptConfig::from_hf_config`] for HuggingFace's
+/// `config.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GptConfig {
     /// Vocabulary size
     pub vocab_size: usize,
@@ -30,6 +39,8 @@ pub struct GptConfig {
     pub n_head: usize,
     /// Layer normalization epsilon
     pub layer_norm_eps: f32,
+    /// Padding token ID, ignored as a label by `GptModel::loss` (GPT-2 has none)
+    pub pad_token_id: Option<u32>,
 }
 
 impl GptConfig {
@@ -42,6 +53,7 @@ impl GptConfig {
             n_layer: 12,
             n_head: 12,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         }
     }
 
@@ -54,6 +66,7 @@ impl GptConfig {
             n_layer: 24,
             n_head: 16,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         }
     }
 
@@ -66,6 +79,7 @@ impl GptConfig {
             n_layer: 36,
             n_head: 20,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         }
     }
 
@@ -78,6 +92,7 @@ impl GptConfig {
             n_layer: 48,
             n_head: 25,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         }
     }
 
@@ -92,8 +107,51 @@ impl GptConfig {
             layer_norm_eps: config["layer_norm_epsilon"]
                 .as_f64()
                 .unwrap_or(1e-5) as f32,
+            pad_token_id: config["pad_token_id"].as_u64().map(|id| id as u32),
         })
     }
+
+    /// Check that a model can be built from this configuration
+    ///
+    /// Catches malformed `config.json` values before they cause a panic
+    /// deep inside layer construction.
+    pub fn validate(&self) -> NlpResult<()> {
+        for (name, value) in [
+            ("vocab_size", self.vocab_size),
+            ("n_positions", self.n_positions),
+            ("n_embd", self.n_embd),
+            ("n_head", self.n_head),
+        ] {
+            if value == 0 {
+                return Err(NlpError::ModelError(format!("{} must be greater than 0", name)));
+            }
+        }
+        if !self.n_embd.is_multiple_of(self.n_head) {
+            return Err(NlpError::ModelError(format!(
+                "n_embd ({}) must be divisible by n_head ({})",
+                self.n_embd, self.n_head
+            )));
+        }
+        if !(self.layer_norm_eps > 0.0 && self.layer_norm_eps.is_finite()) {
+            return Err(NlpError::ModelError(format!(
+                "layer_norm_eps must be positive, got {}",
+                self.layer_norm_eps
+            )));
+        }
+        Ok(())
+    }
+
+    /// Number of learnable values in a model with this configuration,
+    /// without building it (token embeddings are tied to the output layer)
+    pub fn num_parameters(&self) -> usize {
+        let d = self.n_embd;
+        let layer_norm = 2 * d;
+        let attn = (d * 3 * d + 3 * d) + (d * d + d);
+        let mlp = (d * 4 * d + 4 * d) + (4 * d * d + d);
+        let block = 2 * layer_norm + attn + mlp;
+
+        self.vocab_size * d + self.n_positions * d + self.n_layer * block + layer_norm
+    }
 }
 
 impl Default for GptConfig {
@@ -102,6 +160,79 @@ impl Default for GptConfig {
     }
 }
 
+/// Look up a loaded weight and check it has the shape the config implies
+fn get_checked(
+    weights: &HashMap<String, Tensor>,
+    name: &str,
+    expected: &[usize],
+) -> NlpResult<Option<Tensor>> {
+    let Some(weight) = weights.get(name) else {
+        return Ok(None);
+    };
+    if weight.shape() != expected {
+        return Err(NlpError::ModelError(format!(
+            "Weight {} has shape {:?}, but the config expects {:?}",
+            name,
+            weight.shape(),
+            expected
+        )));
+    }
+    Ok(Some(weight.clone()))
+}
+
+/// Like [`get_checked`], but the weight must be present
+fn get_required(
+    weights: &HashMap<String, Tensor>,
+    name: &str,
+    expected: &[usize],
+) -> NlpResult<Tensor> {
+    get_checked(weights, name, expected)?
+        .ok_or_else(|| NlpError::ModelError(format!("Missing weight: {}", name)))
+}
+
+/// Bytes taken by a model's weights, split by component
+///
+/// Counts each tensor once, so the output projection tied to the token
+/// embeddings adds nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemorySummary {
+    /// Number of learnable values
+    pub num_parameters: usize,
+    /// Token and position embeddings
+    pub embedding_bytes: usize,
+    /// All transformer blocks
+    pub block_bytes: usize,
+    /// Final layer norm
+    pub norm_bytes: usize,
+}
+
+impl MemorySummary {
+    /// Bytes taken by all weights
+    pub fn total_bytes(&self) -> usize {
+        self.embedding_bytes + self.block_bytes + self.norm_bytes
+    }
+}
+
+impl fmt::Display for MemorySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MB: f64 = 1024.0 * 1024.0;
+        write!(
+            f,
+            "{} parameters, {:.1} MB (embeddings {:.1} MB, blocks {:.1} MB, final norm {:.1} MB)",
+            self.num_parameters,
+            self.total_bytes() as f64 / MB,
+            self.embedding_bytes as f64 / MB,
+            self.block_bytes as f64 / MB,
+            self.norm_bytes as f64 / MB,
+        )
+    }
+}
+
+/// Total bytes of `tensors`
+fn byte_size(tensors: &[&Tensor]) -> usize {
+    tensors.iter().map(|t| t.byte_size()).sum()
+}
+
 /// GPT-2 MLP (Feed-Forward Network)
 ///
 /// Structure: fc → GELU → proj
@@ -118,10 +249,16 @@ pub struct GptMlp {
 impl GptMlp {
     /// Create a new MLP
     pub fn new(n_embd: usize) -> Self {
+        Self::new_with_rng(n_embd, &mut rand::thread_rng())
+    }
+
+    /// Create an MLP with weights drawn from `rng`
+    pub fn new_with_rng<R: Rng + ?Sized>(n_embd: usize, rng: &mut R) -> Self {
         let hidden_dim = 4 * n_embd;
+        let init = InitScheme::default();
         Self {
-            c_fc: Linear::new(n_embd, hidden_dim),
-            c_proj: Linear::new(hidden_dim, n_embd),
+            c_fc: Linear::new_with_rng(n_embd, hidden_dim, init, rng),
+            c_proj: Linear::new_with_rng(hidden_dim, n_embd, init, rng),
         }
     }
 
@@ -145,6 +282,13 @@ impl GptMlp {
         let out = self.c_proj.forward(&h)?;
         Ok(out)
     }
+
+    /// Expansion and projection weights and biases
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        let mut params = self.c_fc.parameters();
+        params.extend(self.c_proj.parameters());
+        params
+    }
 }
 
 /// GPT-2 Transformer Block
@@ -167,11 +311,16 @@ pub struct GptBlock {
 impl GptBlock {
     /// Create a new transformer block
     pub fn new(config: &GptConfig) -> Self {
+        Self::new_with_rng(config, &mut rand::thread_rng())
+    }
+
+    /// Create a transformer block with weights drawn from `rng`
+    pub fn new_with_rng<R: Rng + ?Sized>(config: &GptConfig, rng: &mut R) -> Self {
         Self {
             ln_1: LayerNorm::with_eps(config.n_embd, config.layer_norm_eps),
-            attn: CausalSelfAttention::new(config.n_embd, config.n_head),
+            attn: CausalSelfAttention::new_with_rng(config.n_embd, config.n_head, rng),
             ln_2: LayerNorm::with_eps(config.n_embd, config.layer_norm_eps),
-            mlp: GptMlp::new(config.n_embd),
+            mlp: GptMlp::new_with_rng(config.n_embd, rng),
         }
     }
 
@@ -181,42 +330,40 @@ impl GptBlock {
         prefix: &str,
         config: &GptConfig,
     ) -> NlpResult<Self> {
-        let get_weight = |name: &str| -> NlpResult<Tensor> {
-            weights
-                .get(&format!("{}.{}", prefix, name))
-                .cloned()
-                .ok_or_else(|| NlpError::ModelError(format!("Missing weight: {}.{}", prefix, name)))
+        let d = config.n_embd;
+        let get_weight = |name: &str, shape: &[usize]| -> NlpResult<Tensor> {
+            get_required(weights, &format!("{}.{}", prefix, name), shape)
         };
 
-        let get_weight_opt = |name: &str| -> Option<Tensor> {
-            weights.get(&format!("{}.{}", prefix, name)).cloned()
+        let get_weight_opt = |name: &str, shape: &[usize]| -> NlpResult<Option<Tensor>> {
+            get_checked(weights, &format!("{}.{}", prefix, name), shape)
         };
 
         let ln_1 = LayerNorm::from_weights(
-            get_weight("ln_1.weight")?,
-            get_weight("ln_1.bias")?,
+            get_weight("ln_1.weight", &[d])?,
+            get_weight("ln_1.bias", &[d])?,
             config.layer_norm_eps,
         )?;
 
         let attn = CausalSelfAttention::from_weights(
-            get_weight("attn.c_attn.weight")?,
-            get_weight_opt("attn.c_attn.bias"),
-            get_weight("attn.c_proj.weight")?,
-            get_weight_opt("attn.c_proj.bias"),
+            get_weight("attn.c_attn.weight", &[3 * d, d])?,
+            get_weight_opt("attn.c_attn.bias", &[3 * d])?,
+            get_weight("attn.c_proj.weight", &[d, d])?,
+            get_weight_opt("attn.c_proj.bias", &[d])?,
             config.n_head,
         )?;
 
         let ln_2 = LayerNorm::from_weights(
-            get_weight("ln_2.weight")?,
-            get_weight("ln_2.bias")?,
+            get_weight("ln_2.weight", &[d])?,
+            get_weight("ln_2.bias", &[d])?,
             config.layer_norm_eps,
         )?;
 
         let mlp = GptMlp::from_weights(
-            get_weight("mlp.c_fc.weight")?,
-            get_weight_opt("mlp.c_fc.bias"),
-            get_weight("mlp.c_proj.weight")?,
-            get_weight_opt("mlp.c_proj.bias"),
+            get_weight("mlp.c_fc.weight", &[4 * d, d])?,
+            get_weight_opt("mlp.c_fc.bias", &[4 * d])?,
+            get_weight("mlp.c_proj.weight", &[d, 4 * d])?,
+            get_weight_opt("mlp.c_proj.bias", &[d])?,
         )?;
 
         Ok(Self { ln_1, attn, ln_2, mlp })
@@ -236,6 +383,27 @@ impl GptBlock {
 
         Ok(x)
     }
+
+    /// Forward pass that also returns the block's attention weights,
+    /// shape [batch_size, n_head, seq_len, seq_len]
+    pub fn forward_with_attention(&self, x: &Tensor) -> NlpResult<(Tensor, Tensor)> {
+        let h = self.ln_1.forward(x)?;
+        let (attn_out, weights) = self.attn.forward_with_weights(&h)?;
+        let x = x.add(&attn_out)?;
+
+        let h = self.ln_2.forward(&x)?;
+        let mlp_out = self.mlp.forward(&h)?;
+        Ok((x.add(&mlp_out)?, weights))
+    }
+
+    /// Layer norm, attention and MLP parameters, in forward order
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        let mut params = self.ln_1.parameters();
+        params.extend(self.attn.parameters());
+        params.extend(self.ln_2.parameters());
+        params.extend(self.mlp.parameters());
+        params
+    }
 }
 
 /// GPT-2 Model
@@ -263,21 +431,37 @@ pub struct GptModel {
 
 impl GptModel {
     /// Create a new randomly initialized GPT model
-    pub fn new(config: GptConfig) -> Self {
-        let wte = Embedding::new(config.vocab_size, config.n_embd);
-        let wpe = Embedding::new(config.n_positions, config.n_embd);
+    ///
+    /// Fails if the configuration is invalid (see [`GptConfig::validate`]).
+    pub fn new(config: GptConfig) -> NlpResult<Self> {
+        Self::new_with_rng(config, &mut rand::thread_rng())
+    }
+
+    /// Create a randomly initialized GPT model that is the same for the
+    /// same seed, for reproducible tests and demos
+    pub fn new_seeded(config: GptConfig, seed: u64) -> NlpResult<Self> {
+        Self::new_with_rng(config, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Create a GPT model with weights drawn from `rng`
+    pub fn new_with_rng<R: Rng + ?Sized>(config: GptConfig, rng: &mut R) -> NlpResult<Self> {
+        config.validate()?;
+
+        let init = InitScheme::default();
+        let wte = Embedding::new_with_rng(config.vocab_size, config.n_embd, init, rng);
+        let wpe = Embedding::new_with_rng(config.n_positions, config.n_embd, init, rng);
         let blocks: Vec<GptBlock> = (0..config.n_layer)
-            .map(|_| GptBlock::new(&config))
+            .map(|_| GptBlock::new_with_rng(&config, rng))
             .collect();
         let ln_f = LayerNorm::with_eps(config.n_embd, config.layer_norm_eps);
 
-        Self {
+        Ok(Self {
             config,
             wte,
             wpe,
             blocks,
             ln_f,
-        }
+        })
     }
 
     /// Load model from HuggingFace Hub weights
@@ -296,18 +480,44 @@ impl GptModel {
         Self::from_weights(config, weights)
     }
 
+    /// Download a GPT-2 repository from HuggingFace Hub and load the model
+    /// with its tokenizer
+    ///
+    /// Fetches `config.json`, `model.safetensors`, `vocab.json` and
+    /// `merges.txt` into the default cache (reusing cached files).
+    ///
+    /// # Arguments
+    /// * `repo_id` - Repository identifier (e.g., "openai-community/gpt2")
+    pub async fn from_pretrained(repo_id: &str) -> NlpResult<(Self, BpeTokenizer)> {
+        let bundle = HubApi::new().download_model(repo_id).await?;
+        Self::from_bundle(&bundle).await
+    }
+
+    /// Load the model and tokenizer from downloaded repository files
+    ///
+    /// The configuration comes from `config.json` and the weights are
+    /// mapped from HuggingFace names.
+    pub async fn from_bundle(bundle: &ModelBundle) -> NlpResult<(Self, BpeTokenizer)> {
+        let config = GptConfig::from_hf_config(&bundle.load_config().await?)?;
+        let model = Self::from_hub_weights(config, bundle.load_tensors()?)?;
+        let tokenizer = BpeTokenizer::from_files(bundle.vocab_path(), bundle.merges_path())?;
+        Ok((model, tokenizer))
+    }
+
     /// Load model from pre-mapped weights
+    ///
+    /// Every weight must have the shape implied by `config`; a mismatch
+    /// (e.g. a checkpoint for a different model size) is reported with the
+    /// weight's name.
     pub fn from_weights(config: GptConfig, weights: HashMap<String, Tensor>) -> NlpResult<Self> {
-        let get_weight = |name: &str| -> NlpResult<Tensor> {
-            weights
-                .get(name)
-                .cloned()
-                .ok_or_else(|| NlpError::ModelError(format!("Missing weight: {}", name)))
-        };
+        config.validate()?;
+
+        let d = config.n_embd;
+        let get_weight = |name: &str, shape: &[usize]| get_required(&weights, name, shape);
 
         // Load embeddings
-        let wte = Embedding::from_weights(get_weight("wte.weight")?)?;
-        let wpe = Embedding::from_weights(get_weight("wpe.weight")?)?;
+        let wte = Embedding::from_weights(get_weight("wte.weight", &[config.vocab_size, d])?)?;
+        let wpe = Embedding::from_weights(get_weight("wpe.weight", &[config.n_positions, d])?)?;
 
         // Load transformer blocks
         let blocks: Result<Vec<GptBlock>, _> = (0..config.n_layer)
@@ -317,8 +527,8 @@ impl GptModel {
 
         // Load final layer norm
         let ln_f = LayerNorm::from_weights(
-            get_weight("ln_f.weight")?,
-            get_weight("ln_f.bias")?,
+            get_weight("ln_f.weight", &[d])?,
+            get_weight("ln_f.bias", &[d])?,
             config.layer_norm_eps,
         )?;
 
@@ -339,6 +549,69 @@ impl GptModel {
     /// # Returns
     /// Logits over vocabulary, shape [batch_size, seq_len, vocab_size]
     pub fn forward(&self, input_ids: &Tensor) -> NlpResult<Tensor> {
+        let hidden_states = self.forward_hidden(input_ids)?;
+
+        // Project to vocabulary (weight tying: use wte.weight.T)
+        Ok(hidden_states.matmul(&self.wte.weight.t()?)?)
+    }
+
+    /// Final-layer hidden states, before the vocabulary projection
+    ///
+    /// Useful as contextual token embeddings, e.g. mean-pooled into a
+    /// sentence embedding.
+    ///
+    /// # Arguments
+    /// * `input_ids` - Token IDs, shape [batch_size, seq_len]
+    ///
+    /// # Returns
+    /// Hidden states after the final layer norm, shape [batch_size, seq_len, n_embd]
+    pub fn forward_hidden(&self, input_ids: &Tensor) -> NlpResult<Tensor> {
+        let mut hidden_states = self.embed(input_ids)?;
+
+        // Pass through transformer blocks
+        for block in &self.blocks {
+            hidden_states = block.forward(&hidden_states)?;
+        }
+
+        // Final layer norm
+        Ok(self.ln_f.forward(&hidden_states)?)
+    }
+
+    /// Forward pass that times each stage
+    ///
+    /// Computes the same logits as [`forward`](Self::forward) and records
+    /// the wall-clock time spent in each stage, to find which layers
+    /// dominate. The keys are `"embeddings"`, `"block.{i}"` for each
+    /// transformer block, `"ln_f"` and `"lm_head"`.
+    pub fn forward_profiled(
+        &self,
+        input_ids: &Tensor,
+    ) -> NlpResult<(Tensor, HashMap<String, Duration>)> {
+        let mut profile = HashMap::with_capacity(self.blocks.len() + 3);
+
+        let start = Instant::now();
+        let mut hidden_states = self.embed(input_ids)?;
+        profile.insert("embeddings".to_string(), start.elapsed());
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            let start = Instant::now();
+            hidden_states = block.forward(&hidden_states)?;
+            profile.insert(format!("block.{}", i), start.elapsed());
+        }
+
+        let start = Instant::now();
+        let hidden_states = self.ln_f.forward(&hidden_states)?;
+        profile.insert("ln_f".to_string(), start.elapsed());
+
+        let start = Instant::now();
+        let logits = hidden_states.matmul(&self.wte.weight.t()?)?;
+        profile.insert("lm_head".to_string(), start.elapsed());
+
+        Ok((logits, profile))
+    }
+
+    /// Token plus position embeddings, the input to the first block
+    fn embed(&self, input_ids: &Tensor) -> NlpResult<Tensor> {
         let shape = input_ids.shape();
         let seq_len = shape[shape.len() - 1];
 
@@ -364,20 +637,117 @@ impl GptModel {
         let position_embeds = self.wpe.forward(&position_ids)?;
 
         // Combine embeddings
-        let mut hidden_states = token_embeds.add(&position_embeds)?;
+        Ok(token_embeds.add(&position_embeds)?)
+    }
 
-        // Pass through transformer blocks
+    /// Forward pass that also returns every layer's attention weights
+    ///
+    /// Computes the same logits as [`forward`](Self::forward) but keeps the
+    /// weights of each block; use it for inspecting or visualizing attention.
+    ///
+    /// # Arguments
+    /// * `input_ids` - Token IDs, shape [batch_size, seq_len]
+    ///
+    /// # Returns
+    /// Logits, shape [batch_size, seq_len, vocab_size], and one post-softmax
+    /// attention tensor per block, each shape [batch_size, n_head, seq_len, seq_len]
+    pub fn forward_with_attentions(&self, input_ids: &Tensor) -> NlpResult<(Tensor, Vec<Tensor>)> {
+        let mut hidden_states = self.embed(input_ids)?;
+
+        let mut attentions = Vec::with_capacity(self.blocks.len());
         for block in &self.blocks {
-            hidden_states = block.forward(&hidden_states)?;
+            let (h, weights) = block.forward_with_attention(&hidden_states)?;
+            hidden_states = h;
+            attentions.push(weights);
         }
 
-        // Final layer norm
-        hidden_states = self.ln_f.forward(&hidden_states)?;
-
-        // Project to vocabulary (weight tying: use wte.weight.T)
+        let hidden_states = self.ln_f.forward(&hidden_states)?;
         let logits = hidden_states.matmul(&self.wte.weight.t()?)?;
+        Ok((logits, attentions))
+    }
+
+    /// Mean cross-entropy of next-token prediction
+    ///
+    /// The logits at position `t` are scored against `labels` at `t + 1`;
+    /// positions whose label is `config.pad_token_id` are ignored.
+    ///
+    /// # Arguments
+    /// * `input_ids` - Token IDs, shape [batch_size, seq_len] or [seq_len]
+    /// * `labels` - Target token IDs, same shape as `input_ids` (usually the same tensor)
+    pub fn loss(&self, input_ids: &Tensor, labels: &Tensor) -> NlpResult<f32> {
+        if input_ids.shape() != labels.shape() {
+            return Err(NlpError::ModelError(format!(
+                "Labels shape {:?} doesn't match input shape {:?}",
+                labels.shape(),
+                input_ids.shape()
+            )));
+        }
+
+        let log_probs = self.forward(input_ids)?.log_softmax(-1)?;
+        let (log_probs, labels) = if labels.ndim() == 1 {
+            (log_probs.unsqueeze(0)?, labels.unsqueeze(0)?)
+        } else {
+            (log_probs, labels.clone())
+        };
+
+        let (batch_size, seq_len) = (labels.shape()[0], labels.shape()[1]);
+        let mut total = 0.0;
+        let mut count = 0usize;
+        for b in 0..batch_size {
+            for t in 0..seq_len.saturating_sub(1) {
+                let label = labels.get(&[b, t + 1])? as u32;
+                if Some(label) == self.config.pad_token_id {
+                    continue;
+                }
+                total -= log_probs.get(&[b, t, label as usize])?;
+                count += 1;
+            }
+        }
+
+        if count == 0 {
+            return Err(NlpError::ModelError(
+                "No labels to score: need at least two non-padding tokens".to_string(),
+            ));
+        }
+        Ok(total / count as f32)
+    }
+
+    /// Perplexity of `labels` under the model: `exp(loss)`
+    pub fn perplexity(&self, input_ids: &Tensor, labels: &Tensor) -> NlpResult<f32> {
+        Ok(self.loss(input_ids, labels)?.exp())
+    }
+
+    /// All learnable tensors: embeddings, every block, then the final
+    /// layer norm
+    ///
+    /// The output projection shares `wte.weight`, so it isn't listed again.
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        let mut params = self.wte.parameters();
+        params.extend(self.wpe.parameters());
+        for block in &self.blocks {
+            params.extend(block.parameters());
+        }
+        params.extend(self.ln_f.parameters());
+        params
+    }
+
+    /// Total number of learnable values
+    pub fn num_parameters(&self) -> usize {
+        self.parameters().iter().map(|p| p.numel()).sum()
+    }
 
-        Ok(logits)
+    /// Bytes taken by the model's weights, per component and in total
+    pub fn memory_summary(&self) -> MemorySummary {
+        let mut embeddings = self.wte.parameters();
+        embeddings.extend(self.wpe.parameters());
+        let blocks: usize = self.blocks.iter().map(|b| byte_size(&b.parameters())).sum();
+
+        MemorySummary {
+            num_parameters: self.num_parameters(),
+            embedding_bytes: byte_size(&embeddings),
+            block_bytes: blocks,
+            norm_bytes: byte_size(&self.ln_f.parameters()),
+        }
     }
 
     /// Get the model's vocabulary size
@@ -430,9 +800,10 @@ mod tests {
             n_layer: 2,
             n_head: 4,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         };
 
-        let model = GptModel::new(config.clone());
+        let model = GptModel::new(config.clone()).unwrap();
         assert_eq!(model.blocks.len(), 2);
         assert_eq!(model.vocab_size(), 100);
     }
@@ -446,9 +817,10 @@ mod tests {
             n_layer: 2,
             n_head: 4,
             layer_norm_eps: 1e-5,
+            pad_token_id: None,
         };
 
-        let model = GptModel::new(config);
+        let model = GptModel::new(config).unwrap();
 
         // Input: [batch=2, seq=8]
         let input_ids = Tensor::from_vec(
@@ -461,6 +833,485 @@ mod tests {
         assert_eq!(logits.shape(), &[2, 8, 100]);
     }
 
+    #[test]
+    fn test_forward_hidden_projects_to_logits() {
+        let config = GptConfig {
+            vocab_size: 100,
+            n_positions: 32,
+            n_embd: 64,
+            n_layer: 2,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let model = GptModel::new(config).unwrap();
+
+        let input_ids = Tensor::from_vec((0..16).map(|i| (i * 3 % 100) as f32).collect(), vec![2, 8])
+            .unwrap();
+        let hidden = model.forward_hidden(&input_ids).unwrap();
+        assert_eq!(hidden.shape(), &[2, 8, 64]);
+
+        let projected = hidden.matmul(&model.wte.weight.t().unwrap()).unwrap();
+        assert!(projected.allclose(&model.forward(&input_ids).unwrap(), 1e-5, 1e-5));
+    }
+
+    #[test]
+    fn test_forward_with_attentions_is_causal() {
+        let config = GptConfig {
+            vocab_size: 50,
+            n_positions: 16,
+            n_embd: 32,
+            n_layer: 3,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let model = GptModel::new(config).unwrap();
+        let input_ids = Tensor::from_vec((0..10).map(|i| (i * 7 % 50) as f32).collect(), vec![2, 5])
+            .unwrap();
+
+        let (logits, attentions) = model.forward_with_attentions(&input_ids).unwrap();
+        assert!(logits.allclose(&model.forward(&input_ids).unwrap(), 1e-5, 1e-5));
+        assert_eq!(attentions.len(), 3);
+
+        for weights in &attentions {
+            assert_eq!(weights.shape(), &[2, 4, 5, 5]);
+            for b in 0..2 {
+                for h in 0..4 {
+                    for i in 0..5 {
+                        let row: Vec<f32> =
+                            (0..5).map(|j| weights.get(&[b, h, i, j]).unwrap()).collect();
+                        assert!(row[i + 1..].iter().all(|&w| w == 0.0), "row {}: {:?}", i, row);
+                        assert!((row.iter().sum::<f32>() - 1.0).abs() < 1e-5, "row {}: {:?}", i, row);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_forward_profiled_times_every_stage() {
+        let config = GptConfig {
+            vocab_size: 50,
+            n_positions: 16,
+            n_embd: 16,
+            n_layer: 3,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let model = GptModel::new(config).unwrap();
+        let input_ids = Tensor::from_vec((0..8).map(|i| i as f32).collect(), vec![1, 8]).unwrap();
+
+        let (logits, profile) = model.forward_profiled(&input_ids).unwrap();
+        assert!(logits.allclose(&model.forward(&input_ids).unwrap(), 1e-5, 1e-5));
+
+        let mut stages: Vec<_> = profile.keys().map(String::as_str).collect();
+        stages.sort();
+        assert_eq!(stages, vec!["block.0", "block.1", "block.2", "embeddings", "lm_head", "ln_f"]);
+        assert!(profile.values().all(|d| *d >= Duration::ZERO));
+    }
+
+    /// A model without blocks that predicts its input token again with
+    /// near certainty (one-hot embeddings, sharp final layer norm)
+    fn create_echo_model() -> GptModel {
+        let mut model = GptModel::new(GptConfig {
+            vocab_size: 8,
+            n_positions: 8,
+            n_embd: 8,
+            n_layer: 0,
+            n_head: 2,
+            layer_norm_eps: 1e-5,
+            pad_token_id: Some(0),
+        })
+        .unwrap();
+        model.wte = Embedding::from_weights(Tensor::eye(8)).unwrap();
+        model.wpe = Embedding::from_weights(Tensor::zeros(vec![8, 8])).unwrap();
+        model.ln_f = LayerNorm::from_weights(
+            Tensor::full(vec![8], 50.0),
+            Tensor::zeros(vec![8]),
+            1e-5,
+        )
+        .unwrap();
+        model
+    }
+
+    #[test]
+    fn test_loss_is_finite_and_non_negative() {
+        let config = GptConfig {
+            vocab_size: 100,
+            n_positions: 32,
+            n_embd: 64,
+            n_layer: 2,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let model = GptModel::new(config).unwrap();
+
+        let ids = Tensor::from_vec((0..16).map(|i| (i * 7 % 100) as f32).collect(), vec![2, 8])
+            .unwrap();
+        let loss = model.loss(&ids, &ids).unwrap();
+        assert!(loss.is_finite());
+        assert!(loss >= 0.0);
+        assert!((model.perplexity(&ids, &ids).unwrap() - loss.exp()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_confident_correct_prediction_has_near_zero_loss() {
+        let model = create_echo_model();
+
+        let ids = Tensor::from_vec(vec![3.0, 3.0, 3.0, 3.0], vec![1, 4]).unwrap();
+        assert!(model.loss(&ids, &ids).unwrap() < 1e-4);
+        assert!((model.perplexity(&ids, &ids).unwrap() - 1.0).abs() < 1e-3);
+
+        // Confidently wrong predictions are heavily penalized
+        let ids = Tensor::from_vec(vec![3.0, 5.0, 3.0, 5.0], vec![4]).unwrap();
+        assert!(model.loss(&ids, &ids).unwrap() > 10.0);
+    }
+
+    #[test]
+    fn test_loss_ignores_padding_labels() {
+        let model = create_echo_model();
+
+        let ids = Tensor::from_vec(vec![3.0, 3.0, 5.0], vec![1, 3]).unwrap();
+        let padded = Tensor::from_vec(vec![3.0, 3.0, 5.0, 0.0, 0.0], vec![1, 5]).unwrap();
+        let expected = model.loss(&ids, &ids).unwrap();
+        assert!((model.loss(&padded, &padded).unwrap() - expected).abs() < 1e-4);
+
+        let all_padding = Tensor::from_vec(vec![3.0, 0.0, 0.0], vec![1, 3]).unwrap();
+        assert!(model.loss(&all_padding, &all_padding).is_err());
+    }
+
+    #[test]
+    fn test_loss_rejects_mismatched_labels() {
+        let model = create_echo_model();
+        let ids = Tensor::from_vec(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        let labels = Tensor::from_vec(vec![1.0, 2.0], vec![1, 2]).unwrap();
+        assert!(model.loss(&ids, &labels).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_indivisible_heads() {
+        let config = GptConfig {
+            n_embd: 65,
+            n_head: 12,
+            ..GptConfig::gpt2_small()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, NlpError::ModelError(_)));
+        assert!(err.to_string().contains("divisible"));
+
+        assert!(matches!(GptModel::new(config.clone()), Err(NlpError::ModelError(_))));
+        assert!(matches!(
+            GptModel::from_weights(config, HashMap::new()),
+            Err(NlpError::ModelError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_dims() {
+        assert!(GptConfig::gpt2_small().validate().is_ok());
+        assert!(GptConfig::gpt2_xl().validate().is_ok());
+
+        for config in [
+            GptConfig { n_head: 0, ..GptConfig::gpt2_small() },
+            GptConfig { n_embd: 0, ..GptConfig::gpt2_small() },
+            GptConfig { n_positions: 0, ..GptConfig::gpt2_small() },
+            GptConfig { vocab_size: 0, ..GptConfig::gpt2_small() },
+            GptConfig { layer_norm_eps: 0.0, ..GptConfig::gpt2_small() },
+        ] {
+            assert!(matches!(config.validate(), Err(NlpError::ModelError(_))));
+        }
+    }
+
+    #[test]
+    fn test_num_parameters_matches_model() {
+        // Small config's depth, tiny width
+        let config = GptConfig {
+            vocab_size: 100,
+            n_positions: 32,
+            n_embd: 16,
+            n_layer: GptConfig::gpt2_small().n_layer,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let model = GptModel::new(config.clone()).unwrap();
+
+        // 2 embeddings + 12 tensors per block + final layer norm
+        assert_eq!(model.parameters().len(), 2 + 12 * config.n_layer + 2);
+        assert_eq!(model.num_parameters(), config.num_parameters());
+    }
+
+    #[test]
+    fn test_config_serde_round_trip() {
+        let config = GptConfig {
+            layer_norm_eps: 1e-6,
+            pad_token_id: Some(50256),
+            ..GptConfig::gpt2_medium()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: GptConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, config);
+        assert_eq!(restored.n_embd, 1024);
+        assert_eq!(restored.n_layer, 24);
+        assert_eq!(restored.pad_token_id, Some(50256));
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["n_head"], 16);
+        assert!(value["pad_token_id"].is_number());
+
+        let restored: GptConfig =
+            serde_json::from_str(&serde_json::to_string(&GptConfig::gpt2_small()).unwrap()).unwrap();
+        assert_eq!(restored.pad_token_id, None);
+    }
+
+    #[test]
+    fn test_seeded_models_match() {
+        let config = GptConfig {
+            vocab_size: 50,
+            n_positions: 16,
+            n_embd: 16,
+            n_layer: 2,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let values = |model: &GptModel| -> Vec<Vec<f32>> {
+            model.parameters().iter().map(|p| p.to_vec()).collect()
+        };
+
+        let a = GptModel::new_seeded(config.clone(), 1).unwrap();
+        let b = GptModel::new_seeded(config.clone(), 1).unwrap();
+        let c = GptModel::new_seeded(config, 2).unwrap();
+        assert_eq!(values(&a), values(&b));
+        assert_ne!(values(&a), values(&c));
+
+        // Later layers continue the sequence rather than repeat it
+        assert_ne!(a.blocks[0].mlp.c_fc.weight.to_vec(), a.blocks[1].mlp.c_fc.weight.to_vec());
+    }
+
+    #[test]
+    fn test_memory_summary_sums_parameter_bytes() {
+        let config = GptConfig {
+            vocab_size: 100,
+            n_positions: 32,
+            n_embd: 16,
+            n_layer: 2,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        };
+        let model = GptModel::new(config.clone()).unwrap();
+        let summary = model.memory_summary();
+
+        let param_bytes: usize = model.parameters().iter().map(|p| p.byte_size()).sum();
+        assert_eq!(summary.total_bytes(), param_bytes);
+        assert_eq!(summary.total_bytes(), config.num_parameters() * 4);
+        assert_eq!(summary.num_parameters, config.num_parameters());
+        assert_eq!(summary.embedding_bytes, (100 + 32) * 16 * 4);
+        assert_eq!(summary.norm_bytes, 2 * 16 * 4);
+    }
+
+    #[test]
+    fn test_gpt2_xl_needs_about_six_gigabytes() {
+        let bytes = GptConfig::gpt2_xl().num_parameters() * 4;
+        assert!((6_000_000_000..6_500_000_000).contains(&bytes));
+    }
+
+    #[test]
+    fn test_gpt2_parameter_counts() {
+        let small = GptConfig::gpt2_small().num_parameters();
+        assert_eq!(small, 124_439_808);
+
+        let medium = GptConfig::gpt2_medium().num_parameters();
+        assert!(medium > small);
+        assert!((354_000_000..356_000_000).contains(&medium));
+    }
+
+    /// Write `tensors` as an F32 SafeTensors file
+    fn write_safetensors(path: &std::path::Path, tensors: &[(String, Vec<usize>, Vec<f32>)]) {
+        let mut header = serde_json::Map::new();
+        let mut data = Vec::new();
+        for (name, shape, values) in tensors {
+            let start = data.len();
+            data.extend(values.iter().flat_map(|v| v.to_le_bytes()));
+            header.insert(
+                name.clone(),
+                serde_json::json!({ "dtype": "F32", "shape": shape, "data_offsets": [start, data.len()] }),
+            );
+        }
+        let header = serde_json::to_vec(&header).unwrap();
+
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend(header);
+        bytes.extend(data);
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    /// A one-layer GPT-2 repository in HuggingFace layout
+    fn write_fixture_repo(dir: &std::path::Path) {
+        let (vocab, positions, d) = (11, 16, 8);
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(
+            dir.join("config.json"),
+            serde_json::json!({
+                "vocab_size": vocab,
+                "n_positions": positions,
+                "n_embd": d,
+                "n_layer": 1,
+                "n_head": 2,
+                "layer_norm_epsilon": 1e-5,
+            })
+            .to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("vocab.json"),
+            r#"{"h": 0, "e": 1, "l": 2, "o": 3, "he": 4, "ll": 5, "hell": 6, "hello": 7, "<": 8, "|": 9, ">": 10}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("merges.txt"), "#version: 0.2\nh e\nl l\nhe ll\nhell o").unwrap();
+
+        // HuggingFace stores Conv1D weights as [in_features, out_features]
+        let mut tensors = Vec::new();
+        let mut add = |name: &str, shape: Vec<usize>| {
+            let values = Tensor::randn(shape.clone()).mul_scalar(0.02).to_vec();
+            tensors.push((format!("transformer.{}", name), shape, values));
+        };
+        add("wte.weight", vec![vocab, d]);
+        add("wpe.weight", vec![positions, d]);
+        for (name, shape) in [
+            ("ln_1.weight", vec![d]),
+            ("ln_1.bias", vec![d]),
+            ("attn.c_attn.weight", vec![d, 3 * d]),
+            ("attn.c_attn.bias", vec![3 * d]),
+            ("attn.c_proj.weight", vec![d, d]),
+            ("attn.c_proj.bias", vec![d]),
+            ("ln_2.weight", vec![d]),
+            ("ln_2.bias", vec![d]),
+            ("mlp.c_fc.weight", vec![d, 4 * d]),
+            ("mlp.c_fc.bias", vec![4 * d]),
+            ("mlp.c_proj.weight", vec![4 * d, d]),
+            ("mlp.c_proj.bias", vec![d]),
+        ] {
+            add(&format!("h.0.{}", name), shape);
+        }
+        add("ln_f.weight", vec![d]);
+        add("ln_f.bias", vec![d]);
+        write_safetensors(&dir.join("model.safetensors"), &tensors);
+    }
+
+    #[tokio::test]
+    async fn test_from_bundle_assembles_model_and_tokenizer() {
+        let dir = std::env::temp_dir().join(format!("rustml-gpt-fixture-{}", std::process::id()));
+        write_fixture_repo(&dir);
+        let bundle = ModelBundle {
+            model_id: "test/tiny-gpt2".to_string(),
+            model_dir: dir.clone(),
+        };
+
+        let result = GptModel::from_bundle(&bundle).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        let (model, tokenizer) = result.unwrap();
+
+        assert_eq!(model.config.n_layer, 1);
+        assert_eq!(model.config.n_head, 2);
+        assert_eq!(model.embedding_dim(), 8);
+        assert_eq!(model.max_sequence_length(), 16);
+        // Conv1D weights are transposed into [out_features, in_features]
+        assert_eq!(model.blocks[0].attn.c_attn.weight.shape(), &[24, 8]);
+        assert_eq!(model.blocks[0].mlp.c_fc.weight.shape(), &[32, 8]);
+
+        let ids = tokenizer.encode("hello");
+        assert_eq!(ids, vec![7]);
+        let input = Tensor::from_vec(ids.iter().map(|&id| id as f32).collect(), vec![1, ids.len()]).unwrap();
+        assert_eq!(model.forward(&input).unwrap().shape(), &[1, 1, 11]);
+    }
+
+    /// Weights of `model` under the names `from_weights` expects
+    fn named_weights(model: &GptModel) -> HashMap<String, Tensor> {
+        let mut weights = HashMap::new();
+        let mut insert = |name: String, tensor: &Tensor| {
+            weights.insert(name, tensor.clone());
+        };
+        insert("wte.weight".into(), &model.wte.weight);
+        insert("wpe.weight".into(), &model.wpe.weight);
+        for (i, block) in model.blocks.iter().enumerate() {
+            let p = format!("blocks.{}", i);
+            for (name, norm) in [("ln_1", &block.ln_1), ("ln_2", &block.ln_2)] {
+                insert(format!("{}.{}.weight", p, name), &norm.weight);
+                insert(format!("{}.{}.bias", p, name), &norm.bias);
+            }
+            for (name, linear) in [
+                ("attn.c_attn", &block.attn.c_attn),
+                ("attn.c_proj", &block.attn.c_proj),
+                ("mlp.c_fc", &block.mlp.c_fc),
+                ("mlp.c_proj", &block.mlp.c_proj),
+            ] {
+                insert(format!("{}.{}.weight", p, name), &linear.weight);
+                insert(format!("{}.{}.bias", p, name), linear.bias.as_ref().unwrap());
+            }
+        }
+        insert("ln_f.weight".into(), &model.ln_f.weight);
+        insert("ln_f.bias".into(), &model.ln_f.bias);
+        weights
+    }
+
+    fn small_config() -> GptConfig {
+        GptConfig {
+            vocab_size: 50,
+            n_positions: 16,
+            n_embd: 16,
+            n_layer: 2,
+            n_head: 4,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        }
+    }
+
+    #[test]
+    fn test_from_weights_round_trip() {
+        let model = GptModel::new(small_config()).unwrap();
+        let loaded = GptModel::from_weights(small_config(), named_weights(&model)).unwrap();
+
+        let ids = Tensor::from_vec(vec![1.0, 5.0, 9.0], vec![1, 3]).unwrap();
+        assert!(loaded.forward(&ids).unwrap().approx_eq(&model.forward(&ids).unwrap(), 1e-6));
+    }
+
+    #[test]
+    fn test_from_weights_rejects_wrong_embedding_dim() {
+        let model = GptModel::new(small_config()).unwrap();
+        let mut weights = named_weights(&model);
+        weights.insert("wte.weight".into(), Tensor::randn(vec![50, 32]));
+
+        let err = GptModel::from_weights(small_config(), weights).unwrap_err();
+        assert!(matches!(err, NlpError::ModelError(_)));
+        let message = err.to_string();
+        assert!(message.contains("wte.weight"));
+        assert!(message.contains("[50, 32]") && message.contains("[50, 16]"));
+    }
+
+    #[test]
+    fn test_from_weights_rejects_mismatched_block_weight() {
+        let model = GptModel::new(small_config()).unwrap();
+        let mut weights = named_weights(&model);
+        weights.insert("blocks.1.mlp.c_fc.bias".into(), Tensor::randn(vec![16]));
+
+        let err = GptModel::from_weights(small_config(), weights).unwrap_err();
+        assert!(err.to_string().contains("blocks.1.mlp.c_fc.bias"));
+    }
+
+    #[tokio::test]
+    async fn test_from_bundle_missing_files() {
+        let bundle = ModelBundle {
+            model_id: "test/missing".to_string(),
+            model_dir: std::env::temp_dir().join("rustml-gpt-fixture-does-not-exist"),
+        };
+        assert!(GptModel::from_bundle(&bundle).await.is_err());
+    }
+
     #[test]
     fn test_gpt_mlp() {
         let mlp = GptMlp::new(64);
@@ -6,22 +6,15 @@
 //! - GPT-2 model implementation with support for all variants (small, medium, large, xl)
 //! - Text generation with temperature, top-k, and top-p sampling
 //! - BPE tokenizer for GPT-2
+//! - `TextGenerationPipeline` for prompt-in, text-out generation
 //!
 //! ## Example
 //!
 //! ```rust,ignore
-//! use rustml_nlp::{GptModel, GptConfig, TextGenerator, GenerationConfig};
-//! use rustml_nlp::tokenizer::BpeTokenizer;
-//! use rustml_hub::HubApi;
+//! use rustml_nlp::{GptModel, TextGenerationPipeline, TextGenerator, GenerationConfig};
 //!
-//! // Load model
-//! let api = HubApi::new();
-//! let bundle = api.download_model("openai-community/gpt2").await?;
-//! let weights = bundle.load_tensors()?;
-//! let model = GptModel::from_hub_weights(GptConfig::gpt2_small(), weights)?;
-//!
-//! // Load tokenizer
-//! let tokenizer = BpeTokenizer::from_files("vocab.json", "merges.txt")?;
+//! // Load model and tokenizer
+//! let (model, tokenizer) = GptModel::from_pretrained("openai-community/gpt2").await?;
 //!
 //! // Generate text
 //! let generator = TextGenerator::new(&model);
@@ -31,15 +24,21 @@
 //! )?;
 //!
 //! println!("{}", tokenizer.decode(&output));
+//!
+//! // Or let a pipeline handle tokenization
+//! let pipeline = TextGenerationPipeline::from_pretrained("openai-community/gpt2").await?;
+//! println!("{}", pipeline.generate_text("Hello world", &GenerationConfig::default())?);
 //! ```
 
 pub mod generation;
 pub mod gpt;
+pub mod pipeline;
 pub mod tokenizer;
 
-pub use generation::{GenerationConfig, TextGenerator};
-pub use gpt::{GptBlock, GptConfig, GptMlp, GptModel};
-pub use tokenizer::BpeTokenizer;
+pub use generation::{GenerationConfig, TextGenerator, Truncation};
+pub use gpt::{GptBlock, GptConfig, GptMlp, GptModel, MemorySummary};
+pub use pipeline::TextGenerationPipeline;
+pub use tokenizer::{BpeTokenizer, PaddingSide};
 
 use thiserror::Error;
 
@@ -0,0 +1,128 @@
+//! High-level text generation pipeline
+//!
+//! Wraps a model and its tokenizer so application code can go from a
+//! prompt string to generated text in one call.
+
+use crate::{BpeTokenizer, GenerationConfig, GptModel, NlpError, NlpResult, TextGenerator};
+use rustml_core::Tensor;
+
+/// Text generation pipeline: encode → generate → decode
+pub struct TextGenerationPipeline {
+    model: GptModel,
+    tokenizer: BpeTokenizer,
+    return_full_text: bool,
+}
+
+impl TextGenerationPipeline {
+    /// Create a pipeline from a model and its tokenizer
+    ///
+    /// Generated text includes the prompt by default.
+    pub fn new(model: GptModel, tokenizer: BpeTokenizer) -> Self {
+        Self {
+            model,
+            tokenizer,
+            return_full_text: true,
+        }
+    }
+
+    /// Download a model from HuggingFace Hub and create a pipeline for it
+    pub async fn from_pretrained(repo_id: &str) -> NlpResult<Self> {
+        let (model, tokenizer) = GptModel::from_pretrained(repo_id).await?;
+        Ok(Self::new(model, tokenizer))
+    }
+
+    /// Set whether generated text starts with the prompt
+    pub fn with_return_full_text(mut self, return_full_text: bool) -> Self {
+        self.return_full_text = return_full_text;
+        self
+    }
+
+    /// Get the model
+    pub fn model(&self) -> &GptModel {
+        &self.model
+    }
+
+    /// Get the tokenizer
+    pub fn tokenizer(&self) -> &BpeTokenizer {
+        &self.tokenizer
+    }
+
+    /// Generate a continuation of `prompt`
+    ///
+    /// Returns the prompt followed by the generated text, or only the
+    /// generated text when `return_full_text` is disabled.
+    pub fn generate_text(&self, prompt: &str, config: &GenerationConfig) -> NlpResult<String> {
+        let prompt_ids = self.tokenizer.encode(prompt);
+        if prompt_ids.is_empty() {
+            return Err(NlpError::GenerationError(
+                "Prompt must contain at least one token".to_string(),
+            ));
+        }
+
+        let input = Tensor::from_vec(
+            prompt_ids.iter().map(|&id| id as f32).collect(),
+            vec![prompt_ids.len()],
+        )?;
+        let output = TextGenerator::new(&self.model).generate(&input, config)?;
+        let output_ids: Vec<u32> = output.iter().map(|id| id as u32).collect();
+
+        let start = if self.return_full_text { 0 } else { prompt_ids.len() };
+        Ok(self.tokenizer.decode(&output_ids[start..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GptConfig;
+
+    fn create_test_pipeline() -> TextGenerationPipeline {
+        let vocab = r#"{"a": 0, "b": 1, "c": 2, "d": 3, "e": 4, "f": 5, "g": 6, "h": 7}"#;
+        let tokenizer = BpeTokenizer::from_strings(vocab, "#version: 0.2").unwrap();
+        let model = GptModel::new(GptConfig {
+            vocab_size: 8,
+            n_positions: 32,
+            n_embd: 16,
+            n_layer: 1,
+            n_head: 2,
+            layer_norm_eps: 1e-5,
+            pad_token_id: None,
+        })
+        .unwrap();
+        TextGenerationPipeline::new(model, tokenizer)
+    }
+
+    fn greedy_config(max_new_tokens: usize) -> GenerationConfig {
+        GenerationConfig {
+            eos_token_id: None,
+            ..GenerationConfig::greedy(max_new_tokens)
+        }
+    }
+
+    #[test]
+    fn test_generate_text_extends_prompt() {
+        let pipeline = create_test_pipeline();
+
+        let text = pipeline.generate_text("abc", &greedy_config(5)).unwrap();
+        assert!(text.starts_with("abc"));
+        assert_eq!(text.len(), 8);
+    }
+
+    #[test]
+    fn test_generate_text_strips_prompt() {
+        let pipeline = create_test_pipeline();
+        let full = pipeline.generate_text("abc", &greedy_config(5)).unwrap();
+
+        let pipeline = pipeline.with_return_full_text(false);
+        let continuation = pipeline.generate_text("abc", &greedy_config(5)).unwrap();
+        assert_eq!(continuation.len(), 5);
+        assert_eq!(format!("abc{}", continuation), full);
+    }
+
+    #[test]
+    fn test_generate_text_rejects_empty_prompt() {
+        let pipeline = create_test_pipeline();
+        let result = pipeline.generate_text("", &greedy_config(5));
+        assert!(matches!(result, Err(NlpError::GenerationError(_))));
+    }
+}
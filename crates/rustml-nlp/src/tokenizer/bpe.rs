@@ -1,10 +1,32 @@
 //! BPE (Byte Pair Encoding) Tokenizer for GPT-2
 
 use crate::{NlpError, NlpResult};
-use std::collections::HashMap;
+use rustml_core::Tensor;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
+/// Side on which padding tokens are inserted in a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaddingSide {
+    /// Pad before the tokens (what GPT-2 generation wants)
+    #[default]
+    Left,
+    /// Pad after the tokens
+    Right,
+}
+
+/// Everything needed to rebuild a [`BpeTokenizer`], as saved by
+/// [`BpeTokenizer::to_json`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TokenizerState {
+    vocab: BTreeMap<String, u32>,
+    /// Merge rules, highest priority first, as `"left right"`
+    merges: Vec<String>,
+    special_tokens: BTreeMap<String, u32>,
+}
+
 /// GPT-2 BPE Tokenizer
 #[derive(Debug, Clone)]
 pub struct BpeTokenizer {
@@ -14,6 +36,8 @@ pub struct BpeTokenizer {
     byte_encoder: HashMap<u8, char>,
     byte_decoder: HashMap<char, u8>,
     pat: regex::Regex,
+    special_tokens: HashMap<String, u32>,
+    bos_token_id: u32,
     eos_token_id: u32,
 }
 
@@ -21,6 +45,9 @@ impl BpeTokenizer {
     /// GPT-2 EOS token ID
     pub const GPT2_EOS_TOKEN_ID: u32 = 50256;
 
+    /// GPT-2 end-of-text special token
+    pub const GPT2_EOS_TOKEN: &'static str = "<|endoftext|>";
+
     /// Create a tokenizer from vocab.json and merges.txt files
     pub fn from_files(vocab_path: impl AsRef<Path>, merges_path: impl AsRef<Path>) -> NlpResult<Self> {
         let vocab_content = fs::read_to_string(vocab_path)?;
@@ -45,7 +72,16 @@ impl BpeTokenizer {
         ).map_err(|e| NlpError::TokenizerError(format!("Regex error: {}", e)))?;
 
         // EOS token: the token at position 50256. We look it up or default.
-        let eos_token_id = Self::GPT2_EOS_TOKEN_ID;
+        let eos_token_id = encoder
+            .get(Self::GPT2_EOS_TOKEN)
+            .copied()
+            .unwrap_or(Self::GPT2_EOS_TOKEN_ID);
+
+        // GPT-2 registers <|endoftext|> as its only special token (used for both BOS and EOS)
+        let mut special_tokens = HashMap::new();
+        if encoder.contains_key(Self::GPT2_EOS_TOKEN) {
+            special_tokens.insert(Self::GPT2_EOS_TOKEN.to_string(), eos_token_id);
+        }
 
         Ok(Self {
             encoder,
@@ -54,24 +90,206 @@ impl BpeTokenizer {
             byte_encoder,
             byte_decoder,
             pat,
+            special_tokens,
+            bos_token_id: eos_token_id,
             eos_token_id,
         })
     }
 
+    /// Save the vocabulary, merges and special tokens as JSON
+    pub fn to_json(&self) -> NlpResult<String> {
+        let mut merges: Vec<_> = self.bpe_ranks.iter().collect();
+        merges.sort_by_key(|&(_, rank)| rank);
+
+        let state = TokenizerState {
+            vocab: self.encoder.iter().map(|(k, &v)| (k.clone(), v)).collect(),
+            merges: merges.into_iter().map(|((a, b), _)| format!("{} {}", a, b)).collect(),
+            special_tokens: self.special_tokens.iter().map(|(k, &v)| (k.clone(), v)).collect(),
+        };
+        serde_json::to_string(&state)
+            .map_err(|e| NlpError::TokenizerError(format!("Failed to save tokenizer: {}", e)))
+    }
+
+    /// Rebuild a tokenizer saved with [`BpeTokenizer::to_json`]
+    pub fn from_json(json: &str) -> NlpResult<Self> {
+        let state: TokenizerState = serde_json::from_str(json)
+            .map_err(|e| NlpError::TokenizerError(format!("Failed to load tokenizer: {}", e)))?;
+        let vocab = serde_json::to_string(&state.vocab)
+            .map_err(|e| NlpError::TokenizerError(format!("Failed to load tokenizer: {}", e)))?;
+
+        let mut tokenizer = Self::from_strings(&vocab, &state.merges.join("\n"))?;
+        for (text, id) in &state.special_tokens {
+            tokenizer.add_special_token(text, *id);
+        }
+        Ok(tokenizer)
+    }
+
+    /// Get the BOS token ID
+    pub fn bos_token_id(&self) -> u32 {
+        self.bos_token_id
+    }
+
     /// Get the EOS token ID
     pub fn eos_token_id(&self) -> u32 {
         self.eos_token_id
     }
 
+    /// Register a special token that is never split or merged by BPE
+    ///
+    /// Special tokens are matched literally in the input text before BPE
+    /// and decode back to their literal string.
+    pub fn add_special_token(&mut self, text: &str, id: u32) {
+        self.special_tokens.insert(text.to_string(), id);
+        self.decoder.insert(id, text.to_string());
+    }
+
+    /// Get the ID of a registered special token
+    pub fn special_token_id(&self, text: &str) -> Option<u32> {
+        self.special_tokens.get(text).copied()
+    }
+
+    /// Check whether a token ID belongs to a registered special token
+    pub fn is_special_token(&self, id: u32) -> bool {
+        self.special_tokens.values().any(|&v| v == id)
+    }
+
     /// Get vocabulary size
     pub fn vocab_size(&self) -> usize {
         self.encoder.len()
     }
 
     /// Encode text to token IDs
+    ///
+    /// Registered special tokens are split out before BPE and encoded as single IDs.
     pub fn encode(&self, text: &str) -> Vec<u32> {
         let mut tokens = Vec::new();
 
+        for (segment, special_id) in self.split_special_tokens(text) {
+            match special_id {
+                Some(id) => tokens.push(id),
+                None => tokens.extend(self.encode_ordinary(segment)),
+            }
+        }
+
+        tokens
+    }
+
+    /// Encode text, optionally wrapping it with BOS and EOS tokens
+    pub fn encode_with_special(&self, text: &str, add_bos: bool, add_eos: bool) -> Vec<u32> {
+        let mut tokens = Vec::new();
+        if add_bos {
+            tokens.push(self.bos_token_id);
+        }
+        tokens.extend(self.encode(text));
+        if add_eos {
+            tokens.push(self.eos_token_id);
+        }
+        tokens
+    }
+
+    /// Encode a batch of texts into padded token IDs and an attention mask
+    ///
+    /// Sequences longer than `max_len` are truncated to their first `max_len`
+    /// tokens; all sequences are then padded with `pad_id` to the longest length.
+    ///
+    /// # Returns
+    /// `(input_ids, attention_mask)`, both of shape [batch_size, seq_len].
+    /// The mask is 1.0 for real tokens and 0.0 for padding.
+    pub fn encode_batch(
+        &self,
+        texts: &[&str],
+        max_len: Option<usize>,
+        pad_id: u32,
+        padding_side: PaddingSide,
+    ) -> NlpResult<(Tensor, Tensor)> {
+        if texts.is_empty() {
+            return Err(NlpError::TokenizerError("Cannot encode an empty batch".into()));
+        }
+
+        let encoded: Vec<Vec<u32>> = texts
+            .iter()
+            .map(|text| {
+                let mut ids = self.encode(text);
+                if let Some(max_len) = max_len {
+                    ids.truncate(max_len);
+                }
+                ids
+            })
+            .collect();
+
+        let batch_size = encoded.len();
+        let seq_len = encoded.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut ids_data = Vec::with_capacity(batch_size * seq_len);
+        let mut mask_data = Vec::with_capacity(batch_size * seq_len);
+
+        for ids in &encoded {
+            let n_pad = seq_len - ids.len();
+            let pad = std::iter::repeat_n((pad_id as f32, 0.0), n_pad);
+            let tokens = ids.iter().map(|&id| (id as f32, 1.0));
+
+            let row: Vec<(f32, f32)> = match padding_side {
+                PaddingSide::Left => pad.chain(tokens).collect(),
+                PaddingSide::Right => tokens.chain(pad).collect(),
+            };
+            for (id, mask) in row {
+                ids_data.push(id);
+                mask_data.push(mask);
+            }
+        }
+
+        let input_ids = Tensor::from_vec(ids_data, vec![batch_size, seq_len])?;
+        let attention_mask = Tensor::from_vec(mask_data, vec![batch_size, seq_len])?;
+
+        Ok((input_ids, attention_mask))
+    }
+
+    /// Decode a batch of token IDs, shape [batch_size, seq_len], into one string per row
+    ///
+    /// Positions where `attention_mask` (as returned by [`encode_batch`](Self::encode_batch))
+    /// is 0.0 are padding and are skipped, so padding on either side is stripped.
+    pub fn decode_batch(&self, ids: &Tensor, attention_mask: Option<&Tensor>) -> NlpResult<Vec<String>> {
+        if ids.ndim() != 2 {
+            return Err(NlpError::TokenizerError(format!(
+                "Expected 2D token IDs [batch, seq], got {:?}",
+                ids.shape()
+            )));
+        }
+        if let Some(mask) = attention_mask
+            && mask.shape() != ids.shape()
+        {
+            return Err(NlpError::TokenizerError(format!(
+                "Attention mask shape {:?} does not match token IDs {:?}",
+                mask.shape(),
+                ids.shape()
+            )));
+        }
+
+        let batch_size = ids.shape()[0];
+        let mut texts = Vec::with_capacity(batch_size);
+
+        for b in 0..batch_size {
+            let row = ids.select(0, b)?.to_vec();
+            let keep = match attention_mask {
+                Some(mask) => mask.select(0, b)?.iter().map(|m| m != 0.0).collect(),
+                None => vec![true; row.len()],
+            };
+            let row: Vec<u32> = row
+                .into_iter()
+                .zip(keep)
+                .filter(|&(_, keep)| keep)
+                .map(|(id, _)| id as u32)
+                .collect();
+            texts.push(self.decode(&row));
+        }
+
+        Ok(texts)
+    }
+
+    /// Encode text with BPE only, ignoring special tokens
+    fn encode_ordinary(&self, text: &str) -> Vec<u32> {
+        let mut tokens = Vec::new();
+
         for mat in self.pat.find_iter(text) {
             let token_str = mat.as_str();
             // Convert bytes to unicode representation
@@ -95,17 +313,64 @@ impl BpeTokenizer {
     }
 
     /// Decode token IDs to text
+    ///
+    /// Byte-level tokens are mapped back through the inverse byte decoder and
+    /// the resulting bytes are interpreted as UTF-8, so spaces (`Ġ`) and
+    /// multibyte characters are reconstructed exactly. Special tokens are
+    /// rendered back to their literal strings.
     pub fn decode(&self, tokens: &[u32]) -> String {
-        let text: String = tokens
-            .iter()
-            .filter_map(|&id| self.decoder.get(&id))
-            .flat_map(|s| s.chars())
-            .filter_map(|c| self.byte_decoder.get(&c).copied())
-            .map(|b| b as char)
-            .collect();
+        let mut text = String::new();
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for &id in tokens {
+            let Some(token) = self.decoder.get(&id) else {
+                continue;
+            };
+            if self.is_special_token(id) {
+                text.push_str(&String::from_utf8_lossy(&bytes));
+                bytes.clear();
+                text.push_str(token);
+            } else {
+                bytes.extend(token.chars().filter_map(|c| self.byte_decoder.get(&c).copied()));
+            }
+        }
+
+        text.push_str(&String::from_utf8_lossy(&bytes));
         text
     }
 
+    /// Split text into ordinary segments and special tokens
+    ///
+    /// Returns `(segment, Some(id))` for special tokens and `(segment, None)`
+    /// for text in between. When several special tokens match at the same
+    /// position, the longest one wins.
+    fn split_special_tokens<'t>(&self, text: &'t str) -> Vec<(&'t str, Option<u32>)> {
+        let mut segments = Vec::new();
+        let mut rest = text;
+
+        while !rest.is_empty() {
+            // Find the earliest (then longest) special token occurrence
+            let next = self
+                .special_tokens
+                .iter()
+                .filter_map(|(tok, &id)| rest.find(tok.as_str()).map(|pos| (pos, tok.len(), id)))
+                .min_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+            let Some((pos, len, id)) = next else {
+                segments.push((rest, None));
+                break;
+            };
+
+            if pos > 0 {
+                segments.push((&rest[..pos], None));
+            }
+            segments.push((&rest[pos..pos + len], Some(id)));
+            rest = &rest[pos + len..];
+        }
+
+        segments
+    }
+
     /// Apply BPE to a word
     fn bpe(&self, token: &str) -> Vec<String> {
         if token.is_empty() {
@@ -240,4 +505,168 @@ mod tests {
         let tokenizer = BpeTokenizer::from_strings(vocab, merges).unwrap();
         assert_eq!(tokenizer.vocab_size(), 3);
     }
+
+    fn create_test_tokenizer() -> BpeTokenizer {
+        let vocab = r#"{"h": 0, "e": 1, "l": 2, "o": 3, "he": 4, "ll": 5, "hell": 6, "hello": 7, "<": 8, "|": 9, ">": 10}"#;
+        let merges = "#version: 0.2\nh e\nl l\nhe ll\nhell o";
+        BpeTokenizer::from_strings(vocab, merges).unwrap()
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut tokenizer = create_test_tokenizer();
+        tokenizer.add_special_token("<|endoftext|>", 50256);
+
+        let restored = BpeTokenizer::from_json(&tokenizer.to_json().unwrap()).unwrap();
+        assert_eq!(restored.vocab_size(), tokenizer.vocab_size());
+        assert_eq!(restored.special_token_id("<|endoftext|>"), Some(50256));
+        for text in ["hello", "hell", "hello<|endoftext|>he", "olleh"] {
+            assert_eq!(restored.encode(text), tokenizer.encode(text), "{}", text);
+        }
+        assert_eq!(restored.decode(&[7, 50256]), "hello<|endoftext|>");
+
+        // Saving is deterministic, so saved files diff cleanly
+        assert_eq!(restored.to_json().unwrap(), tokenizer.to_json().unwrap());
+        assert!(BpeTokenizer::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_special_token_encodes_as_single_id() {
+        let mut tokenizer = create_test_tokenizer();
+        tokenizer.add_special_token("<|endoftext|>", 50256);
+
+        assert_eq!(tokenizer.encode("<|endoftext|>"), vec![50256]);
+        assert_eq!(tokenizer.encode("hello<|endoftext|>hello"), vec![7, 50256, 7]);
+    }
+
+    #[test]
+    fn test_special_token_round_trip() {
+        let mut tokenizer = create_test_tokenizer();
+        tokenizer.add_special_token("<|endoftext|>", 50256);
+
+        let text = "hello<|endoftext|>";
+        assert_eq!(tokenizer.decode(&tokenizer.encode(text)), text);
+        assert_eq!(tokenizer.decode(&[50256]), "<|endoftext|>");
+    }
+
+    #[test]
+    fn test_special_token_registered_from_vocab() {
+        let vocab = r#"{"a": 0, "<|endoftext|>": 1}"#;
+        let tokenizer = BpeTokenizer::from_strings(vocab, "").unwrap();
+
+        assert_eq!(tokenizer.special_token_id("<|endoftext|>"), Some(1));
+        assert_eq!(tokenizer.eos_token_id(), 1);
+        assert_eq!(tokenizer.encode("a<|endoftext|>"), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_encode_with_special() {
+        let tokenizer = create_test_tokenizer();
+
+        let ids = tokenizer.encode_with_special("hello", true, true);
+        assert_eq!(ids, vec![50256, 7, 50256]);
+
+        let ids = tokenizer.encode_with_special("hello", false, true);
+        assert_eq!(ids, vec![7, 50256]);
+    }
+
+    /// Tokenizer whose vocab holds every byte symbol plus a few space-prefixed merges
+    fn create_byte_level_tokenizer() -> BpeTokenizer {
+        let (byte_encoder, _) = BpeTokenizer::bytes_to_unicode();
+        let mut vocab: HashMap<String, u32> = (0u8..=255)
+            .map(|b| (byte_encoder[&b].to_string(), b as u32))
+            .collect();
+        vocab.insert("Ġw".to_string(), 256);
+        vocab.insert("Ġwo".to_string(), 257);
+        let vocab_json = serde_json::to_string(&vocab).unwrap();
+        let merges = "#version: 0.2\nĠ w\nĠw o";
+        BpeTokenizer::from_strings(&vocab_json, merges).unwrap()
+    }
+
+    #[test]
+    fn test_decode_round_trip_accents_and_punctuation() {
+        let tokenizer = create_byte_level_tokenizer();
+        let text = "Hello, world! café";
+
+        let ids = tokenizer.encode(text);
+        // " wo" is merged into a single space-prefixed token
+        assert!(ids.contains(&257));
+        assert_eq!(tokenizer.decode(&ids), text);
+    }
+
+    #[test]
+    fn test_decode_round_trip_emoji() {
+        let tokenizer = create_byte_level_tokenizer();
+        let text = "Rust 🦀 is fun 🎉";
+        assert_eq!(tokenizer.decode(&tokenizer.encode(text)), text);
+    }
+
+    #[test]
+    fn test_decode_round_trip_leading_trailing_spaces() {
+        let tokenizer = create_byte_level_tokenizer();
+        for text in ["  leading", "trailing  ", " both ", "multiple   inner   spaces"] {
+            assert_eq!(tokenizer.decode(&tokenizer.encode(text)), text);
+        }
+    }
+
+    #[test]
+    fn test_decode_multibyte_split_across_tokens() {
+        let tokenizer = create_byte_level_tokenizer();
+        // 'é' is two UTF-8 bytes, encoded here as two separate byte tokens
+        let ids: Vec<u32> = "é".bytes().map(u32::from).collect();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(tokenizer.decode(&ids), "é");
+    }
+
+    #[test]
+    fn test_encode_batch_left_padding() {
+        let tokenizer = create_test_tokenizer();
+
+        // "hello" -> [7], "he<>" -> [4, 8, 10]
+        let (ids, mask) = tokenizer
+            .encode_batch(&["hello", "he<>"], None, 99, PaddingSide::Left)
+            .unwrap();
+
+        assert_eq!(ids.shape(), &[2, 3]);
+        assert_eq!(ids.to_vec(), vec![99.0, 99.0, 7.0, 4.0, 8.0, 10.0]);
+        assert_eq!(mask.to_vec(), vec![0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_encode_batch_right_padding_and_truncation() {
+        let tokenizer = create_test_tokenizer();
+
+        let (ids, mask) = tokenizer
+            .encode_batch(&["hello", "he<>"], Some(2), 99, PaddingSide::Right)
+            .unwrap();
+
+        assert_eq!(ids.shape(), &[2, 2]);
+        assert_eq!(ids.to_vec(), vec![7.0, 99.0, 4.0, 8.0]);
+        assert_eq!(mask.to_vec(), vec![1.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_decode_batch_strips_padding() {
+        let tokenizer = create_test_tokenizer();
+        let texts = ["hello", "he<>"];
+
+        for side in [PaddingSide::Left, PaddingSide::Right] {
+            // Pad 0 is a real token ("h"), so only the mask tells it apart
+            let (ids, mask) = tokenizer.encode_batch(&texts, None, 0, side).unwrap();
+            assert_eq!(ids.shape(), &[2, 3]);
+
+            assert_eq!(tokenizer.decode_batch(&ids, Some(&mask)).unwrap(), texts);
+            assert_ne!(tokenizer.decode_batch(&ids, None).unwrap()[0], "hello");
+        }
+    }
+
+    #[test]
+    fn test_decode_batch_rejects_mismatched_mask() {
+        let tokenizer = create_test_tokenizer();
+        let (ids, _) = tokenizer
+            .encode_batch(&["hello", "he<>"], None, 0, PaddingSide::Right)
+            .unwrap();
+        let mask = Tensor::ones(vec![2, 2]);
+        assert!(tokenizer.decode_batch(&ids, Some(&mask)).is_err());
+    }
 }
@@ -4,4 +4,4 @@
 
 mod bpe;
 
-pub use bpe::BpeTokenizer;
+pub use bpe::{BpeTokenizer, PaddingSide};
@@ -1,6 +1,7 @@
 //! Attention mechanisms including Causal Self-Attention for GPT
 
-use crate::{Linear, NnResult};
+use crate::{InitScheme, Linear, Module, NnResult};
+use rand::Rng;
 use rustml_core::Tensor;
 
 /// Base attention trait
@@ -115,6 +116,14 @@ impl MultiHeadAttention {
         // Output projection
         self.wo.forward(&out)
     }
+
+    /// Q, K, V and output projection weights and biases
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        [&self.wq, &self.wk, &self.wv, &self.wo]
+            .into_iter()
+            .flat_map(Linear::parameters)
+            .collect()
+    }
 }
 
 /// Causal Self-Attention for GPT-style models
@@ -141,14 +150,20 @@ pub struct CausalSelfAttention {
 impl CausalSelfAttention {
     /// Create a new causal self-attention layer
     pub fn new(n_embd: usize, n_head: usize) -> Self {
+        Self::new_with_rng(n_embd, n_head, &mut rand::thread_rng())
+    }
+
+    /// Create a causal self-attention layer with weights drawn from `rng`
+    pub fn new_with_rng<R: Rng + ?Sized>(n_embd: usize, n_head: usize, rng: &mut R) -> Self {
         assert!(
             n_embd % n_head == 0,
             "Embedding dimension must be divisible by number of heads"
         );
 
         // Combined QKV projection (GPT-2 style)
-        let c_attn = Linear::new(n_embd, 3 * n_embd);
-        let c_proj = Linear::new(n_embd, n_embd);
+        let init = InitScheme::default();
+        let c_attn = Linear::new_with_rng(n_embd, 3 * n_embd, init, rng);
+        let c_proj = Linear::new_with_rng(n_embd, n_embd, init, rng);
 
         Self {
             c_attn,
@@ -184,6 +199,38 @@ impl CausalSelfAttention {
     /// Input shape: [batch_size, seq_len, n_embd]
     /// Output shape: [batch_size, seq_len, n_embd]
     pub fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        let (out, _) = self.attend(x, None)?;
+        Ok(out)
+    }
+
+    /// Forward pass with some heads switched off
+    ///
+    /// `head_mask[h] == true` disables head `h`: its output is zeroed before
+    /// the output projection, so it contributes nothing but the bias. Useful
+    /// for ablation studies of head importance. `None` is the same as
+    /// [`forward`](Self::forward).
+    ///
+    /// Input shape: [batch_size, seq_len, n_embd]
+    /// Output shape: [batch_size, seq_len, n_embd]
+    pub fn forward_with_head_mask(&self, x: &Tensor, head_mask: Option<&[bool]>) -> NnResult<Tensor> {
+        let (out, _) = self.attend(x, head_mask)?;
+        Ok(out)
+    }
+
+    /// Forward pass that also returns the attention weights
+    ///
+    /// Intended for inspecting or visualizing what each head attends to.
+    ///
+    /// Input shape: [batch_size, seq_len, n_embd]
+    /// Returns the output, shape [batch_size, seq_len, n_embd], and the
+    /// post-softmax weights, shape [batch_size, n_head, seq_len, seq_len],
+    /// where row `i` is how much position `i` attends to each position.
+    pub fn forward_with_weights(&self, x: &Tensor) -> NnResult<(Tensor, Tensor)> {
+        self.attend(x, None)
+    }
+
+    /// Output and attention weights; `forward` drops the weights
+    fn attend(&self, x: &Tensor, head_mask: Option<&[bool]>) -> NnResult<(Tensor, Tensor)> {
         let shape = x.shape();
         if shape.len() != 3 {
             return Err(crate::NnError::ShapeMismatch(format!(
@@ -191,6 +238,15 @@ impl CausalSelfAttention {
                 shape
             )));
         }
+        if let Some(mask) = head_mask
+            && mask.len() != self.n_head
+        {
+            return Err(crate::NnError::ShapeMismatch(format!(
+                "Head mask has {} entries, expected one per head ({})",
+                mask.len(),
+                self.n_head
+            )));
+        }
 
         let batch_size = shape[0];
         let seq_len = shape[1];
@@ -231,13 +287,22 @@ impl CausalSelfAttention {
         // 7. Compute weighted values: attn @ V
         let out = attn.matmul(&v)?; // [B, H, T, C/H]
 
+        // Zero the output of masked heads
+        let out = match head_mask {
+            Some(mask) => {
+                let keep = mask.iter().map(|&masked| if masked { 0.0 } else { 1.0 }).collect();
+                out.mul(&Tensor::from_vec(keep, vec![1, self.n_head, 1, 1])?)?
+            }
+            None => out,
+        };
+
         // 8. Reshape back: [B, H, T, C/H] -> [B, T, C]
         let out = out
             .transpose(1, 2)?
             .reshape(vec![batch_size, seq_len, n_embd])?;
 
         // 9. Output projection
-        self.c_proj.forward(&out)
+        Ok((self.c_proj.forward(&out)?, attn))
     }
 
     /// Create a causal mask for the given sequence length
@@ -258,6 +323,13 @@ impl CausalSelfAttention {
         let ones = Tensor::ones(vec![seq_len, seq_len]);
         ones.sub(&tril).unwrap()
     }
+
+    /// QKV and output projection weights and biases
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        let mut params = self.c_attn.parameters();
+        params.extend(self.c_proj.parameters());
+        params
+    }
 }
 
 impl Attention for CausalSelfAttention {
@@ -266,6 +338,26 @@ impl Attention for CausalSelfAttention {
     }
 }
 
+impl Module for CausalSelfAttention {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        CausalSelfAttention::forward(self, x)
+    }
+
+    fn parameters(&self) -> Vec<&Tensor> {
+        CausalSelfAttention::parameters(self)
+    }
+}
+
+impl Module for MultiHeadAttention {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        MultiHeadAttention::forward(self, x)
+    }
+
+    fn parameters(&self) -> Vec<&Tensor> {
+        MultiHeadAttention::parameters(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,6 +370,67 @@ mod tests {
         assert_eq!(y.shape(), &[2, 10, 768]);
     }
 
+    #[test]
+    fn test_causal_attention_parameters() {
+        let attn = CausalSelfAttention::new(64, 4);
+        let shapes: Vec<_> = attn.parameters().iter().map(|p| p.shape().to_vec()).collect();
+        assert_eq!(shapes, vec![vec![192, 64], vec![192], vec![64, 64], vec![64]]);
+    }
+
+    #[test]
+    fn test_attention_weights_are_causal_and_normalized() {
+        let attn = CausalSelfAttention::new(32, 4);
+        let x = Tensor::randn(vec![2, 6, 32]);
+        let (y, weights) = attn.forward_with_weights(&x).unwrap();
+        assert_eq!(weights.shape(), &[2, 4, 6, 6]);
+        assert!(y.allclose(&attn.forward(&x).unwrap(), 1e-6, 1e-6));
+
+        for b in 0..2 {
+            for h in 0..4 {
+                for i in 0..6 {
+                    let mut sum = 0.0;
+                    for j in 0..6 {
+                        let w = weights.get(&[b, h, i, j]).unwrap();
+                        if j > i {
+                            assert_eq!(w, 0.0, "position {} attends to future {}", i, j);
+                        }
+                        sum += w;
+                    }
+                    assert!((sum - 1.0).abs() < 1e-5, "row {} sums to {}", i, sum);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_head_mask() {
+        let mut attn = CausalSelfAttention::new(32, 4);
+        attn.c_proj.bias = Some(Tensor::full(vec![32], 0.5));
+        let x = Tensor::randn(vec![2, 6, 32]);
+
+        // Masking every head leaves only the output projection's bias
+        let y = attn.forward_with_head_mask(&x, Some(&[true; 4])).unwrap();
+        assert!(y.allclose(&Tensor::full(vec![2, 6, 32], 0.5), 1e-6, 1e-6));
+
+        let expected = attn.forward(&x).unwrap();
+        let y = attn.forward_with_head_mask(&x, Some(&[false; 4])).unwrap();
+        assert!(y.allclose(&expected, 1e-6, 1e-6));
+        let y = attn.forward_with_head_mask(&x, None).unwrap();
+        assert!(y.allclose(&expected, 1e-6, 1e-6));
+
+        // Masking one head changes the output
+        let y = attn.forward_with_head_mask(&x, Some(&[false, true, false, false])).unwrap();
+        assert!(!y.allclose(&expected, 1e-6, 1e-6));
+    }
+
+    #[test]
+    fn test_head_mask_rejects_wrong_length() {
+        let attn = CausalSelfAttention::new(32, 4);
+        let x = Tensor::randn(vec![1, 3, 32]);
+        let result = attn.forward_with_head_mask(&x, Some(&[true, false]));
+        assert!(matches!(result, Err(crate::NnError::ShapeMismatch(_))));
+    }
+
     #[test]
     fn test_causal_mask() {
         let mask = CausalSelfAttention::create_causal_mask(4);
@@ -1,6 +1,7 @@
 //! Embedding layer implementation
 
-use crate::NnResult;
+use crate::{InitScheme, Module, NnResult};
+use rand::Rng;
 use rustml_core::Tensor;
 
 /// Embedding layer that maps token indices to dense vectors
@@ -15,13 +16,25 @@ pub struct Embedding {
 }
 
 impl Embedding {
-    /// Create a new embedding layer with random initialization
+    /// Create a new embedding layer with GPT-2 style `N(0, 0.02)` weights
     pub fn new(num_embeddings: usize, embedding_dim: usize) -> Self {
-        // Standard normal initialization scaled by 0.02 (GPT-2 style)
-        let weight = Tensor::randn(vec![num_embeddings, embedding_dim]).mul_scalar(0.02);
+        Self::new_with_init(num_embeddings, embedding_dim, InitScheme::default())
+    }
+
+    /// Create an embedding layer with weights drawn by `init`
+    pub fn new_with_init(num_embeddings: usize, embedding_dim: usize, init: InitScheme) -> Self {
+        Self::new_with_rng(num_embeddings, embedding_dim, init, &mut rand::thread_rng())
+    }
 
+    /// Create an embedding layer with weights drawn by `init` from `rng`
+    pub fn new_with_rng<R: Rng + ?Sized>(
+        num_embeddings: usize,
+        embedding_dim: usize,
+        init: InitScheme,
+        rng: &mut R,
+    ) -> Self {
         Self {
-            weight,
+            weight: init.init_with_rng(num_embeddings, embedding_dim, rng),
             num_embeddings,
             embedding_dim,
         }
@@ -74,6 +87,22 @@ impl Embedding {
 
         Ok(Tensor::from_vec(output_data, output_shape)?)
     }
+
+    /// Embedding weight matrix
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        vec![&self.weight]
+    }
+}
+
+impl Module for Embedding {
+    /// Look up embeddings; `x` holds token indices
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        Embedding::forward(self, x)
+    }
+
+    fn parameters(&self) -> Vec<&Tensor> {
+        Embedding::parameters(self)
+    }
 }
 
 #[cfg(test)]
@@ -88,6 +117,13 @@ mod tests {
         assert_eq!(output.shape(), &[2, 2, 32]);
     }
 
+    #[test]
+    fn test_embedding_with_init() {
+        let embedding = Embedding::new_with_init(100, 16, InitScheme::Normal(0.0));
+        assert_eq!(embedding.weight.shape(), &[100, 16]);
+        assert_eq!(embedding.weight.sum_all(), 0.0);
+    }
+
     #[test]
     fn test_embedding_1d() {
         let embedding = Embedding::new(100, 64);
@@ -0,0 +1,90 @@
+//! Weight initialization schemes
+
+use rand::Rng;
+use rustml_core::Tensor;
+
+/// Standard deviation GPT-2 initializes its weights with
+pub const GPT2_INIT_STD: f32 = 0.02;
+
+/// How to draw the initial values of a weight matrix
+///
+/// For a `[rows, cols]` weight, `fan_in` is `cols` and `fan_out` is `rows`
+/// (the PyTorch convention, matching `Linear`'s `[out_features, in_features]`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitScheme {
+    /// Normal distribution with mean 0 and the given standard deviation
+    Normal(f32),
+    /// Uniform on `[-a, a]` with `a = sqrt(6 / (fan_in + fan_out))`
+    XavierUniform,
+    /// Normal with standard deviation `sqrt(2 / fan_in)`, suited to ReLU
+    KaimingNormal,
+}
+
+impl Default for InitScheme {
+    fn default() -> Self {
+        Self::Normal(GPT2_INIT_STD)
+    }
+}
+
+impl InitScheme {
+    /// Create a `[rows, cols]` weight matrix
+    pub fn init(&self, rows: usize, cols: usize) -> Tensor {
+        self.init_with_rng(rows, cols, &mut rand::thread_rng())
+    }
+
+    /// Create a `[rows, cols]` weight matrix drawing from `rng`
+    pub fn init_with_rng<R: Rng + ?Sized>(&self, rows: usize, cols: usize, rng: &mut R) -> Tensor {
+        let (fan_in, fan_out) = (cols as f32, rows as f32);
+        match *self {
+            Self::Normal(std) => Tensor::randn_with_rng(vec![rows, cols], rng).mul_scalar(std),
+            Self::XavierUniform => {
+                let bound = (6.0 / (fan_in + fan_out)).sqrt();
+                Tensor::rand_with_rng(vec![rows, cols], rng)
+                    .mul_scalar(2.0 * bound)
+                    .add_scalar(-bound)
+            }
+            Self::KaimingNormal => {
+                Tensor::randn_with_rng(vec![rows, cols], rng).mul_scalar((2.0 / fan_in).sqrt())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variance(t: &Tensor) -> f32 {
+        let mean = t.mean_all();
+        t.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / t.numel() as f32
+    }
+
+    #[test]
+    fn test_xavier_uniform_variance() {
+        let (rows, cols) = (256, 512);
+        let w = InitScheme::XavierUniform.init(rows, cols);
+        assert_eq!(w.shape(), &[rows, cols]);
+
+        let expected = 2.0 / (rows + cols) as f32;
+        let bound = (6.0 / (rows + cols) as f32).sqrt();
+        assert!((variance(&w) - expected).abs() / expected < 0.05);
+        assert!(w.iter().all(|x| x.abs() <= bound));
+    }
+
+    #[test]
+    fn test_kaiming_normal_variance() {
+        let w = InitScheme::KaimingNormal.init(256, 512);
+        let expected = 2.0 / 512.0;
+        assert!((variance(&w) - expected).abs() / expected < 0.05);
+    }
+
+    #[test]
+    fn test_default_is_gpt2_normal() {
+        assert_eq!(InitScheme::default(), InitScheme::Normal(GPT2_INIT_STD));
+
+        let w = InitScheme::default().init(256, 512);
+        let std = variance(&w).sqrt();
+        assert!((std - GPT2_INIT_STD).abs() < 0.001);
+        assert!(w.mean_all().abs() < 0.001);
+    }
+}
@@ -1,6 +1,6 @@
 //! Layer normalization implementation
 
-use crate::NnResult;
+use crate::{Module, NnResult};
 use rustml_core::Tensor;
 
 /// Layer normalization
@@ -39,16 +39,24 @@ impl LayerNorm {
     }
 
     /// Create from existing weights
+    ///
+    /// `weight` and `bias` must be 1D of the same length, which becomes the
+    /// normalized dimension. Checking here catches mis-mapped weights at
+    /// load time rather than as a broadcast error in `forward`.
     pub fn from_weights(weight: Tensor, bias: Tensor, eps: f32) -> NnResult<Self> {
-        if weight.shape() != bias.shape() {
-            return Err(crate::NnError::ShapeMismatch(
-                "Weight and bias shapes must match".into(),
-            ));
+        if weight.ndim() != 1 || bias.ndim() != 1 {
+            return Err(crate::NnError::ShapeMismatch(format!(
+                "LayerNorm weight and bias must be 1D, got weight {:?} and bias {:?}",
+                weight.shape(),
+                bias.shape()
+            )));
         }
-        if weight.ndim() != 1 {
-            return Err(crate::NnError::InvalidConfig(
-                "Weight must be 1D".into(),
-            ));
+        if weight.shape() != bias.shape() {
+            return Err(crate::NnError::ShapeMismatch(format!(
+                "LayerNorm weight has length {} but bias has length {}",
+                weight.shape()[0],
+                bias.shape()[0]
+            )));
         }
 
         Ok(Self {
@@ -64,6 +72,50 @@ impl LayerNorm {
     /// Input shape: [..., normalized_shape]
     /// Output shape: [..., normalized_shape]
     pub fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        self.check_input(x)?;
+
+        // Compute mean and variance along last dimension
+        let mean = x.mean(-1)?;
+        let var = x.var(-1)?;
+
+        self.normalize(x, &mean, &var)
+    }
+
+    /// Forward pass with mean and variance computed only over unmasked
+    /// feature positions
+    ///
+    /// `mask` follows `Tensor::masked_fill`: non-zero entries are excluded
+    /// from the statistics. It must broadcast to `x` (e.g. `[normalized_shape]`
+    /// for one mask shared by every row). Masked positions are still
+    /// normalized with the statistics of the rest. Every row needs at least
+    /// one unmasked position.
+    pub fn forward_masked(&self, x: &Tensor, mask: &Tensor) -> NnResult<Tensor> {
+        self.check_input(x)?;
+
+        // 1 where a position counts towards the statistics
+        let keep = Tensor::ones(x.shape().to_vec()).masked_fill(mask, 0.0)?;
+        if keep.shape() != x.shape() {
+            return Err(crate::NnError::ShapeMismatch(format!(
+                "Mask {:?} does not broadcast to input {:?}",
+                mask.shape(),
+                x.shape()
+            )));
+        }
+        let count = keep.sum(-1)?;
+        if count.iter().any(|c| c == 0.0) {
+            return Err(crate::NnError::InvalidConfig(
+                "Mask excludes every position of a row".into(),
+            ));
+        }
+
+        let mean = x.mul(&keep)?.sum(-1)?.div(&count)?;
+        let x_centered = x.sub(&mean.unsqueeze(-1)?.broadcast_to(&x.shape().into())?)?;
+        let var = x_centered.mul(&x_centered)?.mul(&keep)?.sum(-1)?.div(&count)?;
+
+        self.normalize(x, &mean, &var)
+    }
+
+    fn check_input(&self, x: &Tensor) -> NnResult<()> {
         let shape = x.shape();
         if shape.is_empty() || shape[shape.len() - 1] != self.normalized_shape {
             return Err(crate::NnError::ShapeMismatch(format!(
@@ -71,11 +123,11 @@ impl LayerNorm {
                 self.normalized_shape, shape
             )));
         }
+        Ok(())
+    }
 
-        // Compute mean and variance along last dimension
-        let mean = x.mean(-1)?;
-        let var = x.var(-1)?;
-
+    /// Normalize `x` with per-row `mean` and `var`, then scale and shift
+    fn normalize(&self, x: &Tensor, mean: &Tensor, var: &Tensor) -> NnResult<Tensor> {
         // Broadcast mean and var back to original shape
         let mean_broadcast = mean.unsqueeze(-1)?.broadcast_to(&x.shape().into())?;
         let var_broadcast = var.unsqueeze(-1)?.broadcast_to(&x.shape().into())?;
@@ -91,6 +143,21 @@ impl LayerNorm {
 
         Ok(output)
     }
+
+    /// Scale (gamma) and shift (beta)
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        vec![&self.weight, &self.bias]
+    }
+}
+
+impl Module for LayerNorm {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        LayerNorm::forward(self, x)
+    }
+
+    fn parameters(&self) -> Vec<&Tensor> {
+        LayerNorm::parameters(self)
+    }
 }
 
 #[cfg(test)]
@@ -118,4 +185,71 @@ mod tests {
         assert!(mean.get(&[0]).unwrap().abs() < 1e-5);
         assert!((var.get(&[0]).unwrap() - 1.0).abs() < 0.1);
     }
+
+    #[test]
+    fn test_from_weights_sets_normalized_shape() {
+        let ln = LayerNorm::from_weights(Tensor::ones(vec![8]), Tensor::zeros(vec![8]), 1e-5).unwrap();
+        assert_eq!(ln.normalized_shape, 8);
+    }
+
+    #[test]
+    fn test_from_weights_rejects_mismatched_lengths() {
+        let result = LayerNorm::from_weights(Tensor::ones(vec![8]), Tensor::zeros(vec![7]), 1e-5);
+        match result {
+            Err(crate::NnError::ShapeMismatch(msg)) => {
+                assert!(msg.contains("length 8") && msg.contains("length 7"), "{}", msg);
+            }
+            other => panic!("expected ShapeMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_weights_rejects_non_1d() {
+        let result = LayerNorm::from_weights(Tensor::ones(vec![2, 4]), Tensor::zeros(vec![2, 4]), 1e-5);
+        assert!(matches!(result, Err(crate::NnError::ShapeMismatch(_))));
+
+        let result = LayerNorm::from_weights(Tensor::ones(vec![8]), Tensor::zeros(vec![1, 8]), 1e-5);
+        assert!(matches!(result, Err(crate::NnError::ShapeMismatch(_))));
+    }
+
+    #[test]
+    fn test_forward_masked_excludes_positions() {
+        let ln = LayerNorm::with_eps(4, 0.0);
+        let x = Tensor::from_vec(vec![1.0, 2.0, 3.0, 100.0], vec![1, 4]).unwrap();
+        let mask = Tensor::from_vec(vec![0.0, 0.0, 0.0, 1.0], vec![4]).unwrap();
+
+        let masked = ln.forward_masked(&x, &mask).unwrap();
+        let unmasked = ln.forward(&x).unwrap();
+
+        // Statistics of [1, 2, 3]: mean 2, variance 2/3
+        let std = (2.0f32 / 3.0).sqrt();
+        let expected = Tensor::from_vec(
+            vec![-1.0 / std, 0.0, 1.0 / std, 98.0 / std],
+            vec![1, 4],
+        )
+        .unwrap();
+        assert!(masked.allclose(&expected, 1e-4, 1e-4));
+
+        // The outlier dominates the unmasked statistics
+        assert!(unmasked.get(&[0, 1]).unwrap() < -0.5);
+        assert!(!masked.approx_eq(&unmasked, 0.1));
+    }
+
+    #[test]
+    fn test_forward_masked_without_mask_matches_forward() {
+        let ln = LayerNorm::new(8);
+        let x = Tensor::randn(vec![2, 3, 8]);
+        let none = Tensor::zeros(vec![8]);
+
+        let masked = ln.forward_masked(&x, &none).unwrap();
+        assert!(masked.approx_eq(&ln.forward(&x).unwrap(), 1e-4));
+    }
+
+    #[test]
+    fn test_forward_masked_rejects_fully_masked_row() {
+        let ln = LayerNorm::new(2);
+        let x = Tensor::randn(vec![2, 2]);
+        let mask = Tensor::from_vec(vec![0.0, 1.0, 1.0, 1.0], vec![2, 2]).unwrap();
+        assert!(ln.forward_masked(&x, &mask).is_err());
+    }
 }
@@ -4,9 +4,12 @@
 //!
 //! This crate provides building blocks for neural networks including:
 //! - Linear layers
+//! - Weight initialization schemes (GPT-2 normal, Xavier, Kaiming)
 //! - Embedding layers
 //! - Layer normalization
 //! - Attention mechanisms (including causal self-attention for GPT)
+//! - A `Module` trait and `Sequential` container for composing layers
+//! - SGD and Adam optimizers for gradients computed outside the crate
 //!
 //! ## Example
 //!
@@ -20,13 +23,19 @@
 
 pub mod attention;
 pub mod embedding;
+pub mod init;
 pub mod layer_norm;
 pub mod linear;
+pub mod module;
+pub mod optim;
 
 pub use attention::{Attention, CausalSelfAttention, MultiHeadAttention};
 pub use embedding::Embedding;
+pub use init::{InitScheme, GPT2_INIT_STD};
 pub use layer_norm::LayerNorm;
 pub use linear::Linear;
+pub use module::{Gelu, Module, Relu, Sequential};
+pub use optim::{Adam, Optimizer, Sgd};
 
 use rustml_core::TensorError;
 use thiserror::Error;
@@ -1,6 +1,7 @@
 //! Linear layer implementation
 
-use crate::NnResult;
+use crate::{InitScheme, Module, NnResult};
+use rand::Rng;
 use rustml_core::Tensor;
 
 /// A fully connected linear layer: y = xW^T + b
@@ -17,16 +18,29 @@ pub struct Linear {
 }
 
 impl Linear {
-    /// Create a new linear layer with random initialization
+    /// Create a new linear layer with GPT-2 style `N(0, 0.02)` weights and
+    /// zero bias
     pub fn new(in_features: usize, out_features: usize) -> Self {
-        // Xavier/Glorot initialization
-        let scale = (2.0 / (in_features + out_features) as f32).sqrt();
-        let weight = Tensor::randn(vec![out_features, in_features]).mul_scalar(scale);
-        let bias = Some(Tensor::zeros(vec![out_features]));
+        Self::new_with_init(in_features, out_features, InitScheme::default())
+    }
+
+    /// Create a linear layer with weights drawn by `init` and zero bias
+    pub fn new_with_init(in_features: usize, out_features: usize, init: InitScheme) -> Self {
+        Self::new_with_rng(in_features, out_features, init, &mut rand::thread_rng())
+    }
 
+    /// Create a linear layer with weights drawn by `init` from `rng`
+    ///
+    /// Pass a seeded RNG to get the same weights every run.
+    pub fn new_with_rng<R: Rng + ?Sized>(
+        in_features: usize,
+        out_features: usize,
+        init: InitScheme,
+        rng: &mut R,
+    ) -> Self {
         Self {
-            weight,
-            bias,
+            weight: init.init_with_rng(out_features, in_features, rng),
+            bias: Some(Tensor::zeros(vec![out_features])),
             in_features,
             out_features,
         }
@@ -34,14 +48,9 @@ impl Linear {
 
     /// Create a linear layer without bias
     pub fn new_no_bias(in_features: usize, out_features: usize) -> Self {
-        let scale = (2.0 / (in_features + out_features) as f32).sqrt();
-        let weight = Tensor::randn(vec![out_features, in_features]).mul_scalar(scale);
-
         Self {
-            weight,
             bias: None,
-            in_features,
-            out_features,
+            ..Self::new(in_features, out_features)
         }
     }
 
@@ -91,6 +100,23 @@ impl Linear {
 
         Ok(out)
     }
+
+    /// Weight and, if present, bias
+    pub fn parameters(&self) -> Vec<&Tensor> {
+        let mut params = vec![&self.weight];
+        params.extend(self.bias.as_ref());
+        params
+    }
+}
+
+impl Module for Linear {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        Linear::forward(self, x)
+    }
+
+    fn parameters(&self) -> Vec<&Tensor> {
+        Linear::parameters(self)
+    }
 }
 
 #[cfg(test)]
@@ -105,6 +131,18 @@ mod tests {
         assert_eq!(y.shape(), &[2, 3, 8]);
     }
 
+    #[test]
+    fn test_linear_init_schemes() {
+        let linear = Linear::new(64, 32);
+        assert_eq!(linear.weight.shape(), &[32, 64]);
+        assert!(linear.weight.iter().all(|w| w.abs() < 0.02 * 6.0));
+
+        let xavier = Linear::new_with_init(64, 32, InitScheme::XavierUniform);
+        let bound = (6.0f32 / 96.0).sqrt();
+        assert!(xavier.weight.iter().all(|w| w.abs() <= bound));
+        assert_eq!(xavier.bias.unwrap().sum_all(), 0.0);
+    }
+
     #[test]
     fn test_linear_no_bias() {
         let linear = Linear::new_no_bias(4, 8);
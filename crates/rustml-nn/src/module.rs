@@ -0,0 +1,157 @@
+//! Shared module abstraction and sequential composition
+
+use crate::NnResult;
+use rustml_core::Tensor;
+
+/// A layer that maps one tensor to another
+pub trait Module {
+    /// Forward pass
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor>;
+
+    /// Learnable tensors of the module (none by default)
+    fn parameters(&self) -> Vec<&Tensor> {
+        Vec::new()
+    }
+}
+
+/// GELU activation as a module
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gelu;
+
+impl Module for Gelu {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        Ok(x.gelu())
+    }
+}
+
+/// ReLU activation as a module
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Relu;
+
+impl Module for Relu {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        Ok(x.relu())
+    }
+}
+
+/// Modules applied one after another, each to the previous one's output
+#[derive(Default)]
+pub struct Sequential {
+    layers: Vec<Box<dyn Module>>,
+}
+
+impl Sequential {
+    /// Create an empty container
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a module
+    pub fn push(&mut self, module: impl Module + 'static) -> &mut Self {
+        self.layers.push(Box::new(module));
+        self
+    }
+
+    /// Number of modules
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Check if there are no modules
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Forward pass through every module in order
+    ///
+    /// An empty container returns its input unchanged.
+    pub fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        let mut out = x.clone();
+        for layer in &self.layers {
+            out = layer.forward(&out)?;
+        }
+        Ok(out)
+    }
+}
+
+impl Module for Sequential {
+    fn forward(&self, x: &Tensor) -> NnResult<Tensor> {
+        Sequential::forward(self, x)
+    }
+
+    fn parameters(&self) -> Vec<&Tensor> {
+        self.layers.iter().flat_map(|layer| layer.parameters()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CausalSelfAttention, Embedding, LayerNorm, Linear, MultiHeadAttention};
+    use crate::attention::MultiHeadAttentionConfig;
+
+    #[test]
+    fn test_sequential_matches_manual_composition() {
+        let fc1 = Linear::new(4, 16);
+        let fc2 = Linear::new(16, 8);
+
+        let mut mlp = Sequential::new();
+        mlp.push(fc1.clone()).push(Gelu).push(fc2.clone());
+        assert_eq!(mlp.len(), 3);
+
+        let x = Tensor::randn(vec![2, 3, 4]);
+        let y = mlp.forward(&x).unwrap();
+        assert_eq!(y.shape(), &[2, 3, 8]);
+
+        let expected = fc2.forward(&fc1.forward(&x).unwrap().gelu()).unwrap();
+        assert!(y.approx_eq(&expected, 1e-6));
+    }
+
+    #[test]
+    fn test_sequential_parameters() {
+        let mut mlp = Sequential::new();
+        mlp.push(Linear::new(4, 16)).push(Gelu).push(Linear::new_no_bias(16, 8));
+
+        let shapes: Vec<_> = mlp.parameters().iter().map(|p| p.shape().to_vec()).collect();
+        assert_eq!(shapes, vec![vec![16, 4], vec![16], vec![8, 16]]);
+    }
+
+    #[test]
+    fn test_empty_sequential_is_identity() {
+        let empty = Sequential::new();
+        assert!(empty.is_empty());
+
+        let x = Tensor::randn(vec![2, 4]);
+        assert!(empty.forward(&x).unwrap().approx_eq(&x, 0.0));
+    }
+
+    #[test]
+    fn test_sequential_propagates_errors() {
+        let mut net = Sequential::new();
+        net.push(Linear::new(4, 8)).push(Linear::new(4, 2));
+
+        assert!(net.forward(&Tensor::randn(vec![1, 4])).is_err());
+    }
+
+    #[test]
+    fn test_boxed_modules_apply_in_sequence() {
+        let config = MultiHeadAttentionConfig { d_model: 16, n_heads: 4, ..Default::default() };
+        let layers: Vec<Box<dyn Module>> = vec![
+            Box::new(Embedding::new(50, 16)),
+            Box::new(CausalSelfAttention::new(16, 4)),
+            Box::new(MultiHeadAttention::new(config)),
+            Box::new(LayerNorm::new(16)),
+            Box::new(Linear::new(16, 32)),
+            Box::new(Relu),
+        ];
+
+        let tokens = Tensor::from_vec(vec![1.0, 7.0, 3.0, 42.0, 0.0], vec![1, 5]).unwrap();
+        let mut x = tokens;
+        for layer in &layers {
+            x = layer.forward(&x).unwrap();
+        }
+
+        assert_eq!(x.shape(), &[1, 5, 32]);
+        assert!(x.iter().all(|v| v >= 0.0));
+    }
+}
@@ -0,0 +1,255 @@
+//! Optimizers that update parameters in place from given gradients
+//!
+//! There is no autograd yet, so gradients come from the caller (computed by
+//! hand or by finite differences). Optimizer state is created on the first
+//! step, one entry per parameter, so the same parameters must be passed in
+//! the same order every step.
+
+use crate::{NnError, NnResult};
+use rustml_core::Tensor;
+
+/// Applies one update step to a set of parameters
+pub trait Optimizer {
+    /// Update each of `params` using the gradient at the same position
+    fn step(&mut self, params: &mut [Tensor], grads: &[Tensor]) -> NnResult<()>;
+}
+
+/// Check that gradients pair up with parameters and state from earlier steps
+fn check_step(params: &[Tensor], grads: &[Tensor], state_len: Option<usize>) -> NnResult<()> {
+    if params.len() != grads.len() {
+        return Err(NnError::InvalidConfig(format!(
+            "Got {} gradients for {} parameters",
+            grads.len(),
+            params.len()
+        )));
+    }
+    if let Some(state_len) = state_len.filter(|&n| n != params.len()) {
+        return Err(NnError::InvalidConfig(format!(
+            "Optimizer was stepped with {} parameters before, got {}",
+            state_len,
+            params.len()
+        )));
+    }
+    for (i, (param, grad)) in params.iter().zip(grads).enumerate() {
+        if param.shape() != grad.shape() {
+            return Err(NnError::ShapeMismatch(format!(
+                "Gradient {} has shape {:?}, parameter has {:?}",
+                i,
+                grad.shape(),
+                param.shape()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Stochastic gradient descent, optionally with momentum
+///
+/// With momentum `μ`: `v = μ·v + g`, `p = p - lr·v` (PyTorch convention).
+#[derive(Debug, Clone)]
+pub struct Sgd {
+    /// Learning rate
+    pub lr: f32,
+    /// Momentum factor (0.0 = plain SGD)
+    pub momentum: f32,
+    velocity: Vec<Tensor>,
+}
+
+impl Sgd {
+    /// Create plain SGD
+    pub fn new(lr: f32) -> Self {
+        Self::with_momentum(lr, 0.0)
+    }
+
+    /// Create SGD with momentum
+    pub fn with_momentum(lr: f32, momentum: f32) -> Self {
+        Self {
+            lr,
+            momentum,
+            velocity: Vec::new(),
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self, params: &mut [Tensor], grads: &[Tensor]) -> NnResult<()> {
+        let state_len = (!self.velocity.is_empty()).then_some(self.velocity.len());
+        check_step(params, grads, state_len)?;
+
+        if self.momentum == 0.0 {
+            for (param, grad) in params.iter_mut().zip(grads) {
+                *param = param.sub(&grad.mul_scalar(self.lr))?;
+            }
+            return Ok(());
+        }
+
+        if self.velocity.is_empty() {
+            self.velocity = grads.to_vec();
+        } else {
+            for (v, grad) in self.velocity.iter_mut().zip(grads) {
+                *v = v.mul_scalar(self.momentum).add(grad)?;
+            }
+        }
+        for (param, v) in params.iter_mut().zip(&self.velocity) {
+            *param = param.sub(&v.mul_scalar(self.lr))?;
+        }
+        Ok(())
+    }
+}
+
+/// Adam optimizer (Kingma & Ba, 2015) with bias-corrected moment estimates
+#[derive(Debug, Clone)]
+pub struct Adam {
+    /// Learning rate
+    pub lr: f32,
+    /// Decay rates of the first and second moment estimates
+    pub betas: (f32, f32),
+    /// Term added to the denominator for numerical stability
+    pub eps: f32,
+    m: Vec<Tensor>,
+    v: Vec<Tensor>,
+    t: i32,
+}
+
+impl Adam {
+    /// Create Adam with the usual defaults: betas (0.9, 0.999), eps 1e-8
+    pub fn new(lr: f32) -> Self {
+        Self::with_config(lr, (0.9, 0.999), 1e-8)
+    }
+
+    /// Create Adam with custom betas and eps
+    pub fn with_config(lr: f32, betas: (f32, f32), eps: f32) -> Self {
+        Self {
+            lr,
+            betas,
+            eps,
+            m: Vec::new(),
+            v: Vec::new(),
+            t: 0,
+        }
+    }
+
+    /// Number of steps taken
+    pub fn steps(&self) -> usize {
+        self.t as usize
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, params: &mut [Tensor], grads: &[Tensor]) -> NnResult<()> {
+        let state_len = (self.t > 0).then_some(self.m.len());
+        check_step(params, grads, state_len)?;
+
+        if self.t == 0 {
+            self.m = grads.iter().map(|g| Tensor::zeros(g.shape().to_vec())).collect();
+            self.v = self.m.clone();
+        }
+        self.t += 1;
+
+        let (beta1, beta2) = self.betas;
+        let bias_correction1 = 1.0 - beta1.powi(self.t);
+        let bias_correction2 = 1.0 - beta2.powi(self.t);
+
+        for (((param, grad), m), v) in params
+            .iter_mut()
+            .zip(grads)
+            .zip(&mut self.m)
+            .zip(&mut self.v)
+        {
+            *m = m.mul_scalar(beta1).add(&grad.mul_scalar(1.0 - beta1))?;
+            *v = v.mul_scalar(beta2).add(&grad.mul(grad)?.mul_scalar(1.0 - beta2))?;
+
+            let m_hat = m.div_scalar(bias_correction1);
+            let v_hat = v.div_scalar(bias_correction2);
+            let update = m_hat.div(&v_hat.sqrt().add_scalar(self.eps))?;
+            *param = param.sub(&update.mul_scalar(self.lr))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tensor(values: &[f32]) -> Tensor {
+        Tensor::from_vec(values.to_vec(), vec![values.len()]).unwrap()
+    }
+
+    #[test]
+    fn test_sgd_step() {
+        let mut params = vec![tensor(&[1.0, -2.0, 0.5])];
+        let grads = vec![tensor(&[0.5, 1.0, -4.0])];
+
+        Sgd::new(0.1).step(&mut params, &grads).unwrap();
+        assert!(params[0].approx_eq(&tensor(&[0.95, -2.1, 0.9]), 1e-6));
+    }
+
+    #[test]
+    fn test_sgd_momentum_accumulates() {
+        let mut params = vec![tensor(&[0.0])];
+        let grads = vec![tensor(&[1.0])];
+        let mut sgd = Sgd::with_momentum(0.1, 0.9);
+
+        sgd.step(&mut params, &grads).unwrap();
+        assert!(params[0].approx_eq(&tensor(&[-0.1]), 1e-6));
+
+        // v = 0.9 * 1 + 1 = 1.9
+        sgd.step(&mut params, &grads).unwrap();
+        assert!(params[0].approx_eq(&tensor(&[-0.29]), 1e-6));
+    }
+
+    #[test]
+    fn test_adam_first_step() {
+        let (lr, betas, eps) = (0.01, (0.9, 0.999), 1e-8);
+        let g = [0.5f32, -2.0, 1e-3];
+        let mut params = vec![tensor(&[1.0, 1.0, 1.0])];
+        let mut adam = Adam::with_config(lr, betas, eps);
+
+        adam.step(&mut params, &[tensor(&g)]).unwrap();
+        assert_eq!(adam.steps(), 1);
+
+        // m_hat = g, v_hat = g^2 after bias correction
+        let expected: Vec<f32> = g
+            .iter()
+            .map(|&g| {
+                let m_hat = (1.0 - betas.0) * g / (1.0 - betas.0);
+                let v_hat = (1.0 - betas.1) * g * g / (1.0 - betas.1);
+                1.0 - lr * m_hat / (v_hat.sqrt() + eps)
+            })
+            .collect();
+        assert!(params[0].approx_eq(&tensor(&expected), 1e-6));
+        // The first step moves every parameter by about lr, whatever the gradient scale
+        assert!(params[0].approx_eq(&tensor(&[0.99, 1.01, 0.99]), 1e-4));
+    }
+
+    #[test]
+    fn test_adam_minimizes_quadratic() {
+        // f(x) = (x - 3)^2, f'(x) = 2(x - 3)
+        let mut params = vec![tensor(&[0.0])];
+        let mut adam = Adam::new(0.1);
+        for _ in 0..500 {
+            let grad = params[0].add_scalar(-3.0).mul_scalar(2.0);
+            adam.step(&mut params, &[grad]).unwrap();
+        }
+        assert!(params[0].approx_eq(&tensor(&[3.0]), 1e-2));
+    }
+
+    #[test]
+    fn test_step_rejects_mismatched_gradients() {
+        let mut params = vec![tensor(&[1.0, 2.0])];
+
+        let result = Sgd::new(0.1).step(&mut params, &[]);
+        assert!(matches!(result, Err(NnError::InvalidConfig(_))));
+
+        let result = Adam::new(0.1).step(&mut params, &[tensor(&[1.0])]);
+        assert!(matches!(result, Err(NnError::ShapeMismatch(_))));
+
+        // State is tied to the parameters of the first step
+        let mut adam = Adam::new(0.1);
+        adam.step(&mut params, &[tensor(&[1.0, 1.0])]).unwrap();
+        let mut more = vec![tensor(&[1.0, 2.0]), tensor(&[3.0])];
+        let result = adam.step(&mut more, &[tensor(&[1.0, 1.0]), tensor(&[1.0])]);
+        assert!(matches!(result, Err(NnError::InvalidConfig(_))));
+    }
+}
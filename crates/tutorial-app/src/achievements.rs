@@ -0,0 +1,125 @@
+//! Data-driven achievement definitions
+
+use crate::data::Module;
+use crate::state::{Achievement, User};
+
+/// State an achievement condition is evaluated against
+pub struct AchievementContext<'a> {
+    pub user: &'a User,
+    pub course: &'a [Module],
+    /// Whether the lesson just completed had no mistakes
+    pub perfect_lesson: bool,
+}
+
+/// An achievement and the condition that unlocks it
+pub struct AchievementRule {
+    pub achievement: fn() -> Achievement,
+    pub condition: fn(&AchievementContext) -> bool,
+}
+
+/// All achievements, in the order they are checked
+pub const ACHIEVEMENTS: &[AchievementRule] = &[
+    AchievementRule {
+        achievement: Achievement::first_lesson,
+        condition: |ctx| !ctx.user.completed_lessons.is_empty(),
+    },
+    AchievementRule {
+        achievement: Achievement::perfect_lesson,
+        condition: |ctx| ctx.perfect_lesson,
+    },
+    AchievementRule {
+        achievement: Achievement::streak_7,
+        condition: |ctx| ctx.user.streak >= 7,
+    },
+    AchievementRule {
+        achievement: Achievement::level_5,
+        condition: |ctx| ctx.user.level >= 5,
+    },
+    AchievementRule {
+        achievement: Achievement::level_10,
+        condition: |ctx| ctx.user.level >= 10,
+    },
+    AchievementRule {
+        achievement: Achievement::ownership_complete,
+        condition: |ctx| module_completed(ctx.user, ctx.course, "ownership"),
+    },
+];
+
+/// Check if every lesson in a module is completed
+pub fn module_completed(user: &User, course: &[Module], module_id: &str) -> bool {
+    course
+        .iter()
+        .find(|m| m.id == module_id)
+        .is_some_and(|m| m.lessons.iter().all(|l| user.is_lesson_completed(&l.id)))
+}
+
+/// Achievements whose condition now holds but which the user hasn't unlocked yet
+pub fn newly_unlocked(ctx: &AchievementContext) -> Vec<Achievement> {
+    ACHIEVEMENTS
+        .iter()
+        .filter(|rule| (rule.condition)(ctx))
+        .map(|rule| (rule.achievement)())
+        .filter(|a| !ctx.user.has_achievement(&a.id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::get_rust_course;
+
+    fn ids(achievements: &[Achievement]) -> Vec<&str> {
+        achievements.iter().map(|a| a.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_streak_and_level_predicates() {
+        let course = get_rust_course();
+        let mut user = User { streak: 7, level: 5, ..Default::default() };
+
+        let ctx = AchievementContext { user: &user, course: &course, perfect_lesson: false };
+        assert_eq!(ids(&newly_unlocked(&ctx)), vec!["streak_7", "level_5"]);
+
+        user.streak = 6;
+        user.level = 10;
+        let ctx = AchievementContext { user: &user, course: &course, perfect_lesson: true };
+        assert_eq!(ids(&newly_unlocked(&ctx)), vec!["perfect", "level_5", "level_10"]);
+    }
+
+    #[test]
+    fn test_module_completion_predicate() {
+        let course = get_rust_course();
+        let ownership = course.iter().find(|m| m.id == "ownership").unwrap();
+        let mut user = User::default();
+
+        let (last, rest) = ownership.lessons.split_last().unwrap();
+        for lesson in rest {
+            user.complete_lesson(&lesson.id);
+        }
+        assert!(!module_completed(&user, &course, "ownership"));
+
+        user.complete_lesson(&last.id);
+        assert!(module_completed(&user, &course, "ownership"));
+        assert!(!module_completed(&user, &course, "missing"));
+
+        let ctx = AchievementContext { user: &user, course: &course, perfect_lesson: false };
+        assert_eq!(ids(&newly_unlocked(&ctx)), vec!["first_lesson", "ownership_complete"]);
+    }
+
+    #[test]
+    fn test_unlocked_achievements_do_not_refire() {
+        let course = get_rust_course();
+        let mut user = User::default();
+        user.complete_lesson("variables");
+
+        let ctx = AchievementContext { user: &user, course: &course, perfect_lesson: true };
+        let unlocked = newly_unlocked(&ctx);
+        assert_eq!(ids(&unlocked), vec!["first_lesson", "perfect"]);
+
+        for achievement in unlocked {
+            user.unlock_achievement(achievement);
+        }
+        let ctx = AchievementContext { user: &user, course: &course, perfect_lesson: true };
+        assert!(newly_unlocked(&ctx).is_empty());
+    }
+}
@@ -2,15 +2,20 @@
 
 use dioxus::prelude::*;
 use components::prelude::*;
+use crate::achievements::{newly_unlocked, AchievementContext};
 use crate::components::*;
-use crate::data::{get_rust_course, Module, Lesson};
-use crate::state::{User, LevelUpEvent, Achievement};
+use crate::data::{find_question, get_rust_course, Module, Lesson};
+use crate::state::{LevelUpEvent, Achievement, Mistake, MAX_HEARTS};
+use crate::storage;
+use crate::timer;
+use chrono::{Local, Utc};
+use std::time::Duration;
 
 /// Main app component
 #[component]
 pub fn TutorialApp() -> Element {
     // State
-    let mut user = use_signal(|| User::default());
+    let mut user = use_signal(storage::load_user);
     let mut current_module = use_signal(|| 0usize);
     let mut current_lesson = use_signal(|| Option::<usize>::None);
     let mut current_quiz = use_signal(|| 0usize);
@@ -18,11 +23,50 @@ pub fn TutorialApp() -> Element {
     let mut level_up_event = use_signal(|| Option::<LevelUpEvent>::None);
     let mut xp_gained = use_signal(|| Option::<u32>::None);
     let mut mistakes = use_signal(|| 0u32);
+    // Snapshot of the review queue while in review mode (empty when not reviewing)
+    let mut review_queue = use_signal(Vec::<Mistake>::new);
+    let mut review_idx = use_signal(|| 0usize);
+    let mut achievement_toasts = use_signal(Vec::<Achievement>::new);
     
     let course = get_rust_course();
     
+    // Persist progress whenever the user changes
+    use_effect(move || storage::save_user(&user.read()));
+    
+    // Regenerate hearts on mount and then once a minute
+    use_future(move || async move {
+        loop {
+            let now = Utc::now();
+            if user.peek().hearts_due(now) > 0 {
+                user.write().regen_heart_if_due(now);
+            }
+            timer::sleep(Duration::from_secs(60)).await;
+        }
+    });
+    
+    // Unlock any newly-satisfied achievements and show a toast for each
+    let mut check_achievements = move |perfect_lesson: bool| {
+        let course = get_rust_course();
+        let unlocked = newly_unlocked(&AchievementContext {
+            user: &user.read(),
+            course: &course,
+            perfect_lesson,
+        });
+        
+        for achievement in unlocked {
+            user.write().unlock_achievement(achievement.clone());
+            achievement_toasts.write().push(achievement);
+            spawn(async move {
+                timer::sleep(Duration::from_secs(3)).await;
+                if !achievement_toasts.peek().is_empty() {
+                    achievement_toasts.write().remove(0);
+                }
+            });
+        }
+    };
+    
     // Handle quiz answer
-    let handle_answer = move |is_correct: bool| {
+    let mut handle_answer = move |is_correct: bool| {
         if is_correct {
             // Correct answer - award XP
             let xp = 10;
@@ -31,16 +75,18 @@ pub fn TutorialApp() -> Element {
             if let Some(event) = user.write().add_xp(xp) {
                 level_up_event.set(Some(event));
             }
+            check_achievements(false);
         } else {
             // Wrong answer - lose heart
-            mistakes.set(*mistakes.read() + 1);
+            *mistakes.write() += 1;
             user.write().lose_heart();
         }
     };
     
     // Complete lesson
-    let complete_lesson = move |lesson_id: String, xp_reward: u32| {
+    let mut complete_lesson = move |lesson_id: String, xp_reward: u32| {
         user.write().complete_lesson(&lesson_id);
+        user.write().record_activity(Local::now().date_naive());
         
         // Award XP
         let bonus = if *mistakes.read() == 0 { xp_reward / 2 } else { 0 }; // Perfect bonus
@@ -51,12 +97,7 @@ pub fn TutorialApp() -> Element {
         }
         
         // Check achievements
-        if user.read().completed_lessons.len() == 1 {
-            user.write().unlock_achievement(Achievement::first_lesson());
-        }
-        if *mistakes.read() == 0 {
-            user.write().unlock_achievement(Achievement::perfect_lesson());
-        }
+        check_achievements(*mistakes.read() == 0);
         
         // Reset and go back to path
         mistakes.set(0);
@@ -75,7 +116,7 @@ pub fn TutorialApp() -> Element {
                 }
                 
                 div { class: "user-stats",
-                    Hearts { count: user.read().hearts, max: 5 }
+                    Hearts { count: user.read().hearts, max: MAX_HEARTS }
                     Streak { days: user.read().streak }
                     Gems { count: user.read().gems }
                 }
@@ -95,11 +136,96 @@ pub fn TutorialApp() -> Element {
             
             // Main content
             main { class: "app-main",
-                if current_lesson.read().is_none() {
+                if !review_queue.read().is_empty() {
+                    // Review view
+                    {
+                        let total = review_queue.read().len();
+                        let idx = (*review_idx.read()).min(total - 1);
+                        let entry = review_queue.read()[idx].clone();
+                        let question = find_question(&course, &entry.lesson_id, &entry.question_id).cloned();
+                        let Mistake { lesson_id, question_id, .. } = entry;
+                        let quiz_key = format!("{lesson_id}/{question_id}");
+                        
+                        rsx! {
+                            div { class: "lesson-view",
+                                div { class: "lesson-header",
+                                    button {
+                                        class: "back-btn",
+                                        onclick: move |_| {
+                                            review_queue.set(vec![]);
+                                            review_idx.set(0);
+                                        },
+                                        Icon { name: IconName::ArrowLeft }
+                                    }
+                                    
+                                    div { class: "lesson-progress",
+                                        Progress {
+                                            value: (idx + 1) as f32,
+                                            max: total as f32,
+                                            variant: Variant::Primary,
+                                        }
+                                    }
+                                }
+                                
+                                h2 { class: "lesson-title", "Review" }
+                                
+                                div { class: "quiz-section",
+                                    if let Some(question) = question {
+                                        Quiz {
+                                            key: "{quiz_key}",
+                                            question: question,
+                                            on_answer: move |is_correct| {
+                                                if is_correct {
+                                                    user.write().mistake_log.resolve(&lesson_id, &question_id);
+                                                }
+                                            },
+                                        }
+                                    }
+                                    
+                                    Button {
+                                        variant: Variant::Secondary,
+                                        onclick: move |_| {
+                                            if idx + 1 < total {
+                                                review_idx.set(idx + 1);
+                                            } else {
+                                                review_queue.set(vec![]);
+                                                review_idx.set(0);
+                                            }
+                                        },
+                                        if idx + 1 < total {
+                                            "Next Question"
+                                        } else {
+                                            "Finish Review"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                } else if current_lesson.read().is_none() {
                     // Learning path view
                     div { class: "learning-path",
                         h2 { "Your Learning Path" }
                         
+                        if !user.read().mistake_log.is_empty() {
+                            div { class: "review-banner",
+                                span { "{user.read().mistake_log.len()} question(s) to review" }
+                                Button {
+                                    variant: Variant::Primary,
+                                    onclick: move |_| {
+                                        let course = get_rust_course();
+                                        let queue: Vec<Mistake> = user.read().mistake_log.review_queue()
+                                            .into_iter()
+                                            .filter(|m| find_question(&course, &m.lesson_id, &m.question_id).is_some())
+                                            .collect();
+                                        review_idx.set(0);
+                                        review_queue.set(queue);
+                                    },
+                                    "Review"
+                                }
+                            }
+                        }
+                        
                         div { class: "modules-list",
                             for (mod_idx, module) in course.iter().enumerate() {
                                 div { class: "module-section",
@@ -120,14 +246,17 @@ pub fn TutorialApp() -> Element {
                                         for (lesson_idx, lesson) in module.lessons.iter().enumerate() {
                                             LessonNode {
                                                 title: lesson.title.clone(),
-                                                icon: if user.read().is_lesson_completed(&lesson.id) { "✓" } else { &module.icon }.to_string(),
+                                                icon: if user.read().is_lesson_completed(&lesson.id) { "✓".to_string() } else { module.icon.clone() },
                                                 completed: user.read().is_lesson_completed(&lesson.id),
                                                 locked: module.required_level > user.read().level,
                                                 current: mod_idx == *current_module.read() && !user.read().is_lesson_completed(&lesson.id),
-                                                on_click: move |_| {
-                                                    if module.required_level <= user.read().level {
-                                                        current_module.set(mod_idx);
-                                                        current_lesson.set(Some(lesson_idx));
+                                                on_click: {
+                                                    let required_level = module.required_level;
+                                                    move |_| {
+                                                        if required_level <= user.read().level {
+                                                            current_module.set(mod_idx);
+                                                            current_lesson.set(Some(lesson_idx));
+                                                        }
                                                     }
                                                 },
                                             }
@@ -168,7 +297,7 @@ pub fn TutorialApp() -> Element {
                                         }
                                     }
                                     
-                                    Hearts { count: user.read().hearts, max: 5 }
+                                    Hearts { count: user.read().hearts, max: MAX_HEARTS }
                                 }
                                 
                                 h2 { class: "lesson-title", "{lesson.title}" }
@@ -197,12 +326,21 @@ pub fn TutorialApp() -> Element {
                                             
                                             if quiz_idx < lesson.quiz.len() {
                                                 let question = lesson.quiz[quiz_idx].clone();
+                                                let question_id = question.id().to_string();
+                                                let quiz_len = lesson.quiz.len();
+                                                let lesson_id = lesson.id.clone();
+                                                let missed_lesson_id = lesson.id.clone();
+                                                let xp_reward = lesson.xp_reward;
                                                 
                                                 rsx! {
                                                     Quiz {
+                                                        key: "{quiz_idx}",
                                                         question: question,
                                                         on_answer: move |is_correct| {
                                                             handle_answer(is_correct);
+                                                            if !is_correct {
+                                                                user.write().mistake_log.record(&missed_lesson_id, &question_id, Utc::now());
+                                                            }
                                                             
                                                             // Move to next question after delay
                                                             // In real app, use timeout
@@ -213,14 +351,14 @@ pub fn TutorialApp() -> Element {
                                                         variant: Variant::Secondary,
                                                         onclick: move |_| {
                                                             let next = quiz_idx + 1;
-                                                            if next < lesson.quiz.len() {
+                                                            if next < quiz_len {
                                                                 current_quiz.set(next);
                                                             } else {
                                                                 // Lesson complete
-                                                                complete_lesson(lesson.id.clone(), lesson.xp_reward);
+                                                                complete_lesson(lesson_id.clone(), xp_reward);
                                                             }
                                                         },
-                                                        if quiz_idx + 1 < lesson.quiz.len() {
+                                                        if quiz_idx + 1 < quiz_len {
                                                             "Next Question"
                                                         } else {
                                                             "Complete Lesson"
@@ -244,6 +382,16 @@ pub fn TutorialApp() -> Element {
                 XpGained { amount: xp, visible: true }
             }
             
+            // Achievement toasts
+            for achievement in achievement_toasts.read().iter() {
+                AchievementToast {
+                    key: "{achievement.id}",
+                    name: achievement.name.clone(),
+                    icon: achievement.icon.clone(),
+                    description: achievement.description.clone(),
+                }
+            }
+            
             // Level up modal
             if let Some(event) = level_up_event.read().clone() {
                 LevelUpModal {
@@ -3,6 +3,8 @@
 use dioxus::prelude::*;
 use components::prelude::*;
 use crate::data::{ContentBlock, QuizQuestion};
+use crate::quiz::{check_code_answer, check_text_answer, shuffle_options};
+use chrono::Utc;
 
 /// XP gained animation
 #[derive(Props, Clone, PartialEq)]
@@ -14,7 +16,7 @@ pub struct XpGainedProps {
 #[component]
 pub fn XpGained(props: XpGainedProps) -> Element {
     if !props.visible {
-        return None;
+        return rsx! {};
     }
     
     rsx! {
@@ -189,10 +191,16 @@ pub fn Quiz(props: QuizProps) -> Element {
     let mut selected = use_signal(|| Option::<usize>::None);
     let mut answered = use_signal(|| false);
     let mut is_correct = use_signal(|| false);
+    let mut text_answer = use_signal(|| match &props.question {
+        QuizQuestion::CodeComplete { code_template, .. } => code_template.clone(),
+        _ => String::new(),
+    });
+    // New seed per mount so every attempt sees a fresh option order
+    let shuffle_seed = use_hook(|| Utc::now().timestamp_nanos_opt().unwrap_or_default() as u64);
     
     match &props.question {
         QuizQuestion::MultipleChoice { question, options, correct_index, explanation, .. } => {
-            let correct = *correct_index;
+            let (options, correct) = shuffle_options(options, *correct_index, shuffle_seed);
             
             rsx! {
                 div { class: "quiz-container",
@@ -316,7 +324,116 @@ pub fn Quiz(props: QuizProps) -> Element {
                 }
             }
         }
-        _ => rsx! { div { "Quiz type not implemented yet" } }
+        QuizQuestion::FillInBlank { question, blank_text, answer, explanation, .. } => {
+            let expected = answer.clone();
+            
+            rsx! {
+                div { class: "quiz-container",
+                    h3 { class: "quiz-question", "{question}" }
+                    p { class: "quiz-statement", "{blank_text}" }
+                    
+                    input {
+                        class: "quiz-input",
+                        class: if *answered.read() && *is_correct.read() { "correct" } else { "" },
+                        class: if *answered.read() && !*is_correct.read() { "incorrect" } else { "" },
+                        r#type: "text",
+                        placeholder: "Type your answer",
+                        value: "{text_answer}",
+                        disabled: *answered.read(),
+                        oninput: move |evt| text_answer.set(evt.value()),
+                    }
+                    
+                    if !*answered.read() && !text_answer.read().trim().is_empty() {
+                        Button {
+                            variant: Variant::Primary,
+                            full_width: true,
+                            onclick: move |_| {
+                                let is_right = check_text_answer(&text_answer.read(), &expected);
+                                is_correct.set(is_right);
+                                answered.set(true);
+                                props.on_answer.call(is_right);
+                            },
+                            "Check Answer"
+                        }
+                    }
+                    
+                    if *answered.read() {
+                        div { class: "quiz-feedback",
+                            class: if *is_correct.read() { "correct" } else { "incorrect" },
+                            
+                            if *is_correct.read() {
+                                div { class: "feedback-header correct",
+                                    Icon { name: IconName::Check }
+                                    span { "Correct!" }
+                                }
+                            } else {
+                                div { class: "feedback-header incorrect",
+                                    Icon { name: IconName::X }
+                                    span { "Not quite! The answer is \"{answer}\"." }
+                                }
+                            }
+                            
+                            p { class: "explanation", "{explanation}" }
+                        }
+                    }
+                }
+            }
+        }
+        QuizQuestion::CodeComplete { question, correct_answer, explanation, .. } => {
+            let expected = correct_answer.clone();
+            
+            rsx! {
+                div { class: "quiz-container",
+                    h3 { class: "quiz-question", "{question}" }
+                    
+                    textarea {
+                        class: "quiz-code-input",
+                        class: if *answered.read() && *is_correct.read() { "correct" } else { "" },
+                        class: if *answered.read() && !*is_correct.read() { "incorrect" } else { "" },
+                        rows: "6",
+                        spellcheck: "false",
+                        value: "{text_answer}",
+                        disabled: *answered.read(),
+                        oninput: move |evt| text_answer.set(evt.value()),
+                    }
+                    
+                    if !*answered.read() {
+                        Button {
+                            variant: Variant::Primary,
+                            full_width: true,
+                            onclick: move |_| {
+                                let is_right = check_code_answer(&text_answer.read(), &expected);
+                                is_correct.set(is_right);
+                                answered.set(true);
+                                props.on_answer.call(is_right);
+                            },
+                            "Check Answer"
+                        }
+                    }
+                    
+                    if *answered.read() {
+                        div { class: "quiz-feedback",
+                            class: if *is_correct.read() { "correct" } else { "incorrect" },
+                            
+                            if *is_correct.read() {
+                                div { class: "feedback-header correct",
+                                    Icon { name: IconName::Check }
+                                    span { "Correct!" }
+                                }
+                            } else {
+                                div { class: "feedback-header incorrect",
+                                    Icon { name: IconName::X }
+                                    span { "Not quite!" }
+                                }
+                                pre { class: "quiz-solution", code { "{correct_answer}" } }
+                            }
+                            
+                            p { class: "explanation", "{explanation}" }
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
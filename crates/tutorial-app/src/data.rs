@@ -25,7 +25,7 @@ pub struct Lesson {
 }
 
 /// Content block types
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ContentBlock {
     Text(String),
     Code { language: String, code: String },
@@ -35,7 +35,7 @@ pub enum ContentBlock {
 }
 
 /// Quiz question types
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum QuizQuestion {
     MultipleChoice {
         id: String,
@@ -77,6 +77,17 @@ impl QuizQuestion {
     }
 }
 
+/// Find a quiz question by lesson and question id
+pub fn find_question<'a>(course: &'a [Module], lesson_id: &str, question_id: &str) -> Option<&'a QuizQuestion> {
+    course
+        .iter()
+        .flat_map(|m| &m.lessons)
+        .find(|l| l.id == lesson_id)?
+        .quiz
+        .iter()
+        .find(|q| q.id() == question_id)
+}
+
 /// Sample course data
 pub fn get_rust_course() -> Vec<Module> {
     vec![
@@ -114,6 +125,13 @@ pub fn get_rust_course() -> Vec<Module> {
                             is_true: false,
                             explanation: "Variables are immutable by default in Rust. Use `mut` to make them mutable.".to_string(),
                         },
+                        QuizQuestion::FillInBlank {
+                            id: "q3".to_string(),
+                            question: "Complete the declaration of a mutable variable.".to_string(),
+                            blank_text: "let ___ count = 0;".to_string(),
+                            answer: "mut".to_string(),
+                            explanation: "`let mut` declares a variable whose value can change.".to_string(),
+                        },
                     ],
                 },
                 Lesson {
@@ -199,6 +217,13 @@ pub fn get_rust_course() -> Vec<Module> {
                             correct_index: 1,
                             explanation: "The `struct` keyword defines a new structure type.".to_string(),
                         },
+                        QuizQuestion::CodeComplete {
+                            id: "q2".to_string(),
+                            question: "Define a struct `Point` with `x` and `y` fields of type `i32`.".to_string(),
+                            code_template: "struct Point {\n    \n}".to_string(),
+                            correct_answer: "struct Point {\n    x: i32,\n    y: i32,\n}".to_string(),
+                            explanation: "Struct fields are declared as `name: Type`, separated by commas.".to_string(),
+                        },
                     ],
                 },
             ],
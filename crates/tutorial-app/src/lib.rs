@@ -1,8 +1,12 @@
 //! Gamified Tutorial App - Like Duolingo for Learning Rust
 
+mod achievements;
 mod app;
 mod components;
 mod data;
+mod quiz;
 mod state;
+mod storage;
+mod timer;
 
 pub use app::TutorialApp;
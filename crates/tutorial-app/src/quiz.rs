@@ -0,0 +1,175 @@
+//! Quiz answer checking
+
+/// Normalize a free-text answer for comparison (trim + case-fold)
+pub fn normalize_answer(answer: &str) -> String {
+    answer.trim().to_lowercase()
+}
+
+/// Check a fill-in-the-blank answer against the expected one
+pub fn check_text_answer(user: &str, expected: &str) -> bool {
+    normalize_answer(user) == normalize_answer(expected)
+}
+
+/// Normalize code for comparison
+///
+/// Whitespace is dropped except where it separates two identifier/number characters
+/// (where a single space is kept, so `let x` never matches `letx`). String literals
+/// are left untouched.
+pub fn normalize_code(code: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(code.len());
+    let mut pending_space = false;
+    let mut in_string = false;
+    let mut escaped = false;
+    
+    for c in code.chars() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        
+        if pending_space && out.chars().last().is_some_and(is_word) && is_word(c) {
+            out.push(' ');
+        }
+        pending_space = false;
+        
+        if c == '"' {
+            in_string = true;
+        }
+        out.push(c);
+    }
+    
+    out
+}
+
+/// Check a code-completion answer, ignoring formatting and a trailing semicolon
+pub fn check_code_answer(user: &str, expected: &str) -> bool {
+    let user = normalize_code(user);
+    let expected = normalize_code(expected);
+    user.strip_suffix(';').unwrap_or(&user) == expected.strip_suffix(';').unwrap_or(&expected)
+}
+
+/// Shuffle multiple-choice options deterministically for a seed
+///
+/// Returns the shuffled options and the index of the originally-correct option
+/// within them.
+pub fn shuffle_options(options: &[String], correct_index: usize, seed: u64) -> (Vec<String>, usize) {
+    let mut order: Vec<usize> = (0..options.len()).collect();
+    let mut state = seed;
+    
+    // Fisher-Yates with a splitmix64 generator
+    for i in (1..order.len()).rev() {
+        let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+    
+    let shuffled = order.iter().map(|&i| options[i].clone()).collect();
+    let new_correct = order.iter().position(|&i| i == correct_index).unwrap_or(correct_index);
+    (shuffled, new_correct)
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_answer() {
+        assert_eq!(normalize_answer("  Mut \n"), "mut");
+        assert_eq!(normalize_answer("String"), "string");
+        assert_eq!(normalize_answer(""), "");
+    }
+
+    #[test]
+    fn test_check_text_answer() {
+        assert!(check_text_answer("mut", "mut"));
+        assert!(check_text_answer("  MUT  ", "mut"));
+        assert!(check_text_answer("Mut", " mut "));
+        assert!(!check_text_answer("let", "mut"));
+        assert!(!check_text_answer("mu t", "mut"));
+        assert!(!check_text_answer("", "mut"));
+    }
+
+    #[test]
+    fn test_check_code_answer_spacing() {
+        assert!(check_code_answer("x+1", "x + 1"));
+        assert!(check_code_answer("  let  y =\n    x * 2;  ", "let y = x * 2;"));
+        assert!(check_code_answer(
+            "struct Point {\n    x: i32,\n    y: i32,\n}",
+            "struct Point { x: i32, y: i32, }",
+        ));
+    }
+
+    #[test]
+    fn test_check_code_answer_trailing_semicolon() {
+        assert!(check_code_answer("let x = 5", "let x = 5;"));
+        assert!(check_code_answer("let x = 5;", "let x = 5"));
+        assert!(check_code_answer("let x = 5 ;", "let x = 5;"));
+    }
+
+    #[test]
+    fn test_check_code_answer_wrong() {
+        assert!(!check_code_answer("x - 1", "x + 1"));
+        assert!(!check_code_answer("let mutx = 5;", "let mut x = 5;"));
+        assert!(!check_code_answer("println!(\"hi  there\")", "println!(\"hi there\")"));
+        assert!(!check_code_answer("", "x + 1"));
+    }
+
+    fn options() -> Vec<String> {
+        ["let", "mut", "const", "static", "fn"].iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_shuffle_preserves_options() {
+        let original = options();
+        for seed in 0..50 {
+            let (shuffled, _) = shuffle_options(&original, 1, seed);
+            let mut sorted = shuffled.clone();
+            sorted.sort();
+            let mut expected = original.clone();
+            expected.sort();
+            assert_eq!(sorted, expected);
+        }
+    }
+
+    #[test]
+    fn test_shuffle_tracks_correct_index() {
+        let original = options();
+        for correct in 0..original.len() {
+            for seed in 0..50 {
+                let (shuffled, new_correct) = shuffle_options(&original, correct, seed);
+                assert_eq!(shuffled[new_correct], original[correct]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shuffle_is_deterministic_per_seed() {
+        let original = options();
+        assert_eq!(shuffle_options(&original, 2, 42), shuffle_options(&original, 2, 42));
+
+        // Different seeds should produce more than one ordering
+        let orderings: std::collections::HashSet<Vec<String>> =
+            (0..20).map(|seed| shuffle_options(&original, 0, seed).0).collect();
+        assert!(orderings.len() > 1);
+    }
+}
@@ -1,7 +1,14 @@
 //! Application state
 
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Maximum number of hearts
+pub const MAX_HEARTS: u32 = 5;
+
+/// Minutes it takes to regenerate one heart
+pub const HEART_REGEN_MINUTES: i64 = 30;
+
 /// User profile and progress
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct User {
@@ -11,10 +18,19 @@ pub struct User {
     pub xp: u32,
     pub xp_to_next_level: u32,
     pub streak: u32,
+    /// Last calendar day the user completed a lesson
+    #[serde(default)]
+    pub last_active_date: Option<NaiveDate>,
     pub hearts: u32,
+    /// When the current heart regeneration interval started (None when hearts are full)
+    #[serde(default)]
+    pub heart_regen_started: Option<DateTime<Utc>>,
     pub gems: u32,
     pub completed_lessons: Vec<String>,
     pub achievements: Vec<Achievement>,
+    /// Questions answered incorrectly, awaiting review
+    #[serde(default)]
+    pub mistake_log: MistakeLog,
 }
 
 impl Default for User {
@@ -26,10 +42,13 @@ impl Default for User {
             xp: 0,
             xp_to_next_level: 100,
             streak: 0,
-            hearts: 5,
+            last_active_date: None,
+            hearts: MAX_HEARTS,
+            heart_regen_started: None,
             gems: 50,
             completed_lessons: vec![],
             achievements: vec![],
+            mistake_log: MistakeLog::default(),
         }
     }
 }
@@ -59,8 +78,16 @@ impl User {
     
     /// Lose a heart (wrong answer)
     pub fn lose_heart(&mut self) -> bool {
+        self.lose_heart_at(Utc::now())
+    }
+    
+    /// Lose a heart at the given time, starting the regeneration timer
+    pub fn lose_heart_at(&mut self, now: DateTime<Utc>) -> bool {
         if self.hearts > 0 {
             self.hearts -= 1;
+            if self.heart_regen_started.is_none() {
+                self.heart_regen_started = Some(now);
+            }
             true
         } else {
             false // No hearts left - game over
@@ -69,7 +96,46 @@ impl User {
     
     /// Restore hearts
     pub fn restore_hearts(&mut self) {
-        self.hearts = 5;
+        self.hearts = MAX_HEARTS;
+        self.heart_regen_started = None;
+    }
+    
+    /// Number of hearts that have regenerated by `now` (capped at the missing hearts)
+    pub fn hearts_due(&self, now: DateTime<Utc>) -> u32 {
+        let Some(started) = self.heart_regen_started else {
+            return 0;
+        };
+        let elapsed = now - started;
+        if elapsed < Duration::zero() {
+            return 0;
+        }
+        let intervals = elapsed.num_minutes() / HEART_REGEN_MINUTES;
+        (intervals.min(MAX_HEARTS as i64) as u32).min(MAX_HEARTS.saturating_sub(self.hearts))
+    }
+    
+    /// Restore one heart for every regeneration interval elapsed by `now`, up to the max
+    ///
+    /// Returns the number of hearts restored.
+    pub fn regen_heart_if_due(&mut self, now: DateTime<Utc>) -> u32 {
+        if self.hearts >= MAX_HEARTS {
+            self.heart_regen_started = None;
+            return 0;
+        }
+        
+        let restored = self.hearts_due(now);
+        if restored == 0 {
+            return 0;
+        }
+        
+        self.hearts += restored;
+        self.heart_regen_started = if self.hearts >= MAX_HEARTS {
+            None
+        } else {
+            // Keep the partial progress towards the next heart
+            self.heart_regen_started
+                .map(|started| started + Duration::minutes(HEART_REGEN_MINUTES * restored as i64))
+        };
+        restored
     }
     
     /// Complete a lesson
@@ -79,17 +145,100 @@ impl User {
         }
     }
     
+    /// Record activity on `today`, updating the daily streak
+    ///
+    /// Consecutive days extend the streak, repeat activity on the same day keeps it,
+    /// and anything else (a missed day) starts a new streak. Returns the new streak.
+    pub fn record_activity(&mut self, today: NaiveDate) -> u32 {
+        self.streak = match self.last_active_date {
+            Some(last) if today == last => self.streak.max(1),
+            Some(last) if last.succ_opt() == Some(today) => self.streak + 1,
+            _ => 1,
+        };
+        self.last_active_date = Some(today);
+        self.streak
+    }
+    
     /// Check if lesson is completed
     pub fn is_lesson_completed(&self, lesson_id: &str) -> bool {
         self.completed_lessons.contains(&lesson_id.to_string())
     }
     
+    /// Check if an achievement has been unlocked
+    pub fn has_achievement(&self, id: &str) -> bool {
+        self.achievements.iter().any(|a| a.id == id)
+    }
+    
     /// Unlock achievement
     pub fn unlock_achievement(&mut self, achievement: Achievement) {
-        if !self.achievements.iter().any(|a| a.id == achievement.id) {
+        if !self.has_achievement(&achievement.id) {
             self.achievements.push(achievement);
         }
     }
+    
+    /// Serialize progress to JSON
+    pub fn to_json(&self) -> Option<String> {
+        serde_json::to_string(self).ok()
+    }
+    
+    /// Deserialize progress from JSON (None if the data is corrupt)
+    pub fn from_json(json: &str) -> Option<Self> {
+        serde_json::from_str(json).ok()
+    }
+}
+
+/// A question the user answered incorrectly
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Mistake {
+    pub lesson_id: String,
+    pub question_id: String,
+    pub missed_at: DateTime<Utc>,
+}
+
+/// Log of missed questions for review
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MistakeLog {
+    entries: Vec<Mistake>,
+}
+
+impl MistakeLog {
+    /// Record a miss (repeat misses of the same question keep the original entry)
+    pub fn record(&mut self, lesson_id: &str, question_id: &str, now: DateTime<Utc>) {
+        if !self.contains(lesson_id, question_id) {
+            self.entries.push(Mistake {
+                lesson_id: lesson_id.to_string(),
+                question_id: question_id.to_string(),
+                missed_at: now,
+            });
+        }
+    }
+    
+    /// Remove a question once it has been answered correctly in review
+    pub fn resolve(&mut self, lesson_id: &str, question_id: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|m| !(m.lesson_id == lesson_id && m.question_id == question_id));
+        self.entries.len() != before
+    }
+    
+    /// Check if a question is in the log
+    pub fn contains(&self, lesson_id: &str, question_id: &str) -> bool {
+        self.entries.iter().any(|m| m.lesson_id == lesson_id && m.question_id == question_id)
+    }
+    
+    /// Questions due for review, oldest miss first
+    pub fn review_queue(&self) -> Vec<Mistake> {
+        let mut queue = self.entries.clone();
+        queue.sort_by_key(|m| m.missed_at);
+        queue
+    }
+    
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
 }
 
 /// Level up event
@@ -160,4 +309,164 @@ impl Achievement {
             xp_reward: 300,
         }
     }
+    
+    pub fn ownership_complete() -> Self {
+        Self {
+            id: "ownership_complete".to_string(),
+            name: "Borrow Checker Tamer".to_string(),
+            description: "Complete the Ownership module".to_string(),
+            icon: "🔒".to_string(),
+            xp_reward: 250,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_json_round_trip() {
+        let mut user = User::default();
+        user.add_xp(40);
+        user.streak = 3;
+        user.gems = 75;
+        user.complete_lesson("variables");
+        user.unlock_achievement(Achievement::first_lesson());
+
+        let json = user.to_json().unwrap();
+        let restored = User::from_json(&json).unwrap();
+
+        assert_eq!(restored.xp, 40);
+        assert_eq!(restored.streak, 3);
+        assert_eq!(restored.gems, 75);
+        assert_eq!(restored.completed_lessons, vec!["variables".to_string()]);
+        assert_eq!(restored.achievements.len(), 1);
+        assert_eq!(restored.achievements[0].id, "first_lesson");
+    }
+
+    #[test]
+    fn test_user_from_corrupt_json() {
+        assert!(User::from_json("{not json").is_none());
+        assert!(User::from_json("").is_none());
+    }
+
+    fn at_minute(minute: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap() + Duration::minutes(minute)
+    }
+
+    #[test]
+    fn test_no_heart_regen_before_interval() {
+        let mut user = User::default();
+        user.lose_heart_at(at_minute(0));
+        assert_eq!(user.hearts, MAX_HEARTS - 1);
+
+        assert_eq!(user.regen_heart_if_due(at_minute(HEART_REGEN_MINUTES - 1)), 0);
+        assert_eq!(user.hearts, MAX_HEARTS - 1);
+    }
+
+    #[test]
+    fn test_one_heart_regen_after_interval() {
+        let mut user = User::default();
+        user.lose_heart_at(at_minute(0));
+        user.lose_heart_at(at_minute(5));
+
+        // The timer started at the first loss
+        assert_eq!(user.regen_heart_if_due(at_minute(HEART_REGEN_MINUTES)), 1);
+        assert_eq!(user.hearts, MAX_HEARTS - 1);
+
+        // The next heart needs another full interval
+        assert_eq!(user.regen_heart_if_due(at_minute(HEART_REGEN_MINUTES + 1)), 0);
+        assert_eq!(user.regen_heart_if_due(at_minute(2 * HEART_REGEN_MINUTES)), 1);
+        assert_eq!(user.hearts, MAX_HEARTS);
+        assert!(user.heart_regen_started.is_none());
+    }
+
+    #[test]
+    fn test_heart_regen_caps_at_max() {
+        let mut user = User::default();
+        for _ in 0..MAX_HEARTS {
+            user.lose_heart_at(at_minute(0));
+        }
+        assert_eq!(user.hearts, 0);
+        assert!(!user.lose_heart_at(at_minute(0)));
+
+        // A long time away restores every heart, but never more than the max
+        assert_eq!(user.regen_heart_if_due(at_minute(100 * HEART_REGEN_MINUTES)), MAX_HEARTS);
+        assert_eq!(user.hearts, MAX_HEARTS);
+        assert_eq!(user.regen_heart_if_due(at_minute(200 * HEART_REGEN_MINUTES)), 0);
+        assert_eq!(user.hearts, MAX_HEARTS);
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_streak_consecutive_days() {
+        let mut user = User::default();
+        assert_eq!(user.record_activity(day(1)), 1);
+        assert_eq!(user.record_activity(day(2)), 2);
+        assert_eq!(user.record_activity(day(3)), 3);
+        assert_eq!(user.last_active_date, Some(day(3)));
+    }
+
+    #[test]
+    fn test_streak_same_day_repeat() {
+        let mut user = User::default();
+        user.record_activity(day(1));
+        user.record_activity(day(2));
+        assert_eq!(user.record_activity(day(2)), 2);
+        assert_eq!(user.record_activity(day(2)), 2);
+        assert_eq!(user.record_activity(day(3)), 3);
+    }
+
+    #[test]
+    fn test_streak_gap_resets() {
+        let mut user = User::default();
+        user.record_activity(day(1));
+        user.record_activity(day(2));
+        assert_eq!(user.record_activity(day(4)), 1);
+        assert_eq!(user.streak, 1);
+        assert_eq!(user.last_active_date, Some(day(4)));
+    }
+
+    #[test]
+    fn test_mistake_log_enqueues_miss() {
+        let mut log = MistakeLog::default();
+        log.record("variables", "q2", at_minute(5));
+        log.record("functions", "q1", at_minute(1));
+
+        let queue = log.review_queue();
+        assert_eq!(queue.len(), 2);
+        // Oldest first
+        assert_eq!((queue[0].lesson_id.as_str(), queue[0].question_id.as_str()), ("functions", "q1"));
+        assert_eq!((queue[1].lesson_id.as_str(), queue[1].question_id.as_str()), ("variables", "q2"));
+    }
+
+    #[test]
+    fn test_mistake_log_resolve_removes() {
+        let mut log = MistakeLog::default();
+        log.record("variables", "q1", at_minute(0));
+        log.record("variables", "q2", at_minute(1));
+
+        assert!(log.resolve("variables", "q1"));
+        assert!(!log.contains("variables", "q1"));
+        assert_eq!(log.len(), 1);
+        assert!(!log.resolve("variables", "q1"));
+    }
+
+    #[test]
+    fn test_mistake_log_dedupes_repeat_misses() {
+        let mut log = MistakeLog::default();
+        log.record("variables", "q1", at_minute(0));
+        log.record("variables", "q1", at_minute(10));
+        // Same question id in a different lesson is a different question
+        log.record("functions", "q1", at_minute(5));
+
+        let queue = log.review_queue();
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue[0].lesson_id, "variables");
+        assert_eq!(queue[0].missed_at, at_minute(0));
+    }
 }
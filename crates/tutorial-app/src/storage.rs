@@ -0,0 +1,68 @@
+//! Progress persistence
+//!
+//! Saves the user's progress between launches: a JSON file under the app
+//! data dir on desktop, local storage on web.
+
+use crate::state::User;
+
+/// Key (web) and file stem (desktop) the user progress is stored under
+const STORAGE_KEY: &str = "rustlingo_user";
+
+/// Load saved user progress, falling back to defaults if missing or corrupt
+pub fn load_user() -> User {
+    read_raw()
+        .and_then(|json| User::from_json(&json))
+        .unwrap_or_default()
+}
+
+/// Save user progress (errors are ignored - progress is best effort)
+pub fn save_user(user: &User) {
+    if let Some(json) = user.to_json() {
+        write_raw(&json);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn progress_file() -> Option<std::path::PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("rustlingo").join(format!("{}.json", STORAGE_KEY)))
+}
+
+#[cfg(feature = "desktop")]
+fn read_raw() -> Option<String> {
+    std::fs::read_to_string(progress_file()?).ok()
+}
+
+#[cfg(feature = "desktop")]
+fn write_raw(json: &str) {
+    if let Some(path) = progress_file() {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(path, json);
+    }
+}
+
+#[cfg(all(feature = "web", not(feature = "desktop")))]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(all(feature = "web", not(feature = "desktop")))]
+fn read_raw() -> Option<String> {
+    local_storage()?.get_item(STORAGE_KEY).ok()?
+}
+
+#[cfg(all(feature = "web", not(feature = "desktop")))]
+fn write_raw(json: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(STORAGE_KEY, json);
+    }
+}
+
+#[cfg(not(any(feature = "web", feature = "desktop")))]
+fn read_raw() -> Option<String> {
+    None
+}
+
+#[cfg(not(any(feature = "web", feature = "desktop")))]
+fn write_raw(_json: &str) {}
@@ -0,0 +1,19 @@
+//! Platform async timer
+
+use std::time::Duration;
+
+/// Sleep for the given duration on the current platform's async runtime
+pub async fn sleep(duration: Duration) {
+    #[cfg(feature = "desktop")]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(all(feature = "web", not(feature = "desktop")))]
+    gloo_timers::future::sleep(duration).await;
+
+    #[cfg(not(any(feature = "web", feature = "desktop")))]
+    {
+        // No timer available - never wake up
+        let _ = duration;
+        std::future::pending::<()>().await;
+    }
+}